@@ -24,7 +24,25 @@
 // UI timing
 #define MESSAGE_DISPLAY_TIME 2.0f      // How long notifications stay on screen
 
+// Simulation timing
+#define SIM_FIXED_STEP (1.0f / 60.0f)  // World simulation step in seconds
+#define SIM_MAX_STEPS_PER_FRAME 8      // Drop backlog beyond this to avoid spiral of death
+#define SIM_MAX_TIME_SCALE 4           // Fast-forward cap (1x, 2x, 4x)
+
+// Undo
+#define UNDO_HISTORY_SIZE 32           // Placements/minings remembered for Ctrl+Z
+
 // Crack overlay stages
 #define CRACK_STAGE_COUNT 10           // Number of crack overlay stages (0-9)
 
+// New game
+#define STARTING_KIT STARTING_KIT_STANDARD  // Kit handed out at spawn (see StartingKit)
+#define WORLD_TREE_DENSITY 1.0f        // Tree density multiplier (1.0 = standard, 0.0 = none)
+#define WORLD_ORE_ABUNDANCE 1.0f       // Ore abundance multiplier (1.0 = standard, 0.0 = none)
+#define SPAWN_SEARCH_RADIUS 256        // Farthest from the origin a dry spawn is looked for
+#define SPAWN_SEARCH_STEP 4            // Spacing between spawn columns checked
+
+// Drops
+#define SAPLING_LEAF_DROP_CHANCE 10    // 1 in N broken leaves gives a sapling
+
 #endif // GAME_CONSTANTS_H
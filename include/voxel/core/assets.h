@@ -0,0 +1,66 @@
+/**
+ * Asset Loading
+ *
+ * Loads the few assets read from disk (GLSL shaders) and records every path
+ * that was missing, so gaps show up in a debug overlay instead of only as a
+ * line in the log. Callers keep their existing fallbacks: a missing file
+ * never aborts the game.
+ */
+
+#ifndef VOXEL_ASSETS_H
+#define VOXEL_ASSETS_H
+
+#include <raylib.h>
+#include <stdbool.h>
+
+#define ASSETS_MAX_MISSING 32   // Missing paths remembered for the overlay
+#define ASSETS_PATH_MAX 128
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Forget all recorded missing paths
+ */
+void assets_clear_missing(void);
+
+/**
+ * Check that a file exists, recording its path if it doesn't
+ * @return true if the file exists
+ */
+bool assets_check_file(const char* path);
+
+/**
+ * Load a vertex + fragment shader pair
+ * @return The shader, or a shader with id 0 if either file is missing
+ */
+Shader assets_load_shader(const char* vs_path, const char* fs_path);
+
+/**
+ * Check if a shader is a real loaded program, not id 0 or raylib's default
+ * (raylib falls back to the default shader when compiling fails)
+ */
+bool assets_shader_is_loaded(Shader shader);
+
+/**
+ * Number of missing paths recorded so far
+ */
+int assets_get_missing_count(void);
+
+/**
+ * Get a recorded missing path (NULL if index is out of range)
+ */
+const char* assets_get_missing(int index);
+
+/**
+ * Show or hide the missing-assets overlay
+ */
+void assets_toggle_overlay(void);
+
+/**
+ * Draw the missing-assets overlay if it is shown (2D, top-left)
+ */
+void assets_draw_overlay(void);
+
+#endif // VOXEL_ASSETS_H
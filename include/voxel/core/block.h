@@ -67,6 +67,8 @@ typedef enum {
     // Doors
     BLOCK_WOOD_DOOR,        // Wooden door (can be opened by hand)
     BLOCK_IRON_DOOR,        // Iron door (needs redstone/interaction)
+    // Vegetation
+    BLOCK_SAPLING,          // Young tree that grows over time
     BLOCK_COUNT  // Total number of block types
 } BlockType;
 
@@ -115,6 +117,13 @@ const BlockProperties* block_get_properties(BlockType type);
  */
 bool block_is_solid(Block block);
 
+/**
+ * Check if block stops players and mobs: solid blocks, plus closed doors
+ * (doors are non-solid for rendering; metadata bit 0 marks them open).
+ * Saplings stay solid so they render and can be targeted, but never block.
+ */
+bool block_blocks_movement(Block block);
+
 /**
  * Check if block is transparent (light passes through)
  */
@@ -55,6 +55,7 @@ typedef enum {
     // Tools
     ITEM_WOODEN_PICKAXE,
     ITEM_STONE_PICKAXE,
+    ITEM_IRON_PICKAXE,      // Mines a 3x3 area
     ITEM_WOODEN_SHOVEL,
     ITEM_STONE_SHOVEL,
     ITEM_WOODEN_AXE,
@@ -91,9 +92,29 @@ typedef enum {
     ITEM_WOOD_DOOR,
     ITEM_IRON_DOOR,
 
+    // Vegetation
+    ITEM_SAPLING,
+
+    // Upgrades
+    ITEM_BACKPACK,          // Use to unlock an extra inventory row
+
+    // Gadgets
+    ITEM_ORE_SCANNER,       // Hold to show nearby ores
+
     ITEM_COUNT
 } ItemType;
 
+// ============================================================================
+// FOOD EFFECTS
+// ============================================================================
+
+typedef enum {
+    EFFECT_NONE = 0,
+    EFFECT_SPEED,           // Faster walking and flying
+
+    EFFECT_COUNT
+} EffectType;
+
 // ============================================================================
 // DATA STRUCTURES
 // ============================================================================
@@ -123,6 +144,10 @@ typedef struct {
     // Tool mining properties
     ToolType tool_type;      // TOOL_PICKAXE, TOOL_AXE, etc.
     float dig_speed;         // Multiplier (1.0 = hand, 2.0 = wooden, 4.0 = stone)
+    int mine_radius;         // Area mining: 0 = single block, 1 = 3x3
+    // Food properties
+    EffectType food_effect;  // Buff granted when eaten (EFFECT_NONE = not food)
+    float food_duration;     // Buff length in seconds
 } ItemProperties;
 
 // ============================================================================
@@ -155,6 +180,27 @@ bool item_can_stack(const ItemStack* a, const ItemStack* b);
  */
 const char* item_get_name(ItemType type);
 
+/**
+ * Find an item by its display name (case-insensitive)
+ * Returns ITEM_NONE if no item has that name
+ */
+ItemType item_find_by_name(const char* name);
+
+/**
+ * Check if an item can be eaten
+ */
+bool item_is_food(ItemType type);
+
+/**
+ * Get the display name of a food effect
+ */
+const char* item_get_effect_name(EffectType effect);
+
+/**
+ * Get the lowercase name of a tool type, for use in messages ("pickaxe")
+ */
+const char* item_get_tool_type_name(ToolType type);
+
 /**
  * Calculate dig time for a block with a given tool
  * Returns time in seconds, 0 for instant, -1 for unbreakable
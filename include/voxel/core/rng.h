@@ -0,0 +1,43 @@
+/**
+ * Game RNG - Seedable Random Numbers
+ *
+ * Small splitmix64 generator for gameplay randomness (mob AI, spawning,
+ * loot, saplings). Unlike rand(), each generator carries its own state, so
+ * a world seeded the same way replays the same sequence.
+ */
+
+#ifndef VOXEL_RNG_H
+#define VOXEL_RNG_H
+
+#include <stdint.h>
+
+typedef struct {
+    uint64_t state;
+} GameRng;
+
+/**
+ * Reset a generator to the start of a seed's sequence
+ */
+void rng_seed(GameRng* rng, uint64_t seed);
+
+/**
+ * Next raw 32-bit value
+ */
+uint32_t rng_next(GameRng* rng);
+
+/**
+ * Random float in [0, 1)
+ */
+float rng_float(GameRng* rng);
+
+/**
+ * Random float in [min, max)
+ */
+float rng_range(GameRng* rng, float min, float max);
+
+/**
+ * Random integer in [0, max) (0 if max <= 0)
+ */
+int rng_int(GameRng* rng, int max);
+
+#endif // VOXEL_RNG_H
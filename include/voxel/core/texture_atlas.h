@@ -55,6 +55,11 @@ void texture_atlas_init(void);
  */
 void texture_atlas_destroy(void);
 
+/**
+ * Reload the block shader from disk, keeping the current one if that fails
+ */
+bool texture_atlas_reload_shader(void);
+
 /**
  * Get texture coordinates for a block face
  */
@@ -27,8 +27,8 @@ typedef enum {
     ENTITY_TYPE_BLOCK_HUMAN,
     ENTITY_TYPE_SHEEP,
     ENTITY_TYPE_PIG,
+    ENTITY_TYPE_ZOMBIE,
     // Future entity types:
-    // ENTITY_TYPE_ZOMBIE,
     // ENTITY_TYPE_ITEM_DROP,
 } EntityType;
 
@@ -169,4 +169,12 @@ void entity_manager_render(EntityManager* manager);
  */
 int entity_manager_get_count(EntityManager* manager);
 
+/**
+ * Count active entities of one type
+ * @param manager Entity manager
+ * @param type Entity type to count
+ * @return Number of active entities of that type
+ */
+int entity_manager_count_type(EntityManager* manager, EntityType type);
+
 #endif // ENTITY_H
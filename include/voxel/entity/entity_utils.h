@@ -9,6 +9,7 @@
 
 #include <raylib.h>
 #include <raymath.h>
+#include "voxel/core/rng.h"
 
 // ============================================================================
 // ANIMATION CONSTANTS
@@ -22,6 +23,13 @@
 // FUNCTIONS
 // ============================================================================
 
+/**
+ * Set the generator used by the entity random helpers
+ * The world binds its own RNG so mob behavior follows the world seed.
+ * Pass NULL to fall back to an internal generator.
+ */
+void entity_set_rng(GameRng* rng);
+
 /**
  * Get a random float between min and max
  */
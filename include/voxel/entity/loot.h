@@ -0,0 +1,61 @@
+/**
+ * Mob Loot Tables
+ *
+ * Per-entity-type drop tables rolled when a mob is killed.
+ * Each entry has a drop chance and a count range, so drops can vary
+ * instead of always being the same fixed set.
+ */
+
+#ifndef ENTITY_LOOT_H
+#define ENTITY_LOOT_H
+
+#include "voxel/entity/entity.h"
+#include "voxel/core/item.h"
+#include "voxel/core/rng.h"
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+#define LOOT_MAX_DROPS 4    // Max distinct stacks a single kill can produce
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/**
+ * One possible drop in a loot table
+ */
+typedef struct {
+    ItemType item;
+    float chance;           // 0.0-1.0, rolled once per kill
+    uint8_t min_count;
+    uint8_t max_count;      // Inclusive
+} LootEntry;
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Get the loot table for an entity type
+ *
+ * @param type Entity type
+ * @param out_count Output: number of entries
+ * @return Pointer to static table, or NULL if the type drops nothing
+ */
+const LootEntry* loot_get_table(EntityType type, int* out_count);
+
+/**
+ * Roll the loot table for a killed entity
+ * Sheep wool entries are recolored to match the sheep's wool.
+ *
+ * @param entity The killed entity
+ * @param rng Generator for the chance and count rolls (the world's RNG)
+ * @param out_drops Output: rolled stacks
+ * @param max_drops Capacity of out_drops
+ * @return Number of stacks written
+ */
+int loot_roll(const Entity* entity, GameRng* rng, ItemStack* out_drops, int max_drops);
+
+#endif // ENTITY_LOOT_H
@@ -0,0 +1,70 @@
+/**
+ * Entity Pathfinding
+ *
+ * A* search over walkable block columns for mobs that need to
+ * navigate around obstacles instead of wandering blindly.
+ */
+
+#ifndef ENTITY_PATHFINDING_H
+#define ENTITY_PATHFINDING_H
+
+#include <stdbool.h>
+
+// Forward declaration
+struct World;
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+#define PATH_MAX_NODES 4096     // Search cap so unreachable goals can't hang a frame
+#define PATH_MAX_LENGTH 128     // Longest path that can be returned
+
+// ============================================================================
+// DATA STRUCTURES
+// ============================================================================
+
+/**
+ * A block position an entity can stand in (feet position)
+ */
+typedef struct {
+    int x, y, z;
+} PathNode;
+
+/**
+ * Result of a path search, ordered from start to goal (both inclusive)
+ */
+typedef struct {
+    PathNode nodes[PATH_MAX_LENGTH];
+    int length;
+} Path;
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Check if an entity can stand with its feet in the given block
+ *
+ * Requires solid ground below and two passable, non-fluid blocks
+ * for the body (entities avoid walking into water). Closed doors are
+ * not passable, open doors are.
+ */
+bool pathfinding_is_walkable(struct World* world, int x, int y, int z);
+
+/**
+ * Find the shortest walkable path between two positions using A*
+ *
+ * Moves are 4-directional and may step up or down one block.
+ * Uses a Manhattan distance heuristic and gives up after
+ * PATH_MAX_NODES expanded nodes.
+ *
+ * @param world The world to search
+ * @param start Starting feet position
+ * @param goal Target feet position
+ * @param out_path Output: path from start to goal
+ * @return true if a path was found, false if unreachable or too long
+ */
+bool pathfinding_find_path(struct World* world, PathNode start, PathNode goal, Path* out_path);
+
+#endif // ENTITY_PATHFINDING_H
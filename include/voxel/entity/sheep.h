@@ -12,6 +12,7 @@
 #define SHEEP_H
 
 #include "voxel/entity/entity.h"
+#include "voxel/core/item.h"
 #include <raylib.h>
 
 // ============================================================================
@@ -119,6 +120,13 @@ Entity* sheep_spawn_colored(EntityManager* manager, Vector3 position, Color wool
  */
 bool sheep_damage(Entity* entity, int damage);
 
+/**
+ * Get the wool item matching a sheep's wool color
+ * @param entity The sheep entity
+ * @return Closest wool item (light gray if no close match)
+ */
+ItemType sheep_get_wool_item(const Entity* entity);
+
 // ============================================================================
 // INTERNAL CALLBACKS (called by entity system)
 // ============================================================================
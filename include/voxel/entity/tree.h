@@ -1,7 +1,8 @@
 /**
- * Tree Generation & Leaf Decay System
+ * Tree Generation, Leaf Decay & Sapling Growth
  *
- * Generates procedural trees. Leaves decay over time when not connected to wood.
+ * Generates procedural trees. Leaves decay over time when not connected to wood,
+ * occasionally dropping saplings that grow back into trees.
  */
 
 #ifndef VOXEL_TREE_H
@@ -47,8 +48,9 @@ void tree_place_at_typed(Chunk* chunk, int local_x, int base_y, int local_z,
  * Generate trees for an entire chunk.
  * Uses noise for natural placement on grass blocks.
  * Call this after terrain generation.
+ * @param density_scale Multiplier on biome tree density (1.0 = standard, 0.0 = none)
  */
-void tree_generate_for_chunk(Chunk* chunk);
+void tree_generate_for_chunk(Chunk* chunk, float density_scale);
 
 /**
  * Initialize the leaf decay system.
@@ -67,4 +69,26 @@ void leaf_decay_on_wood_removed(struct World* world, int x, int y, int z);
  */
 void leaf_decay_update(struct World* world, float dt);
 
+/**
+ * Initialize the sapling growth system.
+ */
+void sapling_init(void);
+
+/**
+ * Register a sapling so it grows into a tree over time.
+ * Call this when a sapling block is placed.
+ */
+void sapling_on_placed(struct World* world, int x, int y, int z);
+
+/**
+ * Check if a sapling can take root on this block (grass or dirt).
+ */
+bool sapling_is_soil(BlockType type);
+
+/**
+ * Update sapling growth - call each frame.
+ * Grows saplings on grass/dirt into trees when there is room.
+ */
+void sapling_update(struct World* world, float dt);
+
 #endif // VOXEL_TREE_H
@@ -0,0 +1,120 @@
+/**
+ * Zombie Entity - Hostile mob that hunts the player at night
+ *
+ * A green-skinned block human with:
+ * - Wander / chase / attack AI states
+ * - A* pathfinding toward the player (direct steering as fallback)
+ * - Knockback on contact attacks
+ * - Despawns once the sun comes up
+ */
+
+#ifndef ZOMBIE_H
+#define ZOMBIE_H
+
+#include "voxel/entity/entity.h"
+#include "voxel/entity/block_human.h"
+#include "voxel/entity/pathfinding.h"
+#include <raylib.h>
+
+// ============================================================================
+// AI CONSTANTS
+// ============================================================================
+
+#define ZOMBIE_WANDER_SPEED 1.0f
+#define ZOMBIE_CHASE_SPEED 2.8f         // A bit slower than the player walks
+#define ZOMBIE_DETECT_RANGE 16.0f       // Start chasing inside this distance
+#define ZOMBIE_LOSE_RANGE 24.0f         // Give up the chase beyond this
+#define ZOMBIE_ATTACK_RANGE 1.3f        // Horizontal distance for contact hits
+
+#define ZOMBIE_ATTACK_COOLDOWN 1.0f     // Seconds between hits
+#define ZOMBIE_KNOCKBACK 8.0f           // Horizontal knockback speed on hit
+#define ZOMBIE_KNOCKBACK_LIFT 5.0f      // Vertical knockback speed on hit
+#define ZOMBIE_HIT_SHAKE 0.15f          // Camera shake on hit, in blocks
+#define ZOMBIE_HIT_SHAKE_TIME 0.35f
+
+#define ZOMBIE_REPATH_TIME 1.0f         // Seconds between path searches
+#define ZOMBIE_WAYPOINT_RADIUS 0.4f     // Distance at which a path node counts as reached
+
+#define ZOMBIE_WANDER_TIME_MIN 3.0f
+#define ZOMBIE_WANDER_TIME_MAX 7.0f
+
+#define ZOMBIE_JUMP_VELOCITY 7.0f
+#define ZOMBIE_JUMP_COOLDOWN 0.5f
+
+// ============================================================================
+// ZOMBIE DATA
+// ============================================================================
+
+typedef enum {
+    ZOMBIE_STATE_WANDER,            // Shuffling around, player out of range
+    ZOMBIE_STATE_CHASE,             // Following a path toward the player
+    ZOMBIE_STATE_ATTACK             // In contact range, hitting the player
+} ZombieState;
+
+typedef struct {
+    // Body model and walk animation (must stay first: block_human callbacks
+    // read entity->data as BlockHumanData)
+    BlockHumanData body;
+
+    // AI state
+    ZombieState state;
+    float wander_timer;             // Time until next wander direction change
+    Vector3 wander_direction;       // Current wander direction (normalized)
+    float attack_cooldown;          // Time until the next hit can land
+
+    // Pathfinding
+    Path path;                      // Current path toward the player
+    int path_index;                 // Next node to walk to
+    float repath_timer;             // Time until the path is recomputed
+
+    // Health & damage
+    int hp;                         // Current health (default: 6, dies at 0)
+    float damage_flash_timer;       // Red flash duration (0 = no flash)
+
+    // Jump
+    float jump_cooldown;
+} ZombieData;
+
+// ============================================================================
+// ZOMBIE API
+// ============================================================================
+
+/**
+ * Spawn a new zombie entity
+ * @param manager Entity manager to add to
+ * @param position World position (at feet)
+ * @return Pointer to spawned entity
+ */
+Entity* zombie_spawn(EntityManager* manager, Vector3 position);
+
+/**
+ * Damage a zombie
+ * @param entity The zombie entity to damage
+ * @param damage Amount of damage to deal
+ * @return true if the zombie died, false otherwise
+ */
+bool zombie_damage(Entity* entity, int damage);
+
+// ============================================================================
+// INTERNAL CALLBACKS (called by entity system)
+// ============================================================================
+
+/**
+ * Update callback for zombie entities
+ * Handles AI, pathing, attacks and dawn despawn
+ */
+void zombie_update(Entity* entity, struct World* world, float dt);
+
+/**
+ * Render callback for zombie entities
+ * Draws the block human model with zombie colors
+ */
+void zombie_render(Entity* entity);
+
+/**
+ * Destroy callback for zombie entities
+ * Frees ZombieData
+ */
+void zombie_destroy(Entity* entity);
+
+#endif // ZOMBIE_H
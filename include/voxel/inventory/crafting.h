@@ -15,7 +15,10 @@
 // CONSTANTS
 // ============================================================================
 
-#define MAX_RECIPES 32
+#define MAX_RECIPES 64
+
+// Optional recipe file, replaces the built-in recipes when present and valid
+#define CRAFTING_RECIPE_FILE "data/recipes.txt"
 
 // ============================================================================
 // DATA STRUCTURES
@@ -45,9 +48,49 @@ typedef struct {
 
 /**
  * Initialize crafting system and load all recipes
+ * Reads CRAFTING_RECIPE_FILE if it exists, otherwise (or if the file has
+ * any error) uses the built-in recipes.
+ *
+ * Recipe file format - items by display name, recipes separated by blank
+ * lines, '#' starts a comment:
+ *
+ *   shaped 1 Wooden Pickaxe
+ *   Oak Planks, Oak Planks, Oak Planks
+ *   ., Stick, .
+ *   ., Stick, .
+ *
+ *   shapeless 4 Oak Planks
+ *   Oak Log
+ *
+ * Shaped recipes take up to 3 rows of up to 3 cells ('.' = empty), shapeless
+ * recipes one line of up to 9 ingredients.
  */
 void crafting_init(void);
 
+/**
+ * Load recipes from a recipe file, replacing the current ones
+ * Every problem is reported with its line number; on any error the
+ * current recipes are left untouched.
+ * @return true if the file was read and had no errors
+ */
+bool crafting_load_file(const char* path);
+
+/**
+ * Re-read CRAFTING_RECIPE_FILE at runtime (development hot-reload)
+ * Keeps the current recipes if the file is missing or invalid.
+ * Callers should refresh crafting outputs afterwards.
+ * @return true if new recipes were loaded
+ */
+bool crafting_reload(void);
+
+/**
+ * Check the recipe database for authoring mistakes
+ * Reports recipes dropped by MAX_RECIPES, invalid inputs/outputs,
+ * patterns shadowed by an earlier recipe, and dependency cycles.
+ * Called from crafting_init; returns number of problems found (0 = ok)
+ */
+int crafting_validate(void);
+
 /**
  * Find a recipe that matches the given crafting grid
  * Returns pointer to recipe if found, NULL otherwise
@@ -101,6 +144,13 @@ const CraftingRecipe* crafting_get_recipe(int index);
  */
 bool crafting_can_craft_recipe(Inventory* inv, const CraftingRecipe* recipe);
 
+/**
+ * Count how many times a recipe can be crafted from precomputed item totals
+ * Lets callers checking many recipes scan the inventory once
+ * (see inventory_get_totals)
+ */
+int crafting_count_crafts_from_totals(const int totals[ITEM_COUNT], const CraftingRecipe* recipe);
+
 /**
  * Find a recipe that produces a specific output item
  * Used by crafting guide to show recipe when item is clicked
@@ -108,6 +158,22 @@ bool crafting_can_craft_recipe(Inventory* inv, const CraftingRecipe* recipe);
  */
 const CraftingRecipe* crafting_find_recipe_for_output(ItemType output);
 
+/**
+ * Find recipes that consume a specific item as an ingredient
+ * Used by inventory tooltips to show what an item is used in
+ * Fills out with up to max_out recipes, returns total number of matches
+ */
+int crafting_recipes_using(ItemType input, const CraftingRecipe** out, int max_out);
+
+/**
+ * Expand an item down to raw ingredients (items with no recipe)
+ * Intermediates are crafted in whole batches and leftovers are reused,
+ * e.g. 4 sticks + 3 planks cost 1 wood log, not 2.
+ * Fills raw_out (indexed by ItemType) with the total count of each raw item
+ * Returns false if the item has no recipe or the recipes form a cycle
+ */
+bool crafting_get_raw_cost(ItemType item, int quantity, int raw_out[ITEM_COUNT]);
+
 /**
  * Count how many times a recipe can be crafted from all inventory sources
  * Includes hotbar, main inventory, and crafting grid
@@ -15,7 +15,10 @@
 // ============================================================================
 
 #define HOTBAR_SIZE 9
-#define MAIN_INVENTORY_SIZE 27
+#define MAIN_INVENTORY_COLUMNS 9
+#define MAIN_INVENTORY_BASE_ROWS 3     // Rows available without a backpack
+#define MAIN_INVENTORY_MAX_ROWS 5      // Rows with every backpack upgrade
+#define MAIN_INVENTORY_SIZE (MAIN_INVENTORY_COLUMNS * MAIN_INVENTORY_MAX_ROWS)
 #define CRAFTING_GRID_SIZE 9
 #define CRAFTING_OUTPUT_SIZE 1
 
@@ -23,20 +26,39 @@
 // DATA STRUCTURES
 // ============================================================================
 
+/**
+ * Items handed to the player when a new game starts
+ */
+typedef enum {
+    STARTING_KIT_STANDARD,      // Wooden pickaxe and a few sticks
+    STARTING_KIT_HARDCORE,      // Nothing - punch trees to get going
+    STARTING_KIT_TESTING,       // Stone tools and building blocks
+    STARTING_KIT_COUNT
+} StartingKit;
+
 /**
  * Inventory structure
  */
 typedef struct Inventory {
     ItemStack hotbar[HOTBAR_SIZE];                    // Quick access slots (1-9 keys)
-    ItemStack main_inventory[MAIN_INVENTORY_SIZE];    // 3 rows x 9 columns
+    ItemStack main_inventory[MAIN_INVENTORY_SIZE];    // main_rows x 9 columns in use
     ItemStack crafting_grid[CRAFTING_GRID_SIZE];      // 3x3 crafting input
     ItemStack crafting_output[CRAFTING_OUTPUT_SIZE];  // 1 crafting result
 
+    int main_rows;             // Unlocked main inventory rows (grows with backpacks)
     int selected_hotbar_slot;  // 0-8, which hotbar slot is active
     bool is_open;              // Is full inventory UI visible?
 
     ItemStack held_item;       // Item being dragged by cursor
     bool is_holding_item;      // Is player holding an item?
+
+    ItemStack trash_slot;      // Last discarded stack (can be taken back until replaced)
+
+    // Locked slots ignore clicks and are skipped by quick transfer
+    bool hotbar_locked[HOTBAR_SIZE];
+    bool main_locked[MAIN_INVENTORY_SIZE];
+
+    int items_crafted;         // Lifetime count of crafted items (drives crafting XP)
 } Inventory;
 
 // ============================================================================
@@ -56,9 +78,44 @@ void inventory_destroy(Inventory* inv);
 /**
  * Add items to inventory (tries hotbar first, then main inventory)
  * Returns true if all items were added, false if inventory is full
+ * (whatever fit is still added - use inventory_can_add_item first for all-or-nothing)
  */
 bool inventory_add_item(Inventory* inv, ItemType type, uint8_t count);
 
+/**
+ * Add as many items as fit, topping up matching stacks before using empty slots
+ * Returns how many didn't fit (0 if all were added)
+ */
+uint8_t inventory_add_item_partial(Inventory* inv, ItemType type, uint8_t count);
+
+/**
+ * Put the stack held on the cursor back into the hotbar/main inventory
+ * Tops up matching stacks, then uses the first empty slot, keeping durability.
+ * Returns true if the hand is empty afterwards; whatever doesn't fit stays held.
+ */
+bool inventory_return_held_item(Inventory* inv);
+
+/**
+ * Check if a hotbar/main slot is locked (same indexing as inventory_get_slot)
+ */
+bool inventory_is_slot_locked(const Inventory* inv, int index);
+
+/**
+ * Lock or unlock a hotbar/main slot; other slots can't be locked
+ * Returns the new lock state
+ */
+bool inventory_toggle_slot_lock(Inventory* inv, int index);
+
+/**
+ * Fill an empty inventory with a starting kit (hotbar first, in kit order)
+ */
+void inventory_give_starting_kit(Inventory* inv, StartingKit kit);
+
+/**
+ * Get display name for a starting kit
+ */
+const char* inventory_get_starting_kit_name(StartingKit kit);
+
 /**
  * Remove items from a specific slot
  * Returns true if successful
@@ -84,9 +141,9 @@ void inventory_set_selected_slot(Inventory* inv, int slot);
  * Get pointer to a slot by global index
  * Index mapping:
  *   0-8: Hotbar
- *   9-35: Main inventory
- *   36-44: Crafting grid
- *   45: Crafting output
+ *   9-53: Main inventory (slots past the unlocked rows return NULL)
+ *   54-62: Crafting grid
+ *   63: Crafting output
  */
 ItemStack* inventory_get_slot(Inventory* inv, int index);
 
@@ -95,6 +152,24 @@ ItemStack* inventory_get_slot(Inventory* inv, int index);
  */
 int inventory_get_total_slots(Inventory* inv);
 
+/**
+ * Get number of usable main inventory slots (main_rows x 9)
+ */
+int inventory_get_main_size(const Inventory* inv);
+
+/**
+ * Unlock additional main inventory rows (backpack upgrade)
+ * Existing items keep their slots; new rows start empty.
+ * Returns false if already at MAIN_INVENTORY_MAX_ROWS
+ */
+bool inventory_expand(Inventory* inv, int additional_rows);
+
+/**
+ * Count every item type across hotbar, main inventory and crafting grid
+ * totals is indexed by ItemType; include_held adds the cursor stack
+ */
+void inventory_get_totals(Inventory* inv, int totals[ITEM_COUNT], bool include_held);
+
 /**
  * Clear all items from inventory
  */
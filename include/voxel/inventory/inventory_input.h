@@ -20,7 +20,8 @@ typedef enum {
     SECTION_CRAFTING_GRID = 0,
     SECTION_CRAFTING_OUTPUT = 1,
     SECTION_MAIN_INVENTORY = 2,
-    SECTION_HOTBAR = 3
+    SECTION_HOTBAR = 3,
+    SECTION_TRASH = 4
 } InventorySection;
 
 // ============================================================================
@@ -35,6 +36,30 @@ typedef enum {
  */
 void inventory_input_handle_left_click(Inventory* inv, int mouse_x, int mouse_y);
 
+/**
+ * Keep crafting while the left button is held on the crafting output
+ * Call every frame with the button state; after a short delay it takes the
+ * output again at a fixed interval, until ingredients run out, the held
+ * stack is full, or the button is released.
+ */
+void inventory_input_handle_left_hold(Inventory* inv, int mouse_x, int mouse_y, bool held, float dt);
+
+/**
+ * Get the screen position of the trash slot (right of the hotbar mirror)
+ */
+void inventory_input_get_trash_position(const Inventory* inv, int* x, int* y);
+
+/**
+ * Move the controller focus cursor by one slot, wrapping at the edges
+ * Rows: crafting grid (+ output), main inventory, hotbar (+ trash)
+ */
+void inventory_input_move_focus(const Inventory* inv, int dx, int dy);
+
+/**
+ * Get the screen center of the focused slot (clicks there hit that slot)
+ */
+void inventory_input_get_focus_center(const Inventory* inv, int* x, int* y);
+
 /**
  * Handle right-click on inventory
  * - Click on empty slot with held item: place 1 item
@@ -49,11 +74,18 @@ void inventory_input_handle_right_click(Inventory* inv, int mouse_x, int mouse_y
  */
 void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y);
 
+/**
+ * Handle Ctrl+right-click: lock or unlock a hotbar/main slot
+ * Locked slots ignore clicks and are skipped by quick transfer
+ */
+void inventory_input_handle_lock_click(Inventory* inv, int mouse_x, int mouse_y);
+
 /**
  * Get the slot index and section from mouse coordinates
  * Returns slot index within that section, or -1 if no slot clicked
  * Sets section to the inventory section that was clicked
+ * Uses inv->main_rows for the main grid height and hotbar position
  */
-int inventory_input_get_clicked_slot(int mouse_x, int mouse_y, InventorySection* section);
+int inventory_input_get_clicked_slot(const Inventory* inv, int mouse_x, int mouse_y, InventorySection* section);
 
 #endif // VOXEL_INVENTORY_INPUT_H
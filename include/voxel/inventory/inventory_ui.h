@@ -23,6 +23,16 @@ typedef struct Player Player;
  */
 void inventory_ui_draw_hotbar(Inventory* inv, Texture2D atlas);
 
+/**
+ * Draw the experience bar and level just above the hotbar
+ */
+void inventory_ui_draw_xp_bar(int level, int xp, int xp_to_next);
+
+/**
+ * Draw active food effects and their remaining time right of the hotbar
+ */
+void inventory_ui_draw_effects(const float effect_times[EFFECT_COUNT]);
+
 /**
  * Draw the full inventory screen (main inventory + crafting)
  * Only visible when inventory is open (E key)
@@ -52,8 +62,9 @@ void inventory_ui_draw_chest(ChestData* chest, Inventory* inv, Texture2D atlas);
 /**
  * Handle click on chest UI
  * Transfers items between chest and player inventory
+ * @param split Move only half the stack (rounded up), e.g. on right-click
  */
-void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse_x, int mouse_y);
+void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse_x, int mouse_y, bool split);
 
 /**
  * Handle scroll input for crafting guide
@@ -73,6 +84,20 @@ bool inventory_ui_handle_guide_click(Inventory* inv, int mouse_x, int mouse_y);
  */
 void inventory_ui_handle_guide_key(int key);
 
+/**
+ * Top-left corner of the full inventory panel
+ * The panel and crafting guide are centered in the current window, so
+ * drawing and click handling must both use these.
+ */
+int inventory_ui_get_panel_x(void);
+int inventory_ui_get_panel_y(void);
+
+/**
+ * Use the color-blind friendly palette for craft status
+ * Swaps green/red for blue/orange and adds check/cross glyphs
+ */
+void inventory_ui_set_colorblind_palette(bool enabled);
+
 /**
  * Check if search input is currently active
  * Use to prevent other key bindings when typing in search
@@ -0,0 +1,74 @@
+/**
+ * Achievements
+ *
+ * Lightweight milestones ("First Pickaxe", "Lumberjack", ...) unlocked by
+ * gameplay events. Each achievement has a counter and a threshold; crossing
+ * the threshold unlocks it once and shows a short toast.
+ */
+
+#ifndef VOXEL_ACHIEVEMENTS_H
+#define VOXEL_ACHIEVEMENTS_H
+
+#include <stdbool.h>
+#include "voxel/core/block.h"
+#include "voxel/core/item.h"
+
+// Toast configuration
+#define ACHIEVEMENT_TOAST_TIME 3.0f     // Seconds a toast stays on screen
+#define ACHIEVEMENT_TOAST_QUEUE 4       // Unlocks waiting to be shown
+
+// ============================================================================
+// ACHIEVEMENT TYPES
+// ============================================================================
+
+typedef enum {
+    ACHIEVEMENT_FIRST_BLOCK,        // Place any block
+    ACHIEVEMENT_LUMBERJACK,         // Mine 100 logs
+    ACHIEVEMENT_FIRST_PICKAXE,      // Craft a wooden pickaxe
+    ACHIEVEMENT_STONE_AGE,          // Craft a stone pickaxe
+    ACHIEVEMENT_DIAMONDS,           // Mine diamond ore
+    ACHIEVEMENT_FIRST_HUNT,         // Kill an animal
+    ACHIEVEMENT_GOOD_NIGHT,         // Sleep in a bed
+    ACHIEVEMENT_COUNT
+} AchievementId;
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Reset all achievement progress
+ */
+void achievements_init(void);
+
+/**
+ * Add progress to an achievement
+ * Unlocks and queues a toast the first time the threshold is reached;
+ * further progress after unlocking is ignored.
+ * @return true if this call unlocked the achievement
+ */
+bool achievements_add_progress(AchievementId id, int amount);
+
+/**
+ * Check if an achievement is unlocked
+ */
+bool achievements_is_unlocked(AchievementId id);
+
+/**
+ * Event helpers - map gameplay events to achievement progress
+ */
+void achievements_on_block_placed(BlockType type);
+void achievements_on_block_mined(BlockType type);
+void achievements_on_item_crafted(ItemType type, int count);
+
+/**
+ * Advance the current toast timer
+ */
+void achievements_update(float dt);
+
+/**
+ * Draw the current unlock toast (top-left, 2D overlay)
+ */
+void achievements_draw_toast(void);
+
+#endif // VOXEL_ACHIEVEMENTS_H
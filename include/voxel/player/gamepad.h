@@ -0,0 +1,53 @@
+/**
+ * Gamepad Input
+ *
+ * Controller support on top of the keyboard and mouse; both can be used
+ * at the same time. Layout (Xbox names):
+ * - Left stick: move, click to sprint
+ * - Right stick: look, click to change view
+ * - A: jump / rise, B: descend, X: toggle flying
+ * - RT: mine / attack, LT: place / use
+ * - LB / RB: previous / next hotbar slot
+ * - Y: open / close inventory
+ * - Inventory open: D-pad moves the slot focus, A clicks, X right-clicks
+ */
+
+#ifndef VOXEL_GAMEPAD_H
+#define VOXEL_GAMEPAD_H
+
+#include <stdbool.h>
+#include "voxel/player/player.h"
+
+// ============================================================================
+// GAMEPAD CONSTANTS
+// ============================================================================
+
+#define GAMEPAD_ID 0                    // Only the first controller is used
+#define GAMEPAD_STICK_DEADZONE 0.2f     // Stick travel ignored around center
+#define GAMEPAD_LOOK_SPEED 1800.0f      // Look input at full tilt, in mouse pixels per second
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Check if a controller is connected
+ */
+bool gamepad_is_available(void);
+
+/**
+ * Check if a button was pressed this frame (false without a controller)
+ */
+bool gamepad_is_button_pressed(int button);
+
+/**
+ * Check if a button is held (false without a controller)
+ */
+bool gamepad_is_button_down(int button);
+
+/**
+ * Add stick movement, look and button intent to keyboard/mouse input
+ */
+void gamepad_read_player_input(PlayerInput* input, float dt);
+
+#endif // VOXEL_GAMEPAD_H
@@ -0,0 +1,37 @@
+/**
+ * Objectives
+ *
+ * Ordered beginner goals (chop a tree, craft planks, ...) shown in a small
+ * corner panel. Each goal has a predicate on the player's inventory; goals
+ * latch once met, so doing things out of order still counts.
+ */
+
+#ifndef VOXEL_OBJECTIVES_H
+#define VOXEL_OBJECTIVES_H
+
+#include <stdbool.h>
+#include "voxel/inventory/inventory.h"
+
+#define OBJECTIVES_FREE_PLAY_TIME 4.0f  // Seconds the "free play" note stays up
+
+/**
+ * Reset objective progress
+ */
+void objectives_init(void);
+
+/**
+ * Check objective predicates against the inventory
+ */
+void objectives_update(Inventory* inv, float dt);
+
+/**
+ * Check if every objective is complete (free play)
+ */
+bool objectives_all_complete(void);
+
+/**
+ * Draw the current objective panel (left side, 2D overlay)
+ */
+void objectives_draw(void);
+
+#endif // VOXEL_OBJECTIVES_H
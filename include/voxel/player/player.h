@@ -10,6 +10,7 @@
 #include <raylib.h>
 #include <stdbool.h>
 #include "voxel/world/world.h"  // Need World type
+#include "voxel/core/item.h"
 
 // Forward declarations
 typedef struct Inventory Inventory;
@@ -24,6 +25,44 @@ typedef enum {
     VIEW_MODE_THIRD_PERSON_FRONT  // Camera in front, facing player
 } ViewMode;
 
+// ============================================================================
+// INPUT
+// ============================================================================
+
+/**
+ * One frame of player intent, decoupled from the keyboard and mouse so the
+ * simulation can be driven without a window (scripted runs, tools)
+ */
+typedef struct {
+    Vector2 look_delta;         // Raw mouse movement (scaled by mouse_sensitivity)
+    float forward;              // -1 (back) to 1 (forward)
+    float strafe;               // -1 (right) to 1 (left)
+    bool jump;                  // Jump when walking, rise when flying
+    bool descend;               // Sink when flying
+    bool sprint;
+    bool toggle_flying;
+    bool toggle_view;
+} PlayerInput;
+
+// ============================================================================
+// EXPERIENCE
+// ============================================================================
+
+#define PLAYER_XP_BASE 10               // XP needed to go from level 1 to 2
+#define PLAYER_XP_GROWTH 1.25f          // Each level needs this much more XP
+#define PLAYER_XP_PER_BLOCK 1           // Mining a block
+#define PLAYER_XP_PER_HARD_BLOCK 2      // Mining a block that needs a tool (stone, ores)
+#define PLAYER_XP_PER_CRAFTED_ITEM 1    // Each item taken from the crafting output
+
+#define PLAYER_SPEED_BONUS_PER_LEVEL 0.04f  // +4% mining speed per level
+#define PLAYER_SPEED_BONUS_MAX 0.40f        // Bonus stops growing at +40%
+
+// ============================================================================
+// EFFECTS
+// ============================================================================
+
+#define PLAYER_SPEED_EFFECT_BONUS 0.30f     // Swiftness: +30% movement speed
+
 // ============================================================================
 // PLAYER STATE
 // ============================================================================
@@ -68,6 +107,23 @@ typedef struct Player {
     // Lighting
     Vector3 ambient_light;      // Current ambient light color (for model rendering)
 
+    // Home marker
+    Vector3 home;               // First placed block, or set with the home key
+    bool has_home;
+
+    // Experience
+    int level;                  // Starts at 1
+    int xp;                     // XP collected toward the next level
+    int xp_to_next;             // XP needed for the next level
+
+    // Food effects
+    float effect_times[EFFECT_COUNT];  // Seconds left on each effect (0 = inactive)
+
+    // Camera shake (view only - targeting uses the steady camera)
+    float shake_intensity;      // Offset in blocks when the shake started
+    float shake_duration;       // Total shake length in seconds
+    float shake_time;           // Seconds left (0 = not shaking)
+
 } Player;
 
 // ============================================================================
@@ -90,6 +146,18 @@ void player_destroy(Player* player);
  */
 void player_update(Player* player, World* world, float dt);
 
+/**
+ * Read this frame's intent from the keyboard and mouse
+ * Returns empty input when the window is not focused
+ */
+PlayerInput player_read_input(void);
+
+/**
+ * Advance the player by one step from the given intent
+ * Pure simulation - touches no window, keyboard or mouse state
+ */
+void player_apply_input(Player* player, World* world, const PlayerInput* input, float dt);
+
 /**
  * Update player physics only (gravity, collisions) - no input handling
  * Use when game is paused but world should continue (like Minecraft menu)
@@ -127,6 +195,34 @@ bool player_collides_with_position(Player* player, Vector3 block_pos);
  */
 void player_render_model(Player* player);
 
+/**
+ * Give the player experience, levelling up as thresholds are reached
+ * @return true if the player gained at least one level
+ */
+bool player_add_xp(Player* player, int amount);
+
+/**
+ * Mining speed multiplier from the player's level (1.0 at level 1, capped)
+ */
+float player_get_speed_multiplier(const Player* player);
+
+/**
+ * Eat a food item, starting its effect
+ * Eating again while the effect is active refreshes it instead of stacking.
+ * @return false if the item isn't food
+ */
+bool player_consume(Player* player, ItemType food);
+
+/**
+ * Count down active effects - called by player_apply_input
+ */
+void player_update_effects(Player* player, float dt);
+
+/**
+ * Check if an effect is currently active
+ */
+bool player_has_effect(const Player* player, EffectType effect);
+
 /**
  * Start a swing animation (for hitting/mining)
  */
@@ -143,4 +239,31 @@ float player_update_swing(Player* player, float dt);
  */
 float player_get_swing_angle(Player* player);
 
+/**
+ * Shake the view; a weaker shake doesn't cut a stronger one short
+ * Does nothing while camera shake is disabled in the settings.
+ * @param intensity Starting offset in blocks, fading to 0 over duration
+ */
+void player_shake_camera(Player* player, float intensity, float duration);
+
+/**
+ * Fade out the camera shake - call every frame
+ */
+void player_update_shake(Player* player, float dt);
+
+/**
+ * Current shake offset size in blocks (0 once the shake has faded)
+ */
+float player_get_shake_strength(const Player* player);
+
+/**
+ * Camera for drawing the world: player_get_camera plus the shake offset
+ */
+Camera3D player_get_view_camera(Player* player);
+
+/**
+ * Enable or disable camera shake (from settings)
+ */
+void player_set_camera_shake_enabled(bool enabled);
+
 #endif // VOXEL_PLAYER_H
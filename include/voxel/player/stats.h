@@ -0,0 +1,77 @@
+/**
+ * Game Statistics
+ *
+ * Running totals for the current game: playtime, distance traveled and
+ * counters bumped by gameplay events (blocks placed/mined, logs chopped,
+ * items crafted). Shown beside the pause menu.
+ */
+
+#ifndef VOXEL_STATS_H
+#define VOXEL_STATS_H
+
+#include "voxel/core/block.h"
+
+#define STATS_MAX_STEP 4.0f     // Longer single-frame moves are teleports, not travel
+
+// ============================================================================
+// STAT TYPES
+// ============================================================================
+
+typedef enum {
+    STAT_BLOCKS_PLACED,
+    STAT_BLOCKS_MINED,
+    STAT_LOGS_CHOPPED,
+    STAT_ITEMS_CRAFTED,
+    STAT_COUNT
+} StatId;
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Reset all counters, playtime and distance
+ */
+void stats_init(void);
+
+/**
+ * Add to a counter
+ */
+void stats_add(StatId id, int amount);
+
+/**
+ * Get a counter's value
+ */
+int stats_get(StatId id);
+
+/**
+ * Count a mined block (and a chopped log for wood)
+ */
+void stats_on_block_mined(BlockType type);
+
+/**
+ * Add playtime - only call while the game is focused and unpaused
+ */
+void stats_add_playtime(float dt);
+
+/**
+ * Add one frame of player movement; steps over STATS_MAX_STEP are ignored
+ */
+void stats_add_distance(float distance);
+
+/**
+ * Total playtime in seconds
+ */
+float stats_get_playtime(void);
+
+/**
+ * Total distance traveled in blocks
+ */
+float stats_get_distance(void);
+
+/**
+ * Draw the stats panel with its top-left corner at (x, y)
+ */
+void stats_draw(int x, int y);
+
+#endif // VOXEL_STATS_H
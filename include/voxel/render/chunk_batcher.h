@@ -34,7 +34,9 @@ typedef struct ChunkBatch {
     int batch_x, batch_z;           // Batch coordinates (chunk coords / 2)
     Mesh opaque_mesh;               // Combined opaque mesh for all 4 chunks
     Mesh transparent_mesh;          // Combined transparent mesh
+    Mesh opaque_lod_mesh;           // Combined opaque LOD mesh for distant rendering
     bool opaque_valid;              // Opaque mesh uploaded to GPU
+    bool opaque_lod_valid;          // Opaque LOD mesh uploaded to GPU
     bool transparent_valid;         // Transparent mesh uploaded to GPU
     bool dirty;                     // Needs rebuild
     Chunk* chunks[BATCH_SIZE][BATCH_SIZE];  // References to chunks (may be NULL)
@@ -119,6 +121,7 @@ void chunk_batcher_update(ChunkBatcher* batcher, int max_rebuilds);
 
 /**
  * Render all batched opaque meshes
+ * Batches farther than the world's LOD distance draw their LOD mesh
  * camera_pos and center_chunk for frustum/distance culling
  */
 void chunk_batcher_render_opaque(ChunkBatcher* batcher, World* world,
@@ -19,6 +19,7 @@
 typedef enum {
     PARTICLE_TYPE_BLOCK_BREAK,   // Debris when mining blocks
     PARTICLE_TYPE_WATER_SPLASH,  // Water entry/exit splash
+    PARTICLE_TYPE_SPARK,         // Bright sparks when mining ore
 } ParticleType;
 
 // Single particle data
@@ -54,6 +55,11 @@ void particle_system_init(void);
  */
 void particle_system_destroy(void);
 
+/**
+ * Reload the particle shader from disk, keeping the current one if that fails
+ */
+bool particle_system_reload_shader(void);
+
 /**
  * Update all active particles
  * @param dt Delta time in seconds
@@ -74,6 +80,15 @@ void particle_system_render(Camera3D camera);
  */
 void particle_spawn_block_break(Vector3 position, BlockType block_type, int count);
 
+/**
+ * Spawn sparks tinted by ore type when an ore block is mined
+ * Does nothing for blocks that aren't ores
+ * @param position World position of the mined block
+ * @param block_type Type of block that was mined
+ * @param count Number of particles to spawn
+ */
+void particle_spawn_ore_sparks(Vector3 position, BlockType block_type, int count);
+
 /**
  * Spawn water splash particles
  * @param position World position of splash
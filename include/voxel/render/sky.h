@@ -2,9 +2,13 @@
 #define SKY_H
 
 #include <raylib.h>
+#include <stdbool.h>
 
 void sky_init(void);
 void sky_render(Camera3D camera, float time_of_day);
 void sky_destroy(void);
 
+// Reload the sky shader from disk, keeping the current one if that fails
+bool sky_reload_shader(void);
+
 #endif // SKY_H
@@ -0,0 +1,47 @@
+/**
+ * Floating Text System
+ *
+ * Short-lived "+N Item" popups that rise and fade above the place
+ * where the player gained items (mined block, killed mob).
+ */
+
+#ifndef VOXEL_FLOATING_TEXT_H
+#define VOXEL_FLOATING_TEXT_H
+
+#include <raylib.h>
+#include "voxel/core/item.h"
+
+// Floating text configuration
+#define MAX_FLOATING_TEXTS 32
+#define FLOATING_TEXT_LENGTH 48
+#define FLOATING_TEXT_LIFETIME 1.2f     // Seconds before a popup disappears
+#define FLOATING_TEXT_RISE_SPEED 0.8f   // Blocks per second
+#define FLOATING_TEXT_FONT_SIZE 18
+
+/**
+ * Clear all active popups
+ */
+void floating_text_clear(void);
+
+/**
+ * Spawn a popup at a world position
+ * Oldest popup is replaced when the pool is full
+ */
+void floating_text_spawn(Vector3 position, const char* text, Color color);
+
+/**
+ * Spawn a "+N ItemName" popup for an item gain
+ */
+void floating_text_spawn_item_gain(Vector3 position, ItemType item, int count);
+
+/**
+ * Rise, fade and expire popups
+ */
+void floating_text_update(float dt);
+
+/**
+ * Draw popups as a 2D overlay (call after EndMode3D)
+ */
+void floating_text_draw(Camera3D camera);
+
+#endif // VOXEL_FLOATING_TEXT_H
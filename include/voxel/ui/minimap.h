@@ -19,6 +19,7 @@ typedef struct NetworkContext NetworkContext;
 #define MINIMAP_SIZE 150         // Size in pixels (square)
 #define MINIMAP_RADIUS 64        // Radius in blocks to display
 #define MINIMAP_MARGIN 10        // Margin from screen edge
+#define MINIMAP_WAYPOINT_PICK_RADIUS 6  // Click distance in pixels to select a waypoint
 
 // Opaque minimap type
 typedef struct Minimap Minimap;
@@ -45,4 +46,19 @@ void minimap_update(Minimap* minimap, World* world, Player* player);
  */
 void minimap_draw(Minimap* minimap, Player* player, NetworkContext* network);
 
+/**
+ * Find the waypoint nearest to a screen position on the minimap
+ * @return Waypoint slot index, or -1 if none is within MINIMAP_WAYPOINT_PICK_RADIUS
+ */
+int minimap_pick_waypoint(Minimap* minimap, Player* player, int mouse_x, int mouse_y);
+
+/**
+ * Export a top-down map of the loaded world around the player as a PNG
+ * One pixel per block, covering the current view distance. Rendered on the
+ * CPU into an Image, so it doesn't touch the render loop. Unexplored
+ * columns are drawn as fog, like on the minimap.
+ * @return true if the file was written
+ */
+bool minimap_export_png(World* world, Player* player, const char* path);
+
 #endif // VOXEL_MINIMAP_H
@@ -0,0 +1,58 @@
+/**
+ * Ruler Tool
+ *
+ * Measures block distances for planning builds. While ruler mode is on,
+ * holding the left mouse button drags a line from the block where the
+ * press started to the targeted block, with live dx/dy/dz and distance.
+ * Purely an overlay: it never touches the world.
+ */
+
+#ifndef VOXEL_RULER_H
+#define VOXEL_RULER_H
+
+#include <raylib.h>
+#include <stdbool.h>
+
+// Ruler configuration
+#define RULER_FONT_SIZE 16
+#define RULER_COLOR (Color){255, 220, 80, 255}
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Turn ruler mode on or off (clears the current measurement)
+ */
+void ruler_toggle(void);
+
+/**
+ * Check if ruler mode is on (left click measures instead of mining)
+ */
+bool ruler_is_active(void);
+
+/**
+ * Start, drag or finish a measurement
+ * @param has_target Whether a block is targeted
+ * @param target Targeted block position
+ * @param pressed Left mouse pressed this frame
+ * @param held Left mouse currently down
+ */
+void ruler_update(bool has_target, Vector3 target, bool pressed, bool held);
+
+/**
+ * Straight-line distance in blocks between two block positions
+ */
+float ruler_block_distance(Vector3 from, Vector3 to);
+
+/**
+ * Draw the measured line and endpoint boxes (call inside BeginMode3D)
+ */
+void ruler_draw_3d(void);
+
+/**
+ * Draw the measurement readout below the crosshair (call after EndMode3D)
+ */
+void ruler_draw_hud(void);
+
+#endif // VOXEL_RULER_H
@@ -24,6 +24,13 @@ typedef enum {
     SETTINGS_CATEGORY_COUNT
 } SettingsCategory;
 
+// ============================================================================
+// DISPLAY DEFAULTS
+// ============================================================================
+
+#define SETTING_TARGET_FPS_DEFAULT 60   // 0 = uncapped
+#define SETTING_VSYNC_DEFAULT false
+
 // ============================================================================
 // GAME SETTINGS STRUCTURE (matches game.c)
 // ============================================================================
@@ -33,6 +40,10 @@ typedef struct GameSettings {
     int view_distance;           // 2-32 chunks
     int lod_distance;            // 4-16 chunks
     int batch_rebuilds;          // 4-64 per frame
+    int target_fps;              // 30/60/120/144, 0 = uncapped
+    bool vsync;
+    bool colorblind_palette;     // Blue/orange craft status with glyphs
+    bool camera_shake;           // Shake the view on hits and hard landings
 
     // World
     float day_speed;             // 0.01-1.0 hours/sec
@@ -105,6 +116,12 @@ void settings_menu_draw(SettingsMenu* menu);
  */
 void settings_menu_apply(SettingsMenu* menu, World* world);
 
+/**
+ * Apply frame rate cap, vsync and UI palette
+ * Called at startup and whenever settings are applied
+ */
+void settings_apply_display(const GameSettings* settings);
+
 /**
  * Get settings item count for a category
  */
@@ -0,0 +1,93 @@
+/**
+ * Waypoint Markers
+ *
+ * Screen-space markers for world positions the player wants to find
+ * again. On-screen targets get a label at their projected position;
+ * off-screen targets get an arrow clamped to the screen edge.
+ */
+
+#ifndef VOXEL_WAYPOINT_H
+#define VOXEL_WAYPOINT_H
+
+#include <raylib.h>
+#include <stdbool.h>
+
+// Marker configuration
+#define WAYPOINT_EDGE_MARGIN 40     // Pixels between edge arrows and screen border
+#define WAYPOINT_ARROW_SIZE 12      // Arrow length in pixels
+#define WAYPOINT_FONT_SIZE 14
+
+// Waypoint list
+#define MAX_WAYPOINTS 16
+#define WAYPOINT_LABEL_LENGTH 24
+
+/**
+ * A player-placed waypoint
+ * Slots are stable: removing one leaves a hole instead of shifting the rest
+ */
+typedef struct {
+    Vector3 position;
+    char label[WAYPOINT_LABEL_LENGTH];
+    Color color;
+    bool active;
+} Waypoint;
+
+// ============================================================================
+// WAYPOINT LIST
+// ============================================================================
+
+/**
+ * Remove all waypoints
+ */
+void waypoint_clear(void);
+
+/**
+ * Add a waypoint with an auto-numbered label and color
+ * @return Slot index, or -1 if all slots are used
+ */
+int waypoint_add(Vector3 position);
+
+/**
+ * Remove a waypoint by slot index (other slots keep their index)
+ */
+void waypoint_remove(int index);
+
+/**
+ * Get a waypoint by slot index
+ * @return Waypoint, or NULL if the slot is empty or out of range
+ */
+const Waypoint* waypoint_get(int index);
+
+/**
+ * Draw markers for all waypoints
+ */
+void waypoint_draw_all(Camera3D camera, Vector3 from);
+
+// ============================================================================
+// MARKERS
+// ============================================================================
+
+/**
+ * Compute where a marker for a world position should be drawn
+ *
+ * @param camera Current camera
+ * @param target World position of the marker
+ * @param out_screen Output: marker position, clamped inside the screen margin
+ * @param out_dir Output: unit direction from screen center toward the target
+ * @return true if the target is on screen (no clamping needed)
+ */
+bool waypoint_get_screen_marker(Camera3D camera, Vector3 target, Vector2* out_screen, Vector2* out_dir);
+
+/**
+ * Draw a marker with label and distance
+ * On screen: label at the target. Off screen: edge arrow pointing at it.
+ *
+ * @param camera Current camera
+ * @param target World position of the marker
+ * @param from Position distance is measured from (usually the player)
+ * @param label Marker label
+ * @param color Marker color
+ */
+void waypoint_draw_marker(Camera3D camera, Vector3 target, Vector3 from, const char* label, Color color);
+
+#endif // VOXEL_WAYPOINT_H
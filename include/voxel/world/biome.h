@@ -27,6 +27,9 @@ typedef enum {
 // BIOME PROPERTIES
 // ============================================================================
 
+#define BIOME_HIGHLAND_Y 176           // Surface height where grass biomes turn to conifers
+#define BIOME_HIGHLAND_SPRUCE_CHANCE 0.7f  // Chance of spruce on highland grass
+
 typedef struct {
     const char* name;
     BlockType surface_block;      // Top surface block (GRASS, SAND, SNOW)
@@ -75,4 +78,16 @@ const BiomeProperties* biome_get_properties(BiomeType type);
  */
 const char* biome_get_name(BiomeType type);
 
+/**
+ * Pick a tree type for a biome at a given surface height
+ * Highland grass favors spruce; otherwise uses the biome's primary/secondary trees.
+ * Deterministic for the same inputs.
+ *
+ * @param props Biome properties at the tree position
+ * @param surface_y Height of the block the tree grows on
+ * @param roll Random value in [0, 1) (position hash during generation)
+ * @return Tree type to place
+ */
+TreeType biome_pick_tree_type(const BiomeProperties* props, int surface_y, float roll);
+
 #endif // VOXEL_BIOME_H
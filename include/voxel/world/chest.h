@@ -75,8 +75,8 @@ void chest_generate_dungeon_loot(ChestData* chest, unsigned int seed);
 bool chest_is_empty(const ChestData* chest);
 
 /**
- * Try to add an item to chest (first available slot)
- * Returns true if item was added successfully
+ * Try to add an item to chest, topping up matching stacks before empty slots
+ * Returns true if item was added successfully; nothing is added otherwise
  */
 bool chest_add_item(ChestData* chest, ItemStack item);
 
@@ -0,0 +1,48 @@
+/**
+ * Map Discovery (Fog of War)
+ *
+ * Tracks which world columns the player has explored. Columns within
+ * DISCOVERY_REVEAL_RADIUS blocks of the player are revealed as they move;
+ * the minimap and map export draw everything else as fog.
+ *
+ * Stored per chunk column as a 16x16 bitmask, independent of loaded chunks,
+ * so explored areas stay revealed after their chunks unload.
+ */
+
+#ifndef VOXEL_DISCOVERY_H
+#define VOXEL_DISCOVERY_H
+
+#include <stdbool.h>
+
+// ============================================================================
+// DISCOVERY CONSTANTS
+// ============================================================================
+
+#define DISCOVERY_REVEAL_RADIUS 24      // Reveal radius around the player, in blocks
+#define DISCOVERY_MAX_CHUNKS 8192       // Chunk columns that can be tracked
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Forget all discovered columns
+ */
+void discovery_clear(void);
+
+/**
+ * Reveal every column within radius blocks of (x, z)
+ */
+void discovery_reveal(int x, int z, int radius);
+
+/**
+ * Reveal radius blocks around the player, only when they entered a new block column
+ */
+void discovery_update(float player_x, float player_z, int radius);
+
+/**
+ * Check if a world column has been discovered
+ */
+bool discovery_is_discovered(int x, int z);
+
+#endif // VOXEL_DISCOVERY_H
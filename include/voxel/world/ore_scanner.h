@@ -0,0 +1,89 @@
+/**
+ * Ore Scanner
+ *
+ * While the player holds an Ore Scanner, ore blocks within a bounded cube
+ * around them are found periodically and shown as color-coded markers on
+ * the minimap and in the world. Only the scan cube is queried, never the
+ * whole loaded world, and ores that have been mined drop off the list on
+ * the next scan.
+ */
+
+#ifndef VOXEL_ORE_SCANNER_H
+#define VOXEL_ORE_SCANNER_H
+
+#include <raylib.h>
+#include <stdbool.h>
+#include "voxel/core/block.h"
+
+// Forward declaration
+typedef struct World World;
+
+// ============================================================================
+// SCANNER CONSTANTS
+// ============================================================================
+
+#define ORE_SCANNER_RADIUS 16           // Half-size of the scanned cube, in blocks
+#define ORE_SCANNER_INTERVAL 1.0f       // Seconds between scans
+#define ORE_SCANNER_MAX_RESULTS 256     // Ores kept per scan (extra ones are skipped)
+
+/**
+ * One ore block found by the last scan
+ */
+typedef struct {
+    int x, y, z;
+    BlockType type;
+} OreScanResult;
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Forget the last scan
+ */
+void ore_scanner_clear(void);
+
+/**
+ * Check if a block type shows up on the scanner
+ */
+bool ore_scanner_is_ore(BlockType type);
+
+/**
+ * Marker color for an ore type
+ */
+Color ore_scanner_get_color(BlockType type);
+
+/**
+ * Scan every block in the cube of ORE_SCANNER_RADIUS around a position
+ * @return Number of ores found
+ */
+int ore_scanner_scan(World* world, int center_x, int center_y, int center_z);
+
+/**
+ * Rescan on a timer while active; clears the results when inactive
+ * @param active Whether the player is holding a scanner
+ */
+void ore_scanner_update(World* world, Vector3 position, bool active, float dt);
+
+/**
+ * Check if the scanner has results to show
+ */
+bool ore_scanner_is_active(void);
+
+/**
+ * Number of ores found by the last scan
+ */
+int ore_scanner_get_count(void);
+
+/**
+ * Get a scan result by index (NULL if out of range)
+ */
+const OreScanResult* ore_scanner_get(int index);
+
+/**
+ * Draw markers around found ores, visible through terrain
+ * Call inside BeginMode3D. Ores mined since the last scan are skipped.
+ */
+void ore_scanner_draw_3d(World* world);
+
+#endif // VOXEL_ORE_SCANNER_H
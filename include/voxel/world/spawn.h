@@ -11,6 +11,7 @@
 #include "voxel/entity/entity.h"
 #include "voxel/world/biome.h"
 #include "voxel/world/terrain.h"
+#include "voxel/core/rng.h"
 #include <stdbool.h>
 
 // Forward declarations
@@ -22,6 +23,17 @@ struct World;
 
 #define MAX_HERD_RULES_PER_BIOME 4
 
+// Per-species population caps (chunk spawning stops once reached)
+#define SPAWN_CAP_SHEEP 48
+#define SPAWN_CAP_PIG 32
+
+// Night mob spawning (around the player, not per chunk)
+#define SPAWN_CAP_ZOMBIE_MIN 2          // Zombie cap at dusk and dawn
+#define SPAWN_CAP_ZOMBIE_MAX 10         // Zombie cap at midnight
+#define SPAWN_NIGHT_INTERVAL 6.0f       // Seconds between spawn attempts
+#define SPAWN_NIGHT_MIN_DISTANCE 24.0f  // Never closer than this to the player
+#define SPAWN_NIGHT_MAX_DISTANCE 40.0f
+
 /**
  * Defines how a single herd type spawns
  */
@@ -62,6 +74,28 @@ void spawn_system_init(void);
  */
 void spawn_animals_for_chunk(struct World* world, int chunk_x, int chunk_z, TerrainParams params);
 
+/**
+ * Spawn hostile mobs around the player at night
+ * Also removes mobs that went inactive (e.g. zombies at sunrise).
+ * The cap ramps from SPAWN_CAP_ZOMBIE_MIN at dusk to SPAWN_CAP_ZOMBIE_MAX
+ * at midnight.
+ *
+ * @param world World containing entity manager and player
+ * @param dt Delta time in seconds
+ */
+void spawn_night_mobs(struct World* world, float dt);
+
+/**
+ * Get the zombie cap for a time of day (0 during the day)
+ */
+int spawn_get_night_cap(float time_of_day);
+
+/**
+ * Get the population cap for an animal type
+ * @return Max live animals of that type, 0 if it never spawns naturally
+ */
+int spawn_get_population_cap(EntityType type);
+
 /**
  * Spawn a herd of animals at a position
  *
@@ -71,9 +105,10 @@ void spawn_animals_for_chunk(struct World* world, int chunk_x, int chunk_z, Terr
  * @param count Number of animals to spawn
  * @param radius Spread radius for herd
  * @param params Terrain parameters for height lookup
+ * @param rng Generator for placement and wool colors
  */
 void spawn_herd(EntityManager* manager, EntityType type, Vector3 center,
-                int count, float radius, TerrainParams params);
+                int count, float radius, TerrainParams params, GameRng* rng);
 
 /**
  * Get spawn rules for a specific biome
@@ -44,6 +44,10 @@ typedef struct {
     int bedrock_start;           // Y-level where bedrock mixing starts (default: 8)
     int bedrock_solid;           // Y-level of solid bedrock (default: 4)
 
+    // Abundance multipliers (1.0 = standard generation, 0.0 = none)
+    float tree_density;          // Scales every biome's tree density (default: 1.0)
+    float ore_abundance;         // Scales every ore frequency (default: 1.0)
+
     // Ore generation parameters
     float coal_frequency;        // Coal vein frequency (default: 0.15)
     int coal_min_y;              // Min Y for coal (default: 48)
@@ -100,6 +104,14 @@ typedef struct {
     bool generate_water_pools;   // Enable water in caves (default: true)
     int water_pool_max_y;        // Maximum Y for water pools (default: 80)
     float water_pool_frequency;  // Chance per low point (default: 0.15)
+
+    // Surface water settings - rivers and lakes
+    bool generate_rivers;        // Carve rivers and flood low ground (default: true)
+    int water_level;             // Surface water height for rivers and lakes (default: 150)
+    float river_frequency;       // River noise frequency, lower = longer rivers (default: 0.002)
+    float river_width;           // River channel width in noise units (default: 0.035)
+    float river_bank_width;      // Valley slope width in noise units (default: 0.12)
+    int river_depth;             // River bed depth below water level (default: 4)
 } TerrainParams;
 
 // ============================================================================
@@ -0,0 +1,103 @@
+/**
+ * Weather System
+ *
+ * Occasional rain and fog, picked by the world's own weather RNG so a seed
+ * replays the same forecast. Weather fades in and out over
+ * WEATHER_FADE_TIME instead of switching instantly.
+ *
+ * Gameplay tie-ins (nothing else reads the weather):
+ * - Rain makes saplings grow faster
+ * - Fog shortens the view distance and the map reveal radius
+ */
+
+#ifndef VOXEL_WEATHER_H
+#define VOXEL_WEATHER_H
+
+#include "voxel/core/rng.h"
+#include <stdint.h>
+
+// ============================================================================
+// WEATHER CONSTANTS
+// ============================================================================
+
+#define WEATHER_CLEAR_TIME_MIN 240.0f   // Clear spell length range, in seconds
+#define WEATHER_CLEAR_TIME_MAX 600.0f
+#define WEATHER_SPELL_TIME_MIN 90.0f    // Rain/fog spell length range, in seconds
+#define WEATHER_SPELL_TIME_MAX 240.0f
+#define WEATHER_RAIN_CHANCE 0.6f        // Chance a spell after clear weather is rain (else fog)
+#define WEATHER_FADE_TIME 20.0f         // Seconds to fade weather fully in or out
+
+#define WEATHER_RAIN_GROWTH_BONUS 0.5f  // Extra sapling growth speed in full rain (+50%)
+#define WEATHER_FOG_VIEW_SCALE 0.35f    // Fog distance multiplier in full fog
+#define WEATHER_FOG_REVEAL_SCALE 0.5f   // Map reveal radius multiplier in full fog
+
+// ============================================================================
+// WEATHER DATA
+// ============================================================================
+
+typedef enum {
+    WEATHER_CLEAR,
+    WEATHER_RAIN,
+    WEATHER_FOG,
+    WEATHER_COUNT
+} WeatherType;
+
+typedef struct {
+    WeatherType type;       // Weather currently showing (or fading out)
+    WeatherType next;       // Weather to fade into once type has faded out
+    float intensity;        // 0-1 strength of type
+    float timer;            // Seconds until the next weather is picked
+    GameRng rng;            // Forecast randomness, separate from gameplay RNG
+} Weather;
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Start clear weather with a seeded forecast
+ */
+void weather_init(Weather* weather, uint32_t seed);
+
+/**
+ * Advance the forecast and fades by one simulation step
+ */
+void weather_update(Weather* weather, float dt);
+
+/**
+ * Current rain strength (0 = none, 1 = full rain)
+ */
+float weather_get_rain(const Weather* weather);
+
+/**
+ * Current fog strength (0 = none, 1 = full fog)
+ */
+float weather_get_fog(const Weather* weather);
+
+/**
+ * Sapling growth speed multiplier (1.0 when dry)
+ */
+float weather_get_growth_rate(const Weather* weather);
+
+/**
+ * Scale a view or fog distance down by the current fog
+ */
+float weather_scale_view_distance(const Weather* weather, float distance);
+
+/**
+ * Scale the map reveal radius down by the current fog (at least 1 block)
+ */
+int weather_scale_reveal_radius(const Weather* weather, int radius);
+
+/**
+ * Display name of a weather type
+ */
+const char* weather_get_name(WeatherType type);
+
+/**
+ * Draw rain streaks and fog haze over the screen
+ * @param time Seconds for animating the rain (e.g. GetTime())
+ */
+void weather_draw_overlay(const Weather* weather, int screen_width, int screen_height, float time);
+
+#endif // VOXEL_WEATHER_H
@@ -9,6 +9,9 @@
 
 #include "voxel/world/chunk.h"
 #include "voxel/world/terrain.h"
+#include "voxel/world/biome.h"
+#include "voxel/world/weather.h"
+#include "voxel/core/rng.h"
 #include <stdint.h>
 #include <stdbool.h>
 
@@ -26,6 +29,19 @@ typedef struct ChunkBatcher ChunkBatcher;
 
 #define WORLD_MAX_CHUNKS 1024        // Maximum chunks loaded at once
 #define WORLD_VIEW_DISTANCE 8        // Chunks visible in each direction
+#define WORLD_DAYS_PER_SEASON 7      // Days before the season changes
+
+// ============================================================================
+// CALENDAR
+// ============================================================================
+
+typedef enum {
+    SEASON_SPRING,
+    SEASON_SUMMER,
+    SEASON_AUTUMN,
+    SEASON_WINTER,
+    SEASON_COUNT
+} Season;
 
 // ============================================================================
 // CHUNK HASH MAP
@@ -54,10 +70,15 @@ typedef struct World {
     int center_chunk_x;      // Center of loaded chunks (camera position)
     int center_chunk_z;
     int view_distance;       // How many chunks to load around center
+    int lod_distance;        // Chunks beyond this distance render their LOD mesh
     TerrainParams terrain_params;  // Terrain generation parameters
     Player* player;          // Reference to player (for entity AI)
     EntityManager* entity_manager;  // Entity manager for mobs
     float time_of_day;       // Current time (0-24 hours) for lighting
+    int day;                 // Current day, starting at 1 (advances at midnight)
+    Weather weather;         // Rain/fog state (seeded with the world)
+    uint32_t seed;           // World seed (terrain noise and gameplay RNG)
+    GameRng rng;             // Gameplay randomness: mob AI, spawning, loot, saplings
     WaterUpdateQueue* water_queue;  // Water flow update system
     int game_tick;           // Game tick counter for water updates
     ChestRegistry* chest_registry;  // Chest data storage
@@ -77,6 +98,12 @@ typedef struct World {
  */
 World* world_create(TerrainParams terrain_params);
 
+/**
+ * Seed the world's gameplay RNG (call before chunks start generating)
+ * Equal seeds with equal inputs replay the same mob movement, spawns and loot.
+ */
+void world_set_seed(World* world, uint32_t seed);
+
 /**
  * Destroy world and free all chunks
  */
@@ -104,6 +131,35 @@ Block world_get_block(World* world, int x, int y, int z);
  */
 void world_set_block(World* world, int x, int y, int z, Block block);
 
+/**
+ * Find the block of a type whose center is nearest to a point
+ * Ties go to the first block found, so the result is stable.
+ * @param radius Search radius in blocks (block centers farther away are ignored)
+ * @return true if one was found (written to out_x/y/z)
+ */
+bool world_find_nearest_block(World* world, Vector3 center, float radius, BlockType type,
+                              int* out_x, int* out_y, int* out_z);
+
+/**
+ * Everything the debug block inspector shows about one block
+ */
+typedef struct {
+    Block block;
+    bool loaded;                // Chunk is generated (block is air otherwise)
+    int chunk_x, chunk_z;
+    int local_x, local_z;       // Position inside the chunk
+    BiomeType biome;
+    int surface_height;         // Height the terrain generator gives this column
+    bool has_chest_data;        // Chest contents are stored at this position
+} BlockInspection;
+
+/**
+ * Gather debug information about a block
+ * Biome and surface height are recomputed from the seed, so this is
+ * slower than world_get_block - only call it for debug displays.
+ */
+BlockInspection world_inspect_block(World* world, int x, int y, int z);
+
 /**
  * Update world - load/unload chunks based on center position
  * Call this when camera moves to stream chunks
@@ -146,6 +202,26 @@ void world_to_local_coords(int world_x, int world_y, int world_z,
  */
 Vector3 world_get_ambient_color(float time_of_day);
 
+/**
+ * Check if a time of day is night (hostile mobs spawn, 20:00-05:00)
+ */
+bool world_is_night(float time_of_day);
+
+/**
+ * Get the season for a day (day 1 is the first day of spring)
+ */
+Season world_get_season(int day);
+
+/**
+ * Display name of a season
+ */
+const char* world_get_season_name(Season season);
+
+/**
+ * Plant growth speed multiplier for a season (1.0 = normal)
+ */
+float world_get_growth_rate(Season season);
+
 /**
  * Get the entity manager for this world
  */
@@ -166,6 +242,17 @@ int world_get_view_distance(World* world);
  */
 void world_set_view_distance(World* world, int distance);
 
+/**
+ * Get current LOD distance (in chunks)
+ */
+int world_get_lod_distance(World* world);
+
+/**
+ * Set LOD distance (in chunks, clamped to 2-32)
+ * Chunks farther than this from the center draw a simplified mesh
+ */
+void world_set_lod_distance(World* world, int distance);
+
 /**
  * Set batch rebuilds per frame (for settings menu)
  */
@@ -0,0 +1,218 @@
+/**
+ * Katalis World Update Benchmark
+ *
+ * Times the world simulation without rendering: builds a seeded world,
+ * places water sources and saplings, spawns animals, then runs a fixed
+ * number of simulation steps and reports steps per second.
+ *
+ * Everything is generated on the main thread and the view distance matches
+ * the generated area, so no chunks go to the worker threads and equal seeds
+ * replay the same simulation. The final checksum shows that: it should
+ * match between runs, while the timings vary with the machine.
+ *
+ * Usage: make bench && ./bench [steps]
+ */
+
+#include "game_constants.h"
+#include "voxel/core/block.h"
+#include "voxel/core/item.h"
+#include "voxel/world/world.h"
+#include "voxel/world/noise.h"
+#include "voxel/world/terrain.h"
+#include "voxel/world/spawn.h"
+#include "voxel/player/player.h"
+#include "voxel/entity/entity.h"
+#include "voxel/entity/pig.h"
+#include "voxel/entity/sheep.h"
+#include "voxel/entity/tree.h"
+#include "voxel/world/water.h"
+#include "voxel/render/chunk_batcher.h"
+#include <raylib.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <math.h>
+
+// ============================================================================
+// BENCHMARK SETUP
+// ============================================================================
+
+#define BENCH_SEED 12345u
+#define BENCH_WORLD_RADIUS 4        // Chunks generated around the origin (9x9)
+#define BENCH_STEPS 3600            // Default step count (one minute of game time)
+#define BENCH_WARMUP_STEPS 120      // Untimed steps so chunk meshes and batches settle
+#define BENCH_WATER_SOURCES 16      // Water sources left to flow
+#define BENCH_SAPLINGS 32           // Saplings left to grow
+#define BENCH_ANIMALS 64            // Extra animals on top of the chunk herds
+#define BENCH_SPREAD 48             // Half-width of the area things are placed in, in blocks
+
+/**
+ * Deterministic placement position for the i-th of count objects
+ * Walks a square grid over the benchmark area
+ */
+static void bench_grid_pos(int i, int count, int* x, int* z) {
+    int side = (int)ceilf(sqrtf((float)count));
+    int spacing = (BENCH_SPREAD * 2) / side;
+    *x = -BENCH_SPREAD + (i % side) * spacing + spacing / 2;
+    *z = -BENCH_SPREAD + (i / side) * spacing + spacing / 2;
+}
+
+/**
+ * Generate the benchmark area synchronously, like game_init does for spawn
+ */
+static void bench_generate_world(World* world) {
+    for (int cx = -BENCH_WORLD_RADIUS; cx <= BENCH_WORLD_RADIUS; cx++) {
+        for (int cz = -BENCH_WORLD_RADIUS; cz <= BENCH_WORLD_RADIUS; cz++) {
+            Chunk* chunk = world_get_or_create_chunk(world, cx, cz);
+            terrain_generate_chunk(chunk, world->terrain_params);
+            chunk_update_empty_status(chunk);
+            chunk_generate_mesh(chunk);
+            chunk->state = CHUNK_STATE_COMPLETE;
+            chunk_batcher_register_chunk(world->batcher, chunk);
+        }
+    }
+
+    // Herds spawn in a fixed chunk order so the RNG sequence is repeatable
+    for (int cx = -BENCH_WORLD_RADIUS; cx <= BENCH_WORLD_RADIUS; cx++) {
+        for (int cz = -BENCH_WORLD_RADIUS; cz <= BENCH_WORLD_RADIUS; cz++) {
+            Chunk* chunk = world_get_chunk(world, cx, cz);
+            spawn_animals_for_chunk(world, cx, cz, world->terrain_params);
+            chunk->has_spawned = true;
+        }
+    }
+}
+
+/**
+ * Place the active blocks and extra animals the simulation has to process
+ */
+static void bench_populate(World* world) {
+    for (int i = 0; i < BENCH_WATER_SOURCES; i++) {
+        int x, z;
+        bench_grid_pos(i, BENCH_WATER_SOURCES, &x, &z);
+        int y = terrain_get_height_at(x, z, world->terrain_params) + 1;
+        world_set_block(world, x, y, z, (Block){BLOCK_WATER, 0, water_make_metadata(0, false)});
+    }
+
+    for (int i = 0; i < BENCH_SAPLINGS; i++) {
+        int x, z;
+        bench_grid_pos(i, BENCH_SAPLINGS, &x, &z);
+        x += 1;  // Keep clear of the water grid
+        int y = terrain_get_height_at(x, z, world->terrain_params) + 1;
+        world_set_block(world, x, y, z, (Block){BLOCK_SAPLING, 0, 0});
+        sapling_on_placed(world, x, y, z);
+    }
+
+    for (int i = 0; i < BENCH_ANIMALS; i++) {
+        int x, z;
+        bench_grid_pos(i, BENCH_ANIMALS, &x, &z);
+        int y = terrain_get_height_at(x, z, world->terrain_params) + 1;
+        Vector3 pos = {x + 0.5f, (float)y, z + 0.5f};
+        if (i % 2 == 0) {
+            pig_spawn(world->entity_manager, pos);
+        } else {
+            sheep_spawn(world->entity_manager, pos);
+        }
+    }
+}
+
+/**
+ * One simulation step: the fixed-step systems plus the per-frame world update
+ * Time of day stays at noon so no night mobs join mid-run.
+ */
+static void bench_step(World* world) {
+    weather_update(&world->weather, SIM_FIXED_STEP);
+    entity_manager_update(world->entity_manager, (struct World*)world, SIM_FIXED_STEP);
+    leaf_decay_update(world, SIM_FIXED_STEP);
+    sapling_update(world, SIM_FIXED_STEP);
+    world_update(world, 0, 0);
+}
+
+/**
+ * Summary of the simulation state, equal across runs with the same seed
+ */
+static unsigned int bench_checksum(World* world) {
+    unsigned int sum = 0;
+    for (Entity* e = world->entity_manager->entities; e; e = e->next) {
+        sum = sum * 31u + (unsigned int)e->type;
+        sum = sum * 31u + (unsigned int)(int)floorf(e->position.x * 16.0f);
+        sum = sum * 31u + (unsigned int)(int)floorf(e->position.z * 16.0f);
+    }
+    return sum * 31u + (unsigned int)world->water_queue->count;
+}
+
+// ============================================================================
+// ENTRY POINT
+// ============================================================================
+
+int main(int argc, char** argv) {
+    int steps = BENCH_STEPS;
+    if (argc > 1) {
+        steps = atoi(argv[1]);
+        if (steps <= 0) {
+            fprintf(stderr, "Usage: %s [steps]\n", argv[0]);
+            return 1;
+        }
+    }
+
+    // Mesh uploads need a GL context; the window is never shown or drawn
+    SetTraceLogLevel(LOG_WARNING);
+    SetConfigFlags(FLAG_WINDOW_HIDDEN);
+    InitWindow(320, 240, "Katalis Benchmark");
+
+    block_system_init();
+    item_system_init();
+    noise_init(BENCH_SEED);
+    leaf_decay_init();
+    sapling_init();
+
+    TerrainParams terrain_params = terrain_default_params();
+    terrain_params.tree_density = WORLD_TREE_DENSITY;
+    terrain_params.ore_abundance = WORLD_ORE_ABUNDANCE;
+
+    World* world = world_create(terrain_params);
+    world_set_seed(world, BENCH_SEED);
+    world_set_view_distance(world, BENCH_WORLD_RADIUS);
+    world->time_of_day = 12.0f;
+
+    EntityManager* entities = entity_manager_create();
+    world_set_entity_manager(world, entities);
+
+    int spawn_y = terrain_get_height_at(0, 0, terrain_params) + 2;
+    Player* player = player_create((Vector3){0.0f, (float)spawn_y, 0.0f});
+    world->player = player;
+
+    double setup_start = GetTime();
+    bench_generate_world(world);
+    bench_populate(world);
+    for (int i = 0; i < BENCH_WARMUP_STEPS; i++) {
+        bench_step(world);
+    }
+    double setup_time = GetTime() - setup_start;
+
+    printf("[BENCH] Seed %u, %d chunks, %d entities, %d water updates pending\n",
+           BENCH_SEED, (BENCH_WORLD_RADIUS * 2 + 1) * (BENCH_WORLD_RADIUS * 2 + 1),
+           entity_manager_get_count(entities), world->water_queue->count);
+    printf("[BENCH] Setup and warmup: %.2f s\n", setup_time);
+
+    double slowest = 0.0;
+    double start = GetTime();
+    for (int i = 0; i < steps; i++) {
+        double step_start = GetTime();
+        bench_step(world);
+        double step_time = GetTime() - step_start;
+        if (step_time > slowest) slowest = step_time;
+    }
+    double elapsed = GetTime() - start;
+
+    printf("[BENCH] %d steps in %.3f s\n", steps, elapsed);
+    printf("[BENCH] %.1f steps/s, %.3f ms mean, %.3f ms slowest\n",
+           steps / elapsed, elapsed * 1000.0 / steps, slowest * 1000.0);
+    printf("[BENCH] Checksum %08x (%d entities)\n",
+           bench_checksum(world), entity_manager_get_count(entities));
+
+    player_destroy(player);
+    world->player = NULL;
+    entity_manager_destroy(entities);
+    world_destroy(world);
+    CloseWindow();
+    return 0;
+}
@@ -12,7 +12,9 @@
 #include "voxel/world/terrain.h"
 #include "voxel/world/raycast.h"
 #include "voxel/player/player.h"
+#include "voxel/player/gamepad.h"
 #include "voxel/core/texture_atlas.h"
+#include "voxel/core/assets.h"
 #include "voxel/core/item.h"
 #include "voxel/inventory/inventory_ui.h"
 #include "voxel/inventory/inventory_input.h"
@@ -22,11 +24,23 @@
 #include "voxel/entity/block_human.h"
 #include "voxel/entity/sheep.h"
 #include "voxel/entity/pig.h"
+#include "voxel/entity/zombie.h"
+#include "voxel/world/spawn.h"
+#include "voxel/world/discovery.h"
+#include "voxel/world/ore_scanner.h"
+#include "voxel/world/water.h"
+#include "voxel/entity/loot.h"
 #include "voxel/render/sky.h"
 #include "voxel/render/particle.h"
 #include "voxel/entity/tree.h"
 #include "voxel/network/network.h"
 #include "voxel/ui/minimap.h"
+#include "voxel/ui/floating_text.h"
+#include "voxel/ui/ruler.h"
+#include "voxel/ui/waypoint.h"
+#include "voxel/player/achievements.h"
+#include "voxel/player/objectives.h"
+#include "voxel/player/stats.h"
 #include "voxel/world/chest.h"
 #include "voxel/render/chunk_batcher.h"
 #include "voxel/core/settings_constants.h"
@@ -38,6 +52,7 @@
 #include <stdbool.h>
 #include <stdio.h>
 #include <stdlib.h>
+#include <string.h>
 #include <math.h>
 #include <time.h>
 #include <float.h>
@@ -61,6 +76,11 @@ typedef struct {
     BlockFace target_face;
     float flying_message_timer;  // Timer for flying mode notification
     float view_mode_message_timer;  // Timer for view mode notification
+    float home_message_timer;    // Timer for home set notification
+    // Screenshots
+    bool screenshot_requested;   // Capture at end of next draw
+    char capture_message[96];    // "Saved ..." / "Reloaded ..." / "Need a ..." notification text
+    float capture_message_timer;
     float view_dist_message_timer;  // Timer for view distance notification
     PauseMenu* pause_menu;       // Pause menu state
     // Day/night system
@@ -79,6 +99,10 @@ typedef struct {
     bool was_underwater;         // Previous underwater state for splash detection
     // Tunable settings
     GameSettings settings;       // In-game tunable parameters
+    // Fixed-step simulation
+    float sim_accumulator;       // Real time not yet consumed by fixed steps
+    bool sim_paused;             // World frozen, player and camera still move
+    int sim_time_scale;          // Fast-forward: 1, 2 or 4 sim steps per step of real time
 } GameState;
 
 static GameState g_state;
@@ -94,10 +118,423 @@ typedef struct {
 
 static MiningState g_mining = {0};
 
+// Crafted items already turned into XP
+static int g_crafting_xp_awarded = 0;
+
+// Block edits Ctrl+Z can take back, newest at g_undo_head - 1
+typedef enum {
+    UNDO_PLACE,                  // Block placed from the hotbar
+    UNDO_MINE                    // Block mined into the inventory
+} UndoKind;
+
+typedef struct {
+    UndoKind kind;
+    int x, y, z;
+    Block block;                 // Block that was placed or mined
+    ItemType item;               // Item spent on placing / gained from mining
+    uint8_t count;
+} UndoAction;
+
+static UndoAction g_undo_history[UNDO_HISTORY_SIZE];
+static int g_undo_head = 0;
+static int g_undo_count = 0;
+
+// Undo never takes rewards back, so undone edits must not earn them twice:
+// blocks put back by undo give no XP, counters or sapling rolls when mined
+// again, and undone placements leave the next placements uncounted
+typedef struct {
+    int x, y, z;
+} UndoRestored;
+
+static UndoRestored g_undo_restored[UNDO_HISTORY_SIZE];
+static int g_undo_restored_count = 0;
+static int g_undo_uncounted_places = 0;
+
+// ============================================================================
+// EXPERIENCE
+// ============================================================================
+
+/**
+ * Give the player XP and announce level-ups above their head
+ */
+static void award_xp(int amount) {
+    Player* player = g_state.player;
+    if (!player_add_xp(player, amount)) return;
+
+    char text[32];
+    snprintf(text, sizeof(text), "Level %d!", player->level);
+    Vector3 pos = {player->position.x, player->position.y + 2.2f, player->position.z};
+    floating_text_spawn(pos, text, (Color){120, 255, 120, 255});
+}
+
+/**
+ * XP for breaking a block; tougher blocks that need a tool give more
+ */
+static void award_mining_xp(BlockType type) {
+    const BlockProperties* props = block_get_properties(type);
+    award_xp(props->requires_tool ? PLAYER_XP_PER_HARD_BLOCK : PLAYER_XP_PER_BLOCK);
+}
+
+/**
+ * XP for items crafted since the last frame
+ */
+static void update_crafting_xp(void) {
+    Inventory* inv = g_state.player->inventory;
+    int new_items = inv->items_crafted - g_crafting_xp_awarded;
+    if (new_items <= 0) return;
+
+    g_crafting_xp_awarded = inv->items_crafted;
+    award_xp(new_items * PLAYER_XP_PER_CRAFTED_ITEM);
+}
+
+// ============================================================================
+// UNDO
+// ============================================================================
+
+/**
+ * Remember a block edit; the oldest one is forgotten once the history is full
+ * Beds and doors span two blocks and aren't recorded.
+ */
+static void undo_record(UndoKind kind, int x, int y, int z, Block block, ItemType item, uint8_t count) {
+    switch (block.type) {
+        case BLOCK_BED_FOOT:
+        case BLOCK_BED_HEAD:
+        case BLOCK_WOOD_DOOR:
+        case BLOCK_IRON_DOOR:
+            return;
+        default:
+            break;
+    }
+
+    g_undo_history[g_undo_head] = (UndoAction){kind, x, y, z, block, item, count};
+    g_undo_head = (g_undo_head + 1) % UNDO_HISTORY_SIZE;
+    if (g_undo_count < UNDO_HISTORY_SIZE) g_undo_count++;
+}
+
+static void undo_clear(void) {
+    g_undo_head = 0;
+    g_undo_count = 0;
+    g_undo_restored_count = 0;
+    g_undo_uncounted_places = 0;
+}
+
+/**
+ * Remember a block undo put back; the oldest is forgotten when full
+ */
+static void undo_mark_restored(int x, int y, int z) {
+    if (g_undo_restored_count == UNDO_HISTORY_SIZE) {
+        memmove(&g_undo_restored[0], &g_undo_restored[1], sizeof(g_undo_restored[0]) * (UNDO_HISTORY_SIZE - 1));
+        g_undo_restored_count--;
+    }
+    g_undo_restored[g_undo_restored_count++] = (UndoRestored){x, y, z};
+}
+
+/**
+ * Check whether a block being mined was put back by undo, forgetting it
+ * @return true if mining it again should give no rewards
+ */
+static bool undo_take_restored(int x, int y, int z) {
+    for (int i = 0; i < g_undo_restored_count; i++) {
+        UndoRestored* r = &g_undo_restored[i];
+        if (r->x == x && r->y == y && r->z == z) {
+            *r = g_undo_restored[--g_undo_restored_count];
+            return true;
+        }
+    }
+    return false;
+}
+
+/**
+ * Check whether a placement should count toward stats and achievements
+ * Each undone placement makes one later placement go uncounted.
+ */
+static bool undo_counts_placement(void) {
+    if (g_undo_uncounted_places == 0) return true;
+    g_undo_uncounted_places--;
+    return false;
+}
+
+/**
+ * Take the item back from the inventory, newest slots first
+ * @return false (and nothing removed) if the player no longer has enough
+ */
+static bool undo_take_item(Inventory* inv, ItemType type, uint8_t count) {
+    // Hotbar and main inventory only - the crafting grid and its output preview
+    // aren't real stock and must not be taken from
+    int slot_count = HOTBAR_SIZE + inventory_get_main_size(inv);
+
+    int available = 0;
+    for (int i = 0; i < slot_count; i++) {
+        ItemStack* slot = inventory_get_slot(inv, i);
+        if (slot && slot->type == type) available += slot->count;
+    }
+    if (available < count) return false;
+
+    for (int i = slot_count - 1; i >= 0 && count > 0; i--) {
+        ItemStack* slot = inventory_get_slot(inv, i);
+        if (!slot || slot->type != type) continue;
+
+        uint8_t taken = slot->count < count ? slot->count : count;
+        inventory_remove_item(inv, i, taken);
+        count -= taken;
+    }
+    return true;
+}
+
+/**
+ * Revert the newest block edit if the world still matches it
+ * A placed block comes back to the inventory; a mined block is put back
+ * and its drop taken away again. Worn tool durability is not restored.
+ * The edit stays in the history if it can't be undone yet.
+ */
+static void undo_last_action(void) {
+    if (g_undo_count == 0) {
+        snprintf(g_state.capture_message, sizeof(g_state.capture_message), "Nothing to undo");
+        g_state.capture_message_timer = MESSAGE_DISPLAY_TIME;
+        return;
+    }
+
+    int index = (g_undo_head - 1 + UNDO_HISTORY_SIZE) % UNDO_HISTORY_SIZE;
+    UndoAction* action = &g_undo_history[index];
+    Inventory* inv = g_state.player->inventory;
+    Block current = world_get_block(g_state.world, action->x, action->y, action->z);
+    const char* failure = NULL;
+
+    if (action->kind == UNDO_PLACE) {
+        if (current.type != action->block.type) {
+            failure = "Placed block is gone";
+        } else if (!inventory_add_item(inv, action->item, action->count)) {
+            failure = "No room to take the block back";
+        } else {
+            world_set_block(g_state.world, action->x, action->y, action->z, (Block){BLOCK_AIR, 0, 0});
+            network_broadcast_block_change(g_state.network, action->x, action->y, action->z, BLOCK_AIR, 0);
+            g_undo_uncounted_places++;
+        }
+    } else {
+        Vector3 pos = {(float)action->x, (float)action->y, (float)action->z};
+        if (current.type != BLOCK_AIR) {
+            failure = "Something is in the way";
+        } else if (player_collides_with_position(g_state.player, pos)) {
+            failure = "You are standing in the way";
+        } else if (action->item != ITEM_NONE && !undo_take_item(inv, action->item, action->count)) {
+            failure = "The mined item is gone";
+        } else {
+            world_set_block(g_state.world, action->x, action->y, action->z, action->block);
+            network_broadcast_block_change(g_state.network, action->x, action->y, action->z,
+                                           action->block.type, action->block.metadata);
+            undo_mark_restored(action->x, action->y, action->z);
+
+            if (action->block.type == BLOCK_SAPLING) {
+                sapling_on_placed(g_state.world, action->x, action->y, action->z);
+            }
+        }
+    }
+
+    if (failure) {
+        snprintf(g_state.capture_message, sizeof(g_state.capture_message), "Can't undo: %s", failure);
+    } else {
+        snprintf(g_state.capture_message, sizeof(g_state.capture_message), "Undid %s %s",
+                 action->kind == UNDO_PLACE ? "placing" : "mining", block_get_name(action->block.type));
+        printf("[GAME] %s at (%d, %d, %d)\n", g_state.capture_message, action->x, action->y, action->z);
+        g_undo_head = index;
+        g_undo_count--;
+    }
+    g_state.capture_message_timer = MESSAGE_DISPLAY_TIME;
+}
+
+// ============================================================================
+// MINING
+// ============================================================================
+
+/**
+ * Check that the held tool can harvest a block, telling the player if not
+ * The message says whether the right tool is elsewhere in the inventory.
+ * @return true if mining may start
+ */
+static bool check_required_tool(BlockType block_type, ItemType tool) {
+    if (item_can_harvest_block(block_type, tool)) return true;
+
+    ToolType needed = block_get_properties(block_type)->preferred_tool;
+    int totals[ITEM_COUNT];
+    inventory_get_totals(g_state.player->inventory, totals, true);
+
+    bool owns_tool = false;
+    for (int type = 1; type < ITEM_COUNT && !owns_tool; type++) {
+        const ItemProperties* props = item_get_properties(type);
+        owns_tool = totals[type] > 0 && props->is_tool && props->tool_type == needed;
+    }
+
+    snprintf(g_state.capture_message, sizeof(g_state.capture_message),
+             owns_tool ? "Select your %s to mine %s" : "Need a %s to mine %s",
+             item_get_tool_type_name(needed), block_get_name(block_type));
+    g_state.capture_message_timer = MESSAGE_DISPLAY_TIME;
+    return false;
+}
+
+/**
+ * Break a fully mined block, giving its drop and wearing the held tool
+ * Harvestable blocks stay in place if their drop doesn't fit the inventory,
+ * or if the held tool can no longer harvest them (switched mid-dig).
+ * @return true if the block was removed
+ */
+static bool break_mined_block(int x, int y, int z, ItemStack* held, ItemType tool) {
+    Block block = world_get_block(g_state.world, x, y, z);
+
+    // Check if we can harvest with current tool
+    if (!check_required_tool(block.type, tool)) {
+        return false;
+    }
+
+    // Spawn block break particles
+    Vector3 block_pos = {(float)x, (float)y, (float)z};
+    particle_spawn_block_break(block_pos, block.type, 12);
+    particle_spawn_ore_sparks(block_pos, block.type, 10);
+
+    // Calculate drops
+    ItemStack drop = item_get_block_drop(block.type);
+
+    if (drop.type != ITEM_NONE) {
+        // Try to add to inventory
+        if (!inventory_add_item(g_state.player->inventory, drop.type, drop.count)) {
+            return false;
+        }
+
+        // Success - remove block
+        Block air_block = {BLOCK_AIR, 0, 0};
+        world_set_block(g_state.world, x, y, z, air_block);
+        network_broadcast_block_change(g_state.network, x, y, z, BLOCK_AIR, 0);
+        undo_record(UNDO_MINE, x, y, z, block, drop.type, drop.count);
+        bool rewarded = !undo_take_restored(x, y, z);
+
+        Vector3 popup_pos = {x + 0.5f, y + 0.5f, z + 0.5f};
+        floating_text_spawn_item_gain(popup_pos, drop.type, drop.count);
+        if (rewarded) {
+            achievements_on_block_mined(block.type);
+            stats_on_block_mined(block.type);
+            award_mining_xp(block.type);
+        }
+
+        // If wood was removed, trigger leaf decay
+        if (block.type == BLOCK_WOOD) {
+            leaf_decay_on_wood_removed(g_state.world, x, y, z);
+        }
+
+        // Leaves occasionally give a sapling
+        if (rewarded && block.type == BLOCK_LEAVES &&
+            rng_int(&g_state.world->rng, SAPLING_LEAF_DROP_CHANCE) == 0) {
+            inventory_add_item(g_state.player->inventory, ITEM_SAPLING, 1);
+            popup_pos.y += 0.4f;
+            floating_text_spawn_item_gain(popup_pos, ITEM_SAPLING, 1);
+        }
+
+        // Consume tool durability
+        if (held && held->type != ITEM_NONE) {
+            const ItemProperties* props = item_get_properties(held->type);
+            if (props->is_tool && held->durability > 0) {
+                held->durability--;
+                if (held->durability == 0) {
+                    // Tool broke
+                    held->type = ITEM_NONE;
+                    held->count = 0;
+                    printf("[GAME] Tool broke!\n");
+                }
+            }
+        }
+    } else {
+        // Block has no drop - still remove it
+        Block air_block = {BLOCK_AIR, 0, 0};
+        world_set_block(g_state.world, x, y, z, air_block);
+        network_broadcast_block_change(g_state.network, x, y, z, BLOCK_AIR, 0);
+        undo_record(UNDO_MINE, x, y, z, block, ITEM_NONE, 0);
+        if (!undo_take_restored(x, y, z)) {
+            achievements_on_block_mined(block.type);
+            stats_on_block_mined(block.type);
+            award_mining_xp(block.type);
+        }
+    }
+
+    return true;
+}
+
+/**
+ * Break the blocks around a mined block for area tools (mine_radius > 0)
+ * Works on the plane facing the player; only blocks the tool is suited for
+ * are broken, and each one drops and wears the tool on its own.
+ */
+static void break_mined_area(int x, int y, int z, ItemStack* held, ItemType tool) {
+    const ItemProperties* props = item_get_properties(tool);
+    int radius = props->mine_radius;
+    if (radius <= 0) return;
+
+    // Plane axes perpendicular to the face that was hit
+    int ax[3] = {1, 0, 0};
+    int bx[3] = {0, 1, 0};
+    switch (g_state.target_face) {
+        case FACE_TOP:
+        case FACE_BOTTOM:
+            bx[1] = 0; bx[2] = 1;
+            break;
+        case FACE_LEFT:
+        case FACE_RIGHT:
+            ax[0] = 0; ax[2] = 1;
+            break;
+        default:
+            break;
+    }
+
+    for (int a = -radius; a <= radius; a++) {
+        for (int b = -radius; b <= radius; b++) {
+            if (a == 0 && b == 0) continue;
+
+            // Stop once the tool breaks or is swapped out
+            if (!held || held->type != tool) return;
+
+            int bx_pos = x + a * ax[0] + b * bx[0];
+            int by_pos = y + a * ax[1] + b * bx[1];
+            int bz_pos = z + a * ax[2] + b * bx[2];
+
+            Block block = world_get_block(g_state.world, bx_pos, by_pos, bz_pos);
+            if (block.type == BLOCK_AIR) continue;
+
+            const BlockProperties* bp = block_get_properties(block.type);
+            if (bp->preferred_tool != props->tool_type) continue;
+            if (item_calculate_dig_time(block.type, tool) <= 0) continue;
+
+            break_mined_block(bx_pos, by_pos, bz_pos, held, tool);
+        }
+    }
+}
+
 // ============================================================================
 // LIFECYCLE HOOKS (Internal)
 // ============================================================================
 
+/**
+ * Find the column nearest the origin that isn't under a river or lake
+ * Checks square rings outward; falls back to the origin if all of them are flooded
+ */
+static void find_dry_spawn(TerrainParams params, int* out_x, int* out_z) {
+    *out_x = 0;
+    *out_z = 0;
+    if (!params.generate_rivers) return;
+
+    for (int r = 0; r <= SPAWN_SEARCH_RADIUS; r += SPAWN_SEARCH_STEP) {
+        for (int x = -r; x <= r; x += SPAWN_SEARCH_STEP) {
+            for (int z = -r; z <= r; z += SPAWN_SEARCH_STEP) {
+                // Inner columns were checked by the smaller rings
+                if (x != -r && x != r && z != -r && z != r) continue;
+
+                if (terrain_get_height_at(x, z, params) >= params.water_level) {
+                    *out_x = x;
+                    *out_z = z;
+                    return;
+                }
+            }
+        }
+    }
+    printf("[GAME] No dry spawn found, spawning at the origin\n");
+}
+
 /**
  * Initialize game - called once on first frame
  */
@@ -113,11 +550,21 @@ static void game_init(void) {
     // Initialize block system
     block_system_init();
 
-    // Initialize texture atlas
+    // Initialize texture atlas (shaders loaded from here on report missing files)
+    assets_clear_missing();
     texture_atlas_init();
 
     // Initialize particle system (must be after texture atlas)
     particle_system_init();
+    floating_text_clear();
+    waypoint_clear();
+    discovery_clear();
+    ore_scanner_clear();
+    g_crafting_xp_awarded = 0;
+    undo_clear();
+    achievements_init();
+    objectives_init();
+    stats_init();
 
     // Initialize item system
     item_system_init();
@@ -132,16 +579,25 @@ static void game_init(void) {
 
     // Setup terrain parameters (use defaults for deep world)
     TerrainParams terrain_params = terrain_default_params();
+    terrain_params.tree_density = WORLD_TREE_DENSITY;
+    terrain_params.ore_abundance = WORLD_ORE_ABUNDANCE;
 
     // Create world with terrain parameters
     g_state.world = world_create(terrain_params);
+    world_set_seed(g_state.world, seed);
+
+    // Pick a spawn on dry land so the player doesn't start in a river
+    int spawn_x, spawn_z;
+    find_dry_spawn(terrain_params, &spawn_x, &spawn_z);
+    int spawn_cx, spawn_cz;
+    world_to_chunk_coords(spawn_x, spawn_z, &spawn_cx, &spawn_cz);
 
-    // Generate procedural terrain
+    // Generate procedural terrain around the spawn
     printf("[GAME] Generating procedural terrain...\n");
     int chunks_generated = 0;
     int chunks_empty = 0;
-    for (int cx = -3; cx <= 3; cx++) {
-        for (int cz = -3; cz <= 3; cz++) {
+    for (int cx = spawn_cx - 3; cx <= spawn_cx + 3; cx++) {
+        for (int cz = spawn_cz - 3; cz <= spawn_cz + 3; cz++) {
             // Get or create chunk
             Chunk* chunk = world_get_or_create_chunk(g_state.world, cx, cz);
 
@@ -175,7 +631,6 @@ static void game_init(void) {
            chunks_generated, chunks_empty, chunks_generated - chunks_empty);
 
     // Get terrain height at spawn position and spawn player on surface
-    int spawn_x = 0, spawn_z = 0;
     int surface_height = terrain_get_height_at(spawn_x, spawn_z, terrain_params);
     Vector3 spawn_position = {(float)spawn_x, (float)(surface_height + 2), (float)spawn_z};
     g_state.player = player_create(spawn_position);
@@ -188,28 +643,17 @@ static void game_init(void) {
     printf("[GAME] Player spawned at (%.1f, %.1f, %.1f)\n",
            spawn_position.x, spawn_position.y, spawn_position.z);
 
-    // Give player starting items for testing - stone tools in hotbar slots 0-2
-    Inventory* inv = g_state.player->inventory;
-    inv->hotbar[0] = (ItemStack){ITEM_STONE_PICKAXE, 1, 132, 132};
-    inv->hotbar[1] = (ItemStack){ITEM_STONE_SHOVEL, 1, 132, 132};
-    inv->hotbar[2] = (ItemStack){ITEM_STONE_AXE, 1, 132, 132};
-    // Blocks in slots 3-5
-    inv->hotbar[3] = (ItemStack){ITEM_WOOD_LOG, 16, 0, 0};
-    inv->hotbar[4] = (ItemStack){ITEM_DIRT, 64, 0, 0};
-    inv->hotbar[5] = (ItemStack){ITEM_COBBLESTONE, 32, 0, 0};
-    // New items for testing
-    inv->hotbar[6] = (ItemStack){ITEM_STONE_SWORD, 1, 132, 132};  // Sword for combat
-    inv->hotbar[7] = (ItemStack){ITEM_WOOL_WHITE, 16, 0, 0};   // Wool for bed crafting
-    inv->hotbar[8] = (ItemStack){ITEM_WOOD_PLANKS, 32, 0, 0};  // Planks for crafting
-    // Add some iron blocks for crafting (in main inventory)
-    inventory_add_item(inv, ITEM_IRON_BLOCK, 8);
-    printf("[GAME] Added starting items to inventory\n");
+    // Hand out the configured starting kit
+    inventory_give_starting_kit(g_state.player->inventory, STARTING_KIT);
 
     // Initialize target block state
     g_state.has_target_block = false;
     g_state.target_block_pos = (Vector3){0, 0, 0};
     g_state.flying_message_timer = 0.0f;
     g_state.view_mode_message_timer = 0.0f;
+    g_state.home_message_timer = 0.0f;
+    g_state.screenshot_requested = false;
+    g_state.capture_message_timer = 0.0f;
     g_state.view_dist_message_timer = 0.0f;
 
     // Enable mouse cursor lock for FPS controls
@@ -224,15 +668,24 @@ static void game_init(void) {
     pause_menu_set_network(g_state.pause_menu, g_state.network);
     printf("[GAME] Network system initialized\n");
 
+    // Simulation starts unpaused at normal speed
+    g_state.sim_paused = false;
+    g_state.sim_time_scale = 1;
+
     // Initialize game settings with defaults
     g_state.settings.view_distance = SETTING_VIEW_DIST_DEFAULT;
     g_state.settings.lod_distance = SETTING_LOD_DIST_DEFAULT;
     g_state.settings.batch_rebuilds = SETTING_BATCH_REBUILD_DEFAULT;
+    g_state.settings.target_fps = SETTING_TARGET_FPS_DEFAULT;
+    g_state.settings.vsync = SETTING_VSYNC_DEFAULT;
+    g_state.settings.colorblind_palette = false;
+    g_state.settings.camera_shake = true;
     g_state.settings.day_speed = SETTING_DAY_SPEED_DEFAULT;
     g_state.settings.time_paused = false;
     g_state.settings.max_uploads_per_frame = SETTING_MAX_UPLOADS_DEFAULT;
     g_state.settings.show_debug_info = false;
     g_state.settings.mouse_sensitivity = SETTING_MOUSE_SENSITIVITY_DEFAULT;
+    settings_apply_display(&g_state.settings);
 
     // Create settings menu and link to pause menu
     SettingsMenu* settings_menu = settings_menu_create(&g_state.settings);
@@ -249,6 +702,7 @@ static void game_init(void) {
 
     // Initialize leaf decay system
     leaf_decay_init();
+    sapling_init();
 
     // Initialize entity system and link to world for biome-aware spawning
     g_state.entity_manager = entity_manager_create();
@@ -265,6 +719,28 @@ static void game_init(void) {
     printf("[GAME] Procedural world initialized with %d chunks!\n", g_state.world->chunks->chunk_count);
 }
 
+/**
+ * Reload shaders and the recipe file from disk (F5, for development)
+ * Anything that fails to reload keeps its current version.
+ */
+static void reload_assets(void) {
+    assets_clear_missing();
+
+    int shaders = 0;
+    if (texture_atlas_reload_shader()) shaders++;
+    if (sky_reload_shader()) shaders++;
+    if (particle_system_reload_shader()) shaders++;
+
+    // Grid contents stay put; only the previewed output follows the new recipes
+    bool recipes = crafting_reload();
+    crafting_update_output(g_state.player->inventory);
+
+    snprintf(g_state.capture_message, sizeof(g_state.capture_message),
+             "Reloaded %d/3 shaders, %s", shaders,
+             recipes ? "recipes from " CRAFTING_RECIPE_FILE : "recipes unchanged");
+    g_state.capture_message_timer = MESSAGE_DISPLAY_TIME;
+}
+
 /**
  * Place a bed (consists of two blocks: head and foot)
  */
@@ -280,6 +756,22 @@ static void place_bed(GameState* game, int x, int y, int z, BlockType bed_type)
     world_set_block(game->world, x + dx, y, z, (Block){BLOCK_BED_HEAD, 0, 0});
 }
 
+/**
+ * Start the next calendar day, logging season changes
+ */
+static void advance_day(void) {
+    World* world = g_state.world;
+    Season before = world_get_season(world->day);
+    world->day++;
+
+    Season after = world_get_season(world->day);
+    if (after != before) {
+        printf("[GAME] Day %d - %s begins\n", world->day, world_get_season_name(after));
+    } else {
+        printf("[GAME] Day %d\n", world->day);
+    }
+}
+
 /**
  * Try to sleep in a bed (skip to daytime if night)
  */
@@ -287,8 +779,13 @@ static bool try_sleep_in_bed(GameState* game, int x, int y, int z) {
     // Check if it's night time (time_of_day: 0-24, night is roughly 18-6)
     // For simplicity, allow sleeping any time for now
 
-    // Skip to daytime
-    game->time_of_day = 0.0f;
+    // Skip to daytime (06:00, past the night range so night mobs despawn)
+    // Sleeping through the evening runs past midnight into the next day
+    if (game->time_of_day >= 6.0f) {
+        advance_day();
+    }
+    game->time_of_day = 6.0f;
+    achievements_add_progress(ACHIEVEMENT_GOOD_NIGHT, 1);
 
     printf("[GAME] Slept in bed, time set to morning\n");
     return true;
@@ -337,6 +834,252 @@ static void toggle_door(GameState* game, int x, int y, int z) {
     printf("[GAME] Door %s at (%d, %d, %d)\n", is_open ? "closed" : "opened", x, y, z);
 }
 
+/**
+ * Open the chest at a block, creating its contents on first open
+ */
+static void open_chest_at(int x, int y, int z) {
+    ChestData* chest = chest_get(g_state.world->chest_registry, x, y, z);
+    if (!chest) {
+        // First time opening - create chest data and generate loot
+        chest = chest_create(g_state.world->chest_registry, x, y, z);
+        if (chest) {
+            unsigned int loot_seed = (unsigned int)(x * 73856093 ^ y * 19349663 ^ z * 83492791);
+            chest_generate_dungeon_loot(chest, loot_seed);
+        }
+    }
+
+    if (chest) {
+        g_state.open_chest = chest;
+        EnableCursor();
+        printf("[GAME] Opened chest at (%d, %d, %d)\n", x, y, z);
+    }
+}
+
+/**
+ * Find the origin (lowest corner) and size of the structure at a block
+ * Beds span foot + head along X, doors span bottom + top; everything else
+ * is a single block and its own origin.
+ */
+static void get_structure_origin_at(World* world, int x, int y, int z,
+                                    int* origin_x, int* origin_y, int* origin_z, Vector3* size) {
+    Block block = world_get_block(world, x, y, z);
+    *origin_x = x;
+    *origin_y = y;
+    *origin_z = z;
+    *size = (Vector3){1.0f, 1.0f, 1.0f};
+
+    if (block.type == BLOCK_BED_FOOT || block.type == BLOCK_BED_HEAD) {
+        // Head is placed at x+1 of the foot
+        if (block.type == BLOCK_BED_HEAD && world_get_block(world, x - 1, y, z).type == BLOCK_BED_FOOT) {
+            *origin_x = x - 1;
+        }
+        size->x = 2.0f;
+    } else if (block.type == BLOCK_WOOD_DOOR || block.type == BLOCK_IRON_DOOR) {
+        if (world_get_block(world, x, y - 1, z).type == block.type) {
+            *origin_y = y - 1;
+        }
+        size->y = 2.0f;
+    }
+}
+
+/**
+ * Draw name and state of the targeted bed, door or chest beside the crosshair
+ */
+static void draw_structure_info(int center_x, int center_y) {
+    int x = (int)g_state.target_block_pos.x;
+    int y = (int)g_state.target_block_pos.y;
+    int z = (int)g_state.target_block_pos.z;
+    Block block = world_get_block(g_state.world, x, y, z);
+
+    int origin_x, origin_y, origin_z;
+    Vector3 size;
+    get_structure_origin_at(g_state.world, x, y, z, &origin_x, &origin_y, &origin_z, &size);
+    Block origin = world_get_block(g_state.world, origin_x, origin_y, origin_z);
+
+    const char* name;
+    char status[48];
+    switch (block.type) {
+        case BLOCK_BED_FOOT:
+        case BLOCK_BED_HEAD:
+            name = "Bed";
+            snprintf(status, sizeof(status), "Right-click to sleep");
+            break;
+        case BLOCK_WOOD_DOOR:
+        case BLOCK_IRON_DOOR:
+            // Open state lives on the bottom half
+            name = block_get_name(block.type);
+            snprintf(status, sizeof(status), "%s", (origin.metadata & 1) ? "Open" : "Closed");
+            break;
+        case BLOCK_CHEST: {
+            name = block_get_name(block.type);
+            ChestData* chest = chest_get(g_state.world->chest_registry, x, y, z);
+            if (!chest) {
+                snprintf(status, sizeof(status), "Unopened");
+                break;
+            }
+            int used = 0;
+            for (int i = 0; i < CHEST_SLOTS; i++) {
+                if (chest->slots[i].type != ITEM_NONE) used++;
+            }
+            snprintf(status, sizeof(status), "%d/%d slots used", used, CHEST_SLOTS);
+            break;
+        }
+        default:
+            return;
+    }
+
+    int name_size = 16;
+    int status_size = 14;
+    int padding = 5;
+    int width = MeasureText(name, name_size);
+    int status_width = MeasureText(status, status_size);
+    if (status_width > width) width = status_width;
+    width += padding * 2;
+    int height = name_size + status_size + padding * 3;
+
+    // Right of the crosshair, clear of the mining ring
+    int panel_x = center_x + 28;
+    int panel_y = center_y - height / 2;
+    DrawRectangle(panel_x, panel_y, width, height, (Color){0, 0, 0, 150});
+    DrawText(name, panel_x + padding, panel_y + padding, name_size, WHITE);
+    DrawText(status, panel_x + padding, panel_y + padding * 2 + name_size, status_size, LIGHTGRAY);
+}
+
+/**
+ * Debug panel describing the targeted block (Settings > Show Debug Info)
+ */
+static void draw_block_inspector(int x, int y) {
+    int bx = (int)g_state.target_block_pos.x;
+    int by = (int)g_state.target_block_pos.y;
+    int bz = (int)g_state.target_block_pos.z;
+    BlockInspection info = world_inspect_block(g_state.world, bx, by, bz);
+    const BlockProperties* props = block_get_properties(info.block.type);
+
+    char lines[5][80];
+    int line_count = 0;
+    snprintf(lines[line_count++], sizeof(lines[0]), "%s (%d) at %d, %d, %d",
+             block_get_name(info.block.type), info.block.type, bx, by, bz);
+    snprintf(lines[line_count++], sizeof(lines[0]), "Chunk %d, %d  local %d, %d%s",
+             info.chunk_x, info.chunk_z, info.local_x, info.local_z, info.loaded ? "" : "  (not generated)");
+    snprintf(lines[line_count++], sizeof(lines[0]), "%s  surface y %d (%+d)",
+             biome_get_name(info.biome), info.surface_height, by - info.surface_height);
+    snprintf(lines[line_count++], sizeof(lines[0]), "Light %d  metadata 0x%02x",
+             info.block.light_level, info.block.metadata);
+    if (props->is_fluid) {
+        snprintf(lines[line_count++], sizeof(lines[0]), "Water level %d%s",
+                 water_get_level(info.block.metadata), water_is_falling(info.block.metadata) ? ", falling" : "");
+    } else if (info.has_chest_data) {
+        snprintf(lines[line_count++], sizeof(lines[0]), "Chest contents stored");
+    }
+
+    int font_size = 16;
+    int padding = 6;
+    int width = 0;
+    for (int i = 0; i < line_count; i++) {
+        int line_width = MeasureText(lines[i], font_size);
+        if (line_width > width) width = line_width;
+    }
+    int height = line_count * (font_size + 4) + padding * 2 - 4;
+
+    DrawRectangle(x, y, width + padding * 2, height, (Color){0, 0, 0, 150});
+    for (int i = 0; i < line_count; i++) {
+        DrawText(lines[i], x + padding, y + padding + i * (font_size + 4), font_size, WHITE);
+    }
+}
+
+/**
+ * Build a timestamped file name like "screenshot_20250101_120000.png"
+ */
+static void format_capture_path(char* out, size_t out_size, const char* prefix) {
+    time_t now = time(NULL);
+    char stamp[32];
+    strftime(stamp, sizeof(stamp), "%Y%m%d_%H%M%S", localtime(&now));
+    snprintf(out, out_size, "%s_%s.png", prefix, stamp);
+}
+
+/**
+ * Roll a killed mob's loot table and give the drops to the player
+ */
+static void give_entity_loot(Entity* entity, const char* name) {
+    ItemStack drops[LOOT_MAX_DROPS];
+    int drop_count = loot_roll(entity, &g_state.world->rng, drops, LOOT_MAX_DROPS);
+
+    Vector3 popup_pos = entity->position;
+    popup_pos.y += 1.5f;
+
+    if (entity->type != ENTITY_TYPE_ZOMBIE) {
+        achievements_add_progress(ACHIEVEMENT_FIRST_HUNT, 1);
+    }
+
+    printf("[GAME] %s killed! Dropped", name);
+    for (int i = 0; i < drop_count; i++) {
+        // A full inventory loses what doesn't fit - only report what was kept
+        uint8_t left = inventory_add_item_partial(g_state.player->inventory,
+                                                  drops[i].type, drops[i].count);
+        int added = drops[i].count - left;
+        if (added > 0) {
+            floating_text_spawn_item_gain(popup_pos, drops[i].type, added);
+            popup_pos.y += 0.4f;
+        }
+        printf(" %d %s", added, item_get_name(drops[i].type));
+        if (left > 0) printf(" (%d lost, inventory full)", left);
+    }
+    printf("\n");
+}
+
+/**
+ * Advance world simulation by one fixed step
+ * Runs zero or more times per frame from game_run
+ */
+static void game_fixed_update(float step) {
+    // Update time of day
+    if (!g_state.settings.time_paused) {
+        g_state.time_of_day += g_state.settings.day_speed * step;
+        if (g_state.time_of_day >= 24.0f) {
+            g_state.time_of_day -= 24.0f;
+            advance_day();
+        }
+    }
+
+    // Sync time of day to world for entity lighting
+    g_state.world->time_of_day = g_state.time_of_day;
+
+    weather_update(&g_state.world->weather, step);
+
+    // Update all entities
+    entity_manager_update(g_state.entity_manager, (struct World*)g_state.world, step);
+    spawn_night_mobs((struct World*)g_state.world, step);
+
+    // Update leaf decay
+    leaf_decay_update(g_state.world, step);
+    sapling_update(g_state.world, step);
+}
+
+/**
+ * Accumulate real frame time and return how many fixed steps to run
+ * Fast-forward runs more steps of the same size, never bigger ones.
+ * Backlog beyond SIM_MAX_STEPS_PER_FRAME (per speed level) is dropped after a long stall
+ */
+static int game_consume_sim_steps(float frame_dt) {
+    // Paused time is discarded, so resuming doesn't replay a backlog
+    if (g_state.sim_paused) {
+        g_state.sim_accumulator = 0.0f;
+        return 0;
+    }
+
+    g_state.sim_accumulator += frame_dt * g_state.sim_time_scale;
+
+    int max_steps = SIM_MAX_STEPS_PER_FRAME * g_state.sim_time_scale;
+    int steps = (int)(g_state.sim_accumulator / SIM_FIXED_STEP);
+    if (steps > max_steps) {
+        steps = max_steps;
+        g_state.sim_accumulator = 0.0f;
+    } else {
+        g_state.sim_accumulator -= steps * SIM_FIXED_STEP;
+    }
+    return steps;
+}
+
 /**
  * Update game logic - called every frame with delta time
  */
@@ -350,13 +1093,23 @@ static void game_update(float dt) {
     // Toggle inventory with E key (only when pause menu closed and window focused)
     // Don't toggle if crafting guide search is active (let user type)
     bool search_active = g_state.player->inventory->is_open && inventory_ui_is_search_active();
-    if (!menu_blocking_input && IsKeyPressed(KEY_E) && !search_active) {
+    // Y on a controller does the same
+    bool inventory_toggle = IsKeyPressed(KEY_E) || gamepad_is_button_pressed(GAMEPAD_BUTTON_RIGHT_FACE_UP);
+    if (!menu_blocking_input && inventory_toggle && !search_active) {
         g_state.player->inventory->is_open = !g_state.player->inventory->is_open;
 
         // Show cursor when inventory is open, hide when closed
         if (g_state.player->inventory->is_open) {
             EnableCursor();
+
+            // Controller users start with the cursor on the focused slot
+            if (gamepad_is_available()) {
+                int focus_x, focus_y;
+                inventory_input_get_focus_center(g_state.player->inventory, &focus_x, &focus_y);
+                SetMousePosition(focus_x, focus_y);
+            }
         } else {
+            inventory_return_held_item(g_state.player->inventory);
             DisableCursor();
         }
     }
@@ -380,6 +1133,15 @@ static void game_update(float dt) {
             if (current > 8) current = 0;
             inventory_set_selected_slot(g_state.player->inventory, current);
         }
+
+        // Controller bumpers step through the hotbar
+        int step = 0;
+        if (gamepad_is_button_pressed(GAMEPAD_BUTTON_LEFT_TRIGGER_1)) step = -1;
+        if (gamepad_is_button_pressed(GAMEPAD_BUTTON_RIGHT_TRIGGER_1)) step = 1;
+        if (step != 0) {
+            int current = (g_state.player->inventory->selected_hotbar_slot + step + 9) % 9;
+            inventory_set_selected_slot(g_state.player->inventory, current);
+        }
     }
 
     // Toggle flying mode with Shift key (only when pause menu closed)
@@ -404,13 +1166,70 @@ static void game_update(float dt) {
         g_state.view_mode_message_timer -= dt;
     }
 
-    // Update time of day
-    if (!g_state.settings.time_paused) {
-        g_state.time_of_day += g_state.settings.day_speed * dt;
-        if (g_state.time_of_day >= 24.0f) {
-            g_state.time_of_day -= 24.0f;
+    // Set home marker at player position with B key - only when pause menu closed
+    if (!menu_blocking_input && !g_state.player->inventory->is_open && IsKeyPressed(KEY_B)) {
+        g_state.player->home = g_state.player->position;
+        g_state.player->has_home = true;
+        g_state.home_message_timer = MESSAGE_DISPLAY_TIME;
+        printf("[GAME] Home set at (%.0f, %.0f, %.0f)\n",
+               g_state.player->home.x, g_state.player->home.y, g_state.player->home.z);
+    }
+
+    // Drop a waypoint at player position with N key
+    if (!menu_blocking_input && !g_state.player->inventory->is_open && IsKeyPressed(KEY_N)) {
+        int index = waypoint_add(g_state.player->position);
+        if (index >= 0) {
+            printf("[GAME] Added %s\n", waypoint_get(index)->label);
+        } else {
+            printf("[GAME] Waypoint limit reached (%d)\n", MAX_WAYPOINTS);
+        }
+    }
+
+    // Open the nearest chest within reach with G, no aiming needed
+    if (!menu_blocking_input && !g_state.player->inventory->is_open && !g_state.open_chest &&
+        IsKeyPressed(KEY_G)) {
+        Vector3 eye = player_get_camera(g_state.player).position;
+        int chest_x, chest_y, chest_z;
+        if (world_find_nearest_block(g_state.world, eye, PLAYER_REACH_DISTANCE, BLOCK_CHEST,
+                                     &chest_x, &chest_y, &chest_z)) {
+            open_chest_at(chest_x, chest_y, chest_z);
+        }
+    }
+
+    // Undo the last block placement or mining with Ctrl+Z
+    if (!menu_blocking_input && !g_state.player->inventory->is_open && !g_state.open_chest &&
+        (IsKeyDown(KEY_LEFT_CONTROL) || IsKeyDown(KEY_RIGHT_CONTROL)) && IsKeyPressed(KEY_Z)) {
+        undo_last_action();
+    }
+
+    // F5: reload shaders and recipes, F8: list missing asset files
+    if (window_focused && IsKeyPressed(KEY_F5)) {
+        reload_assets();
+    }
+    if (window_focused && IsKeyPressed(KEY_F8)) {
+        assets_toggle_overlay();
+    }
+
+    // F12: screenshot (taken after the frame is drawn), F9: export map of loaded world
+    if (window_focused && IsKeyPressed(KEY_F12)) {
+        g_state.screenshot_requested = true;
+    }
+    if (window_focused && IsKeyPressed(KEY_F9)) {
+        char path[64];
+        format_capture_path(path, sizeof(path), "map");
+        if (minimap_export_png(g_state.world, g_state.player, path)) {
+            snprintf(g_state.capture_message, sizeof(g_state.capture_message), "Map saved: %s", path);
+            g_state.capture_message_timer = MESSAGE_DISPLAY_TIME;
         }
     }
+    if (g_state.capture_message_timer > 0.0f) {
+        g_state.capture_message_timer -= dt;
+    }
+
+    // Update home message timer
+    if (g_state.home_message_timer > 0.0f) {
+        g_state.home_message_timer -= dt;
+    }
 
     // Debug: Toggle time pause with T key - only when pause menu closed
     if (!menu_blocking_input && IsKeyPressed(KEY_T)) {
@@ -420,6 +1239,29 @@ static void game_update(float dt) {
                g_state.time_of_day);
     }
 
+    // Ruler mode with R: left click measures instead of mining
+    if (!menu_blocking_input && !search_active && IsKeyPressed(KEY_R)) {
+        ruler_toggle();
+    }
+
+    // Freeze the world simulation with P (player, camera and inventory stay usable)
+    if (!menu_blocking_input && !search_active && IsKeyPressed(KEY_P)) {
+        g_state.sim_paused = !g_state.sim_paused;
+        printf("[GAME] Simulation %s\n", g_state.sim_paused ? "PAUSED" : "RESUMED");
+    }
+
+    // Fast-forward the world with . (faster) and , (back toward normal speed)
+    if (!menu_blocking_input && !search_active && IsKeyPressed(KEY_PERIOD) &&
+        g_state.sim_time_scale < SIM_MAX_TIME_SCALE) {
+        g_state.sim_time_scale *= 2;
+        printf("[GAME] Simulation speed %dx\n", g_state.sim_time_scale);
+    }
+    if (!menu_blocking_input && !search_active && IsKeyPressed(KEY_COMMA) &&
+        g_state.sim_time_scale > 1) {
+        g_state.sim_time_scale /= 2;
+        printf("[GAME] Simulation speed %dx\n", g_state.sim_time_scale);
+    }
+
     // Debug: Speed up/slow down time with +/- keys - only when pause menu closed
     if (!menu_blocking_input && IsKeyPressed(KEY_EQUAL)) {  // + key
         g_state.settings.day_speed *= 2.0f;
@@ -448,6 +1290,12 @@ static void game_update(float dt) {
         g_state.view_dist_message_timer -= dt;
     }
 
+    // Playtime only counts while focused and unpaused
+    if (window_focused && !pause_menu_is_open(g_state.pause_menu)) {
+        stats_add_playtime(dt);
+    }
+    Vector3 position_before = g_state.player->position;
+
     // Update player (handles input, movement, collision, and camera)
     // Only full update when inventory is closed AND pause menu is closed
     if (!g_state.player->inventory->is_open && !pause_menu_is_open(g_state.pause_menu)) {
@@ -458,8 +1306,11 @@ static void game_update(float dt) {
         player_update_physics(g_state.player, g_state.world, dt);
     }
 
+    stats_add_distance(Vector3Distance(position_before, g_state.player->position));
+
     // Update swing animation (always, even when menu open for smooth animation)
     player_update_swing(g_state.player, dt);
+    player_update_shake(g_state.player, dt);
 
     // Check for water entry/exit and spawn splash particles
     {
@@ -484,19 +1335,23 @@ static void game_update(float dt) {
                           &player_chunk_x, &player_chunk_z);
     world_update(g_state.world, player_chunk_x, player_chunk_z);
 
-    // Sync time of day to world for entity lighting
-    g_state.world->time_of_day = g_state.time_of_day;
-
-    // Update all entities
-    entity_manager_update(g_state.entity_manager, (struct World*)g_state.world, dt);
-
-    // Update particle system
-    particle_system_update(dt);
-
-    // Update leaf decay
-    leaf_decay_update(g_state.world, dt);
-
-    // Update minimap
+    // Update particle system (frozen with the rest of the world while sim-paused)
+    if (!g_state.sim_paused) {
+        particle_system_update(dt);
+    }
+    floating_text_update(dt);
+    achievements_update(dt);
+    objectives_update(g_state.player->inventory, dt);
+    update_crafting_xp();
+
+    // Reveal explored area, then update minimap
+    discovery_update(g_state.player->position.x, g_state.player->position.z,
+                     weather_scale_reveal_radius(&g_state.world->weather, DISCOVERY_REVEAL_RADIUS));
+
+    // Holding an ore scanner marks nearby ores on the minimap and in the world
+    ItemStack* scanner = inventory_get_selected_hotbar_item(g_state.player->inventory);
+    ore_scanner_update(g_state.world, g_state.player->position,
+                       scanner && scanner->type == ITEM_ORE_SCANNER, dt);
     minimap_update(g_state.minimap, g_state.world, g_state.player);
 
     // Raycast to find block player is looking at
@@ -521,8 +1376,17 @@ static void game_update(float dt) {
         ENTITY_REACH_DISTANCE
     );
 
-    // Attack entity on left click (instant, priority over mining)
-    if (IsMouseButtonPressed(MOUSE_LEFT_BUTTON) && !g_state.player->inventory->is_open && !pause_menu_is_open(g_state.pause_menu)) {
+    // Ruler measurements take the left mouse button while ruler mode is on
+    bool ruler_input = !g_state.player->inventory->is_open && !pause_menu_is_open(g_state.pause_menu);
+    ruler_update(g_state.has_target_block, g_state.target_block_pos,
+                 ruler_input && IsMouseButtonPressed(MOUSE_LEFT_BUTTON),
+                 ruler_input && IsMouseButtonDown(MOUSE_LEFT_BUTTON));
+
+    // Attack entity on left click or RT (instant, priority over mining)
+    bool attack_pressed = IsMouseButtonPressed(MOUSE_LEFT_BUTTON) ||
+                          gamepad_is_button_pressed(GAMEPAD_BUTTON_RIGHT_TRIGGER_2);
+    if (attack_pressed && !g_state.sim_paused && !ruler_is_active() &&
+        !g_state.player->inventory->is_open && !pause_menu_is_open(g_state.pause_menu)) {
         if (g_state.target_entity && g_state.target_entity->type == ENTITY_TYPE_SHEEP) {
             // Swing animation for attack
             player_start_swing(g_state.player);
@@ -531,40 +1395,7 @@ static void game_update(float dt) {
             bool died = sheep_damage(g_state.target_entity, 1);
 
             if (died) {
-                // Drop 1-2 meat
-                int meat_count = 1 + (rand() % 2);
-                inventory_add_item(g_state.player->inventory, ITEM_MEAT, meat_count);
-
-                // Drop 1-3 wool (based on sheep's wool color)
-                SheepData* sheep_data = (SheepData*)g_state.target_entity->data;
-                if (sheep_data) {
-                    int wool_count = 1 + (rand() % 3);
-                    Color wool_color = sheep_data->wool_color;
-
-                    // Map wool color to wool item type
-                    ItemType wool_item = ITEM_WOOL_WHITE;  // Default
-
-                    // Simple color matching (based on RGB values)
-                    if (wool_color.r > 230 && wool_color.g > 230 && wool_color.b > 230) {
-                        wool_item = ITEM_WOOL_WHITE;
-                    } else if (wool_color.r < 60 && wool_color.g < 60 && wool_color.b < 60) {
-                        wool_item = ITEM_WOOL_BLACK;
-                    } else if (wool_color.r > 180 && wool_color.g < 80 && wool_color.b < 80) {
-                        wool_item = ITEM_WOOL_RED;
-                    } else if (wool_color.r < 100 && wool_color.g > 100 && wool_color.b < 100) {
-                        wool_item = ITEM_WOOL_GREEN;
-                    } else if (wool_color.r < 100 && wool_color.g < 100 && wool_color.b > 150) {
-                        wool_item = ITEM_WOOL_BLUE;
-                    } else {
-                        // Default to light gray for other colors
-                        wool_item = ITEM_WOOL_LIGHT_GRAY;
-                    }
-
-                    inventory_add_item(g_state.player->inventory, wool_item, wool_count);
-                    printf("[GAME] Sheep killed! Dropped %d meat and %d wool\n", meat_count, wool_count);
-                } else {
-                    printf("[GAME] Sheep killed! Dropped %d meat\n", meat_count);
-                }
+                give_entity_loot(g_state.target_entity, "Sheep");
 
                 // Remove entity from manager and destroy
                 entity_manager_remove(g_state.entity_manager, g_state.target_entity);
@@ -580,10 +1411,23 @@ static void game_update(float dt) {
             bool died = pig_damage(g_state.target_entity, 1);
 
             if (died) {
-                // Drop 1-3 meat (pigs drop more)
-                int meat_count = 1 + (rand() % 3);
-                inventory_add_item(g_state.player->inventory, ITEM_MEAT, meat_count);
-                printf("[GAME] Pig killed! Dropped %d meat\n", meat_count);
+                give_entity_loot(g_state.target_entity, "Pig");
+
+                // Remove entity from manager and destroy
+                entity_manager_remove(g_state.entity_manager, g_state.target_entity);
+                entity_destroy(g_state.target_entity);
+                g_state.target_entity = NULL;
+            }
+        }
+        else if (g_state.target_entity && g_state.target_entity->type == ENTITY_TYPE_ZOMBIE) {
+            // Swing animation for attack
+            player_start_swing(g_state.player);
+
+            // Damage the zombie
+            bool died = zombie_damage(g_state.target_entity, 1);
+
+            if (died) {
+                give_entity_loot(g_state.target_entity, "Zombie");
 
                 // Remove entity from manager and destroy
                 entity_manager_remove(g_state.entity_manager, g_state.target_entity);
@@ -598,7 +1442,10 @@ static void game_update(float dt) {
     }
 
     // Progressive mining - hold left mouse to mine blocks
-    bool mining_input = IsMouseButtonDown(MOUSE_LEFT_BUTTON) &&
+    bool mining_input = (IsMouseButtonDown(MOUSE_LEFT_BUTTON) ||
+                         gamepad_is_button_down(GAMEPAD_BUTTON_RIGHT_TRIGGER_2)) &&
+                        !g_state.sim_paused &&
+                        !ruler_is_active() &&
                         !g_state.player->inventory->is_open &&
                         !pause_menu_is_open(g_state.pause_menu) &&
                         !g_state.target_entity;  // Don't mine while attacking entity
@@ -622,13 +1469,21 @@ static void game_update(float dt) {
             g_mining.target_z = z;
             g_mining.progress = 0.0f;
             g_mining.required_time = item_calculate_dig_time(block.type, tool);
+            if (g_mining.required_time > 0) {
+                g_mining.required_time /= player_get_speed_multiplier(g_state.player);
+            }
             g_mining.crack_stage = 0;
 
+            // Tool-gated blocks without the right tool don't progress (like bedrock)
+            if (g_mining.required_time > 0 && !check_required_tool(block.type, tool)) {
+                g_mining.required_time = -1.0f;
+            }
+
             // Swing animation when starting to mine
             player_start_swing(g_state.player);
         }
 
-        // Check if block is unbreakable (bedrock)
+        // Check if block is unbreakable (bedrock, or missing the required tool)
         if (g_mining.required_time < 0) {
             // Don't progress - can't break this block
         } else if (g_mining.required_time == 0) {
@@ -644,64 +1499,21 @@ static void game_update(float dt) {
             // Update crack stage (0-9)
             int new_stage = (int)(g_mining.progress * 10.0f);
             if (new_stage > 9) new_stage = 9;
+
+            // Chip off a little debris each time the crack grows
+            if (new_stage != g_mining.crack_stage) {
+                Block block = world_get_block(g_state.world, x, y, z);
+                particle_spawn_block_break((Vector3){(float)x, (float)y, (float)z}, block.type, 2);
+            }
             g_mining.crack_stage = new_stage;
 
             // Check if mining complete
             if (g_mining.progress >= 1.0f) {
-                Block block = world_get_block(g_state.world, x, y, z);
-
-                // Spawn block break particles
-                Vector3 block_pos = {(float)x, (float)y, (float)z};
-                particle_spawn_block_break(block_pos, block.type, 12);
-
-                // Check if we can harvest with current tool
                 ItemStack* held = inventory_get_selected_hotbar_item(g_state.player->inventory);
                 ItemType tool = (held && held->type != ITEM_NONE) ? held->type : ITEM_NONE;
-                bool can_harvest = item_can_harvest_block(block.type, tool);
-
-                if (can_harvest) {
-                    // Calculate drops
-                    ItemStack drop = item_get_block_drop(block.type);
-
-                    if (drop.type != ITEM_NONE) {
-                        // Try to add to inventory
-                        if (inventory_add_item(g_state.player->inventory, drop.type, drop.count)) {
-                            // Success - remove block
-                            Block air_block = {BLOCK_AIR, 0, 0};
-                            world_set_block(g_state.world, x, y, z, air_block);
-                            network_broadcast_block_change(g_state.network, x, y, z, BLOCK_AIR, 0);
-
-                            // If wood was removed, trigger leaf decay
-                            if (block.type == BLOCK_WOOD) {
-                                leaf_decay_on_wood_removed(g_state.world, x, y, z);
-                            }
 
-                            // Consume tool durability
-                            if (held && held->type != ITEM_NONE) {
-                                const ItemProperties* props = item_get_properties(held->type);
-                                if (props->is_tool && held->durability > 0) {
-                                    held->durability--;
-                                    if (held->durability == 0) {
-                                        // Tool broke
-                                        held->type = ITEM_NONE;
-                                        held->count = 0;
-                                        printf("[GAME] Tool broke!\n");
-                                    }
-                                }
-                            }
-                        }
-                    } else {
-                        // Block has no drop - still remove it
-                        Block air_block = {BLOCK_AIR, 0, 0};
-                        world_set_block(g_state.world, x, y, z, air_block);
-                        network_broadcast_block_change(g_state.network, x, y, z, BLOCK_AIR, 0);
-                    }
-                } else {
-                    // Can't harvest (wrong tool) - block still breaks but no drops
-                    Block air_block = {BLOCK_AIR, 0, 0};
-                    world_set_block(g_state.world, x, y, z, air_block);
-                    network_broadcast_block_change(g_state.network, x, y, z, BLOCK_AIR, 0);
-                }
+                break_mined_block(x, y, z, held, tool);
+                break_mined_area(x, y, z, held, tool);
 
                 g_mining.is_mining = false;
                 g_mining.crack_stage = 0;
@@ -716,9 +1528,41 @@ static void game_update(float dt) {
         }
     }
 
-    // Place block on right click (only when inventory closed and not paused)
-    if (IsMouseButtonPressed(MOUSE_RIGHT_BUTTON) && !g_state.player->inventory->is_open && !g_state.open_chest && !pause_menu_is_open(g_state.pause_menu)) {
+    // Place block on right click or LT (only when inventory closed and not paused)
+    bool place_pressed = IsMouseButtonPressed(MOUSE_RIGHT_BUTTON) ||
+                         gamepad_is_button_pressed(GAMEPAD_BUTTON_LEFT_TRIGGER_2);
+    if (place_pressed && !g_state.player->inventory->is_open && !g_state.open_chest && !pause_menu_is_open(g_state.pause_menu)) {
+        ItemStack* held = inventory_get_selected_hotbar_item(g_state.player->inventory);
+
+        // Chests, doors and beds take the click even when food is held
+        bool target_is_interactable = false;
         if (g_state.has_target_block) {
+            BlockType target_type = world_get_block(g_state.world,
+                                                    (int)g_state.target_block_pos.x,
+                                                    (int)g_state.target_block_pos.y,
+                                                    (int)g_state.target_block_pos.z).type;
+            target_is_interactable = target_type == BLOCK_CHEST ||
+                                     target_type == BLOCK_WOOD_DOOR || target_type == BLOCK_IRON_DOOR ||
+                                     target_type == BLOCK_BED_FOOT || target_type == BLOCK_BED_HEAD;
+        }
+
+        // Using a backpack unlocks another inventory row (no target needed)
+        if (held && held->type == ITEM_BACKPACK) {
+            if (inventory_expand(g_state.player->inventory, 1)) {
+                inventory_remove_item(g_state.player->inventory,
+                                      g_state.player->inventory->selected_hotbar_slot, 1);
+            } else {
+                printf("[GAME] Inventory is already at full size\n");
+            }
+        }
+        // Eating food starts its effect (no target needed)
+        else if (held && item_is_food(held->type) && !target_is_interactable) {
+            if (player_consume(g_state.player, held->type)) {
+                inventory_remove_item(g_state.player->inventory,
+                                      g_state.player->inventory->selected_hotbar_slot, 1);
+            }
+        }
+        else if (g_state.has_target_block) {
             // Check if clicking on a chest - open it instead of placing
             int target_x = (int)g_state.target_block_pos.x;
             int target_y = (int)g_state.target_block_pos.y;
@@ -726,22 +1570,7 @@ static void game_update(float dt) {
             Block target_block = world_get_block(g_state.world, target_x, target_y, target_z);
 
             if (target_block.type == BLOCK_CHEST) {
-                // Open the chest
-                ChestData* chest = chest_get(g_state.world->chest_registry, target_x, target_y, target_z);
-                if (!chest) {
-                    // First time opening - create chest data and generate loot
-                    chest = chest_create(g_state.world->chest_registry, target_x, target_y, target_z);
-                    if (chest) {
-                        unsigned int loot_seed = (unsigned int)(target_x * 73856093 ^ target_y * 19349663 ^ target_z * 83492791);
-                        chest_generate_dungeon_loot(chest, loot_seed);
-                    }
-                }
-
-                if (chest) {
-                    g_state.open_chest = chest;
-                    EnableCursor();
-                    printf("[GAME] Opened chest at (%d, %d, %d)\n", target_x, target_y, target_z);
-                }
+                open_chest_at(target_x, target_y, target_z);
             }
             // Check if clicking on a door to toggle it
             else if (target_block.type == BLOCK_WOOD_DOOR || target_block.type == BLOCK_IRON_DOOR) {
@@ -772,8 +1601,13 @@ static void game_update(float dt) {
                         default: break;
                     }
 
+                    // Saplings only take root on grass or dirt
+                    bool soil_ok = props->places_as != BLOCK_SAPLING ||
+                        sapling_is_soil(world_get_block(g_state.world,
+                            (int)place_pos.x, (int)place_pos.y - 1, (int)place_pos.z).type);
+
                     // Check if placement position collides with player
-                    if (!player_collides_with_position(g_state.player, place_pos)) {
+                    if (soil_ok && !player_collides_with_position(g_state.player, place_pos)) {
                         // Special handling for beds and doors
                         if (props->places_as == BLOCK_BED_FOOT) {
                             // Place bed (two blocks)
@@ -804,6 +1638,16 @@ static void game_update(float dt) {
                                 (int)place_pos.y,
                                 (int)place_pos.z,
                                 props->places_as, 0);
+
+                            // Saplings start growing once planted
+                            if (props->places_as == BLOCK_SAPLING) {
+                                sapling_on_placed(g_state.world,
+                                    (int)place_pos.x, (int)place_pos.y, (int)place_pos.z);
+                            }
+
+                            undo_record(UNDO_PLACE,
+                                (int)place_pos.x, (int)place_pos.y, (int)place_pos.z,
+                                new_block, selected->type, 1);
                         }
 
                         // Broadcast the foot/placement block change
@@ -813,6 +1657,18 @@ static void game_update(float dt) {
                             (int)place_pos.z,
                             props->places_as, 0);
 
+                        if (undo_counts_placement()) {
+                            achievements_on_block_placed(props->places_as);
+                            stats_add(STAT_BLOCKS_PLACED, 1);
+                        }
+
+                        // First placed block becomes the home marker
+                        if (!g_state.player->has_home) {
+                            g_state.player->home = (Vector3){place_pos.x + 0.5f, place_pos.y, place_pos.z + 0.5f};
+                            g_state.player->has_home = true;
+                            g_state.home_message_timer = MESSAGE_DISPLAY_TIME;
+                        }
+
                         // Consume item from inventory
                         int slot_index = g_state.player->inventory->selected_hotbar_slot;
                         inventory_remove_item(g_state.player->inventory, slot_index, 1);
@@ -847,7 +1703,9 @@ static void game_update(float dt) {
         int mouse_y = (int)mouse_pos.y;
 
         if (IsMouseButtonPressed(MOUSE_LEFT_BUTTON)) {
-            inventory_ui_handle_chest_click(g_state.open_chest, g_state.player->inventory, mouse_x, mouse_y);
+            inventory_ui_handle_chest_click(g_state.open_chest, g_state.player->inventory, mouse_x, mouse_y, false);
+        } else if (IsMouseButtonPressed(MOUSE_RIGHT_BUTTON)) {
+            inventory_ui_handle_chest_click(g_state.open_chest, g_state.player->inventory, mouse_x, mouse_y, true);
         }
     }
 
@@ -877,22 +1735,51 @@ static void game_update(float dt) {
             }
         }
 
+        // Controller D-pad moves the slot focus and takes the cursor with it;
+        // A and X then click there like the mouse buttons
+        int focus_dx = 0, focus_dy = 0;
+        if (gamepad_is_button_pressed(GAMEPAD_BUTTON_LEFT_FACE_LEFT)) focus_dx = -1;
+        if (gamepad_is_button_pressed(GAMEPAD_BUTTON_LEFT_FACE_RIGHT)) focus_dx = 1;
+        if (gamepad_is_button_pressed(GAMEPAD_BUTTON_LEFT_FACE_UP)) focus_dy = -1;
+        if (gamepad_is_button_pressed(GAMEPAD_BUTTON_LEFT_FACE_DOWN)) focus_dy = 1;
+        if (focus_dx != 0 || focus_dy != 0) {
+            inventory_input_move_focus(g_state.player->inventory, focus_dx, focus_dy);
+            inventory_input_get_focus_center(g_state.player->inventory, &mouse_x, &mouse_y);
+            SetMousePosition(mouse_x, mouse_y);
+        }
+        bool pad_click = gamepad_is_button_pressed(GAMEPAD_BUTTON_RIGHT_FACE_DOWN);
+        bool pad_right_click = gamepad_is_button_pressed(GAMEPAD_BUTTON_RIGHT_FACE_LEFT);
+
         // Shift+Left-click: Quick transfer
         if (IsKeyDown(KEY_LEFT_SHIFT) && IsMouseButtonPressed(MOUSE_LEFT_BUTTON)) {
             inventory_input_handle_shift_click(g_state.player->inventory, mouse_x, mouse_y);
         }
-        // Left-click: First check crafting guide, then inventory slots
-        else if (IsMouseButtonPressed(MOUSE_LEFT_BUTTON)) {
+        // Left-click: First check minimap waypoints and crafting guide, then inventory slots
+        else if (IsMouseButtonPressed(MOUSE_LEFT_BUTTON) || pad_click) {
+            int waypoint = minimap_pick_waypoint(g_state.minimap, g_state.player, mouse_x, mouse_y);
+            if (waypoint >= 0) {
+                printf("[GAME] Removed %s\n", waypoint_get(waypoint)->label);
+                waypoint_remove(waypoint);
+            }
             // Try guide click first - if it handles the click, don't process inventory
-            if (!inventory_ui_handle_guide_click(g_state.player->inventory, mouse_x, mouse_y)) {
+            else if (!inventory_ui_handle_guide_click(g_state.player->inventory, mouse_x, mouse_y)) {
                 inventory_input_handle_left_click(g_state.player->inventory, mouse_x, mouse_y);
             }
         }
+        // Ctrl+Right-click: Lock/unlock slot
+        else if (IsKeyDown(KEY_LEFT_CONTROL) && IsMouseButtonPressed(MOUSE_RIGHT_BUTTON)) {
+            inventory_input_handle_lock_click(g_state.player->inventory, mouse_x, mouse_y);
+        }
         // Right-click: Pick up/place half stack
-        else if (IsMouseButtonPressed(MOUSE_RIGHT_BUTTON)) {
+        else if (IsMouseButtonPressed(MOUSE_RIGHT_BUTTON) || pad_right_click) {
             inventory_input_handle_right_click(g_state.player->inventory, mouse_x, mouse_y);
         }
 
+        // Holding left on the crafting output keeps crafting
+        bool left_held = (IsMouseButtonDown(MOUSE_LEFT_BUTTON) && !IsKeyDown(KEY_LEFT_SHIFT)) ||
+                         gamepad_is_button_down(GAMEPAD_BUTTON_RIGHT_FACE_DOWN);
+        inventory_input_handle_left_hold(g_state.player->inventory, mouse_x, mouse_y, left_held, dt);
+
         // Mouse wheel: Scroll crafting guide
         int scroll = (int)GetMouseWheelMove();
         if (scroll != 0) {
@@ -998,6 +1885,7 @@ static void game_update(float dt) {
         } else if (g_state.player->inventory->is_open) {
             // Priority 1: Close inventory
             g_state.player->inventory->is_open = false;
+            inventory_return_held_item(g_state.player->inventory);
             DisableCursor();
         } else if (pause_menu_is_open(g_state.pause_menu)) {
             // Priority 2: Resume game (close pause menu)
@@ -1025,9 +1913,21 @@ static bool is_camera_underwater(Vector3 camera_pos, World* world) {
     return block.type == BLOCK_WATER;
 }
 
+/**
+ * Check if anything solid is above the camera (rain can't reach it)
+ */
+static bool is_camera_sheltered(Vector3 camera_pos, World* world) {
+    int x = (int)floorf(camera_pos.x);
+    int z = (int)floorf(camera_pos.z);
+    for (int y = (int)floorf(camera_pos.y) + 1; y < CHUNK_HEIGHT; y++) {
+        if (block_is_solid(world_get_block(world, x, y, z))) return true;
+    }
+    return false;
+}
+
 static void game_draw(void) {
-    // 3D rendering with player camera
-    Camera3D camera = player_get_camera(g_state.player);
+    // 3D rendering with player camera (shaken - targeting uses the steady one)
+    Camera3D camera = player_get_view_camera(g_state.player);
 
     // Check if camera is underwater for fog effects
     bool underwater = is_camera_underwater(camera.position, g_state.world);
@@ -1057,11 +1957,15 @@ static void game_draw(void) {
     world_render_transparent_with_time(g_state.world, g_state.time_of_day, camera.position, underwater);
     rlEnableDepthMask();   // Re-enable depth write
 
-    // Draw wireframe around targeted block
+    // Draw wireframe around targeted block (whole bed/door when targeting either half)
     if (g_state.has_target_block) {
-        Vector3 block_pos = g_state.target_block_pos;
-        Vector3 cube_center = {block_pos.x + 0.5f, block_pos.y + 0.5f, block_pos.z + 0.5f};
-        Vector3 cube_size = {1.01f, 1.01f, 1.01f};  // Slightly larger than block
+        int origin_x, origin_y, origin_z;
+        Vector3 footprint;
+        get_structure_origin_at(g_state.world, (int)g_state.target_block_pos.x, (int)g_state.target_block_pos.y,
+                                (int)g_state.target_block_pos.z, &origin_x, &origin_y, &origin_z, &footprint);
+        Vector3 cube_center = {origin_x + footprint.x * 0.5f, origin_y + footprint.y * 0.5f,
+                               origin_z + footprint.z * 0.5f};
+        Vector3 cube_size = {footprint.x + 0.01f, footprint.y + 0.01f, footprint.z + 0.01f};  // Slightly larger than block
 
         DrawCubeWires(cube_center, cube_size.x, cube_size.y, cube_size.z, BLACK);
         DrawCubeWires(cube_center, cube_size.x * 0.99f, cube_size.y * 0.99f, cube_size.z * 0.99f, WHITE);
@@ -1122,6 +2026,12 @@ static void game_draw(void) {
         rlSetTexture(0);
     }
 
+    // Draw ruler measurement line
+    ruler_draw_3d();
+
+    // Draw ore scanner markers
+    ore_scanner_draw_3d(g_state.world);
+
     // Render particles (with depth read but no depth write for transparency)
     rlDisableDepthMask();
     particle_system_render(camera);
@@ -1132,6 +2042,11 @@ static void game_draw(void) {
     // === HUD LAYER: Clear depth buffer so HUD is ALWAYS on top ===
     glClear(GL_DEPTH_BUFFER_BIT);
 
+    // Rain and fog haze (not underwater or under a roof)
+    if (!underwater && !is_camera_sheltered(camera.position, g_state.world)) {
+        weather_draw_overlay(&g_state.world->weather, GetScreenWidth(), GetScreenHeight(), (float)GetTime());
+    }
+
     // Draw held item in first-person view (3D HUD layer)
     if (!g_state.player->inventory->is_open &&
         !g_state.open_chest &&
@@ -1143,6 +2058,18 @@ static void game_draw(void) {
     // Draw nametags above remote players (2D overlay)
     network_draw_nametags(g_state.network, camera);
 
+    // Draw "+N item" popups
+    floating_text_draw(camera);
+
+    // Draw home marker (edge arrow when off-screen)
+    if (g_state.player->has_home && !g_state.player->inventory->is_open) {
+        Vector3 marker_pos = Vector3Add(g_state.player->home, (Vector3){0.0f, 1.0f, 0.0f});
+        waypoint_draw_marker(camera, marker_pos, g_state.player->position, "Home", (Color){255, 220, 80, 255});
+    }
+    if (!g_state.player->inventory->is_open) {
+        waypoint_draw_all(camera, g_state.player->position);
+    }
+
     // Draw crosshair in center of screen
     int screen_width = GetScreenWidth();
     int screen_height = GetScreenHeight();
@@ -1161,6 +2088,30 @@ static void game_draw(void) {
     // Draw small dot in center
     DrawCircle(center_x, center_y, 2, WHITE);
 
+    // Mining progress ring around the crosshair (tool-adjusted dig time)
+    if (g_mining.is_mining && g_mining.required_time > 0.0f) {
+        float fraction = g_mining.progress > 1.0f ? 1.0f : g_mining.progress;
+        Block mined = world_get_block(g_state.world, g_mining.target_x, g_mining.target_y, g_mining.target_z);
+        ToolType preferred = block_get_properties(mined.type)->preferred_tool;
+
+        // Chopping wood and digging rock read differently at a glance
+        Color ring_color = WHITE;
+        if (preferred == TOOL_AXE) ring_color = (Color){120, 220, 90, 255};
+        else if (preferred == TOOL_PICKAXE) ring_color = (Color){120, 190, 255, 255};
+
+        Vector2 ring_center = {(float)center_x, (float)center_y};
+        DrawRing(ring_center, 14.0f, 17.0f, 0.0f, 360.0f, 32, (Color){0, 0, 0, 120});
+        DrawRing(ring_center, 14.0f, 17.0f, -90.0f, -90.0f + 360.0f * fraction, 32, ring_color);
+    }
+
+    // Ruler readout below the crosshair
+    ruler_draw_hud();
+
+    // Bed/door/chest info beside the crosshair
+    if (g_state.has_target_block && !g_state.player->inventory->is_open && !g_state.open_chest) {
+        draw_structure_info(center_x, center_y);
+    }
+
     // Draw flying mode notification
     if (g_state.flying_message_timer > 0.0f) {
         const char* message = g_state.player->is_flying ? "FLYING MODE ENABLED" : "FLYING MODE DISABLED";
@@ -1207,6 +2158,52 @@ static void game_draw(void) {
         DrawText(message, text_x, text_y, font_size, text_color);
     }
 
+    // Draw home set notification
+    if (g_state.home_message_timer > 0.0f) {
+        const char* message = "HOME SET";
+        int font_size = 24;
+        int text_width = MeasureText(message, font_size);
+        int text_x = (screen_width - text_width) / 2;
+        int text_y = 160;  // Below view mode message
+
+        DrawRectangle(text_x - 10, text_y - 5, text_width + 20, font_size + 10, (Color){0, 0, 0, 180});
+        DrawText(message, text_x, text_y, font_size, (Color){255, 220, 80, 255});
+    }
+
+    // Draw simulation paused banner (stays up until resumed)
+    if (g_state.sim_paused) {
+        const char* message = "SIMULATION PAUSED (P)";
+        int font_size = 24;
+        int text_width = MeasureText(message, font_size);
+        int text_x = (screen_width - text_width) / 2;
+        int text_y = 40;
+
+        DrawRectangle(text_x - 10, text_y - 5, text_width + 20, font_size + 10, (Color){0, 0, 0, 180});
+        DrawText(message, text_x, text_y, font_size, YELLOW);
+    } else if (g_state.sim_time_scale > 1) {
+        char message[32];
+        snprintf(message, sizeof(message), "FAST FORWARD %dx", g_state.sim_time_scale);
+        int font_size = 24;
+        int text_width = MeasureText(message, font_size);
+        int text_x = (screen_width - text_width) / 2;
+        int text_y = 40;
+
+        DrawRectangle(text_x - 10, text_y - 5, text_width + 20, font_size + 10, (Color){0, 0, 0, 180});
+        DrawText(message, text_x, text_y, font_size, SKYBLUE);
+    }
+
+    // Draw screenshot / map export notification
+    if (g_state.capture_message_timer > 0.0f) {
+        int font_size = 18;
+        int text_width = MeasureText(g_state.capture_message, font_size);
+        int text_x = (screen_width - text_width) / 2;
+        int text_y = 190;  // Below home message
+
+        float alpha = g_state.capture_message_timer / MESSAGE_DISPLAY_TIME;
+        DrawRectangle(text_x - 8, text_y - 4, text_width + 16, font_size + 8, (Color){0, 0, 0, (unsigned char)(150 * alpha)});
+        DrawText(g_state.capture_message, text_x, text_y, font_size, (Color){255, 255, 255, (unsigned char)(255 * alpha)});
+    }
+
     // Draw view distance message above hotbar
     if (g_state.view_dist_message_timer > 0.0f) {
         char view_msg[32];
@@ -1229,16 +2226,25 @@ static void game_draw(void) {
     // Draw hotbar (always visible)
     Texture2D atlas = texture_atlas_get_texture();
     inventory_ui_draw_hotbar(g_state.player->inventory, atlas);
+    inventory_ui_draw_xp_bar(g_state.player->level, g_state.player->xp, g_state.player->xp_to_next);
+    inventory_ui_draw_effects(g_state.player->effect_times);
 
     // Draw minimap (top-right corner, shows remote players too)
     minimap_draw(g_state.minimap, g_state.player, g_state.network);
 
+    // Debug: Missing asset files (toggled with F8)
+    assets_draw_overlay();
+
     // Debug: Show time when H is held
     if (IsKeyDown(KEY_H)) {
         int hours = (int)g_state.time_of_day;
         int minutes = (int)((g_state.time_of_day - hours) * 60.0f);
-        char time_str[64];
-        snprintf(time_str, sizeof(time_str), "Time: %02d:%02d (%.1fx speed)", hours, minutes, g_state.settings.day_speed);
+        char time_str[96];
+        int day = g_state.world->day;
+        const char* season = world_get_season_name(world_get_season(day));
+        const char* weather = weather_get_name(g_state.world->weather.type);
+        snprintf(time_str, sizeof(time_str), "Day %d (%s, %s)  Time: %02d:%02d (%.1fx speed)",
+                 day, season, weather, hours, minutes, g_state.settings.day_speed);
         DrawText(time_str, 10, 10, 20, WHITE);
 
         // Show if paused
@@ -1247,6 +2253,11 @@ static void game_draw(void) {
         }
     }
 
+    // Debug: Inspect the targeted block
+    if (g_state.settings.show_debug_info && g_state.has_target_block) {
+        draw_block_inspector(10, 60);
+    }
+
     // Draw network status indicator (below minimap)
     NetworkMode net_mode = network_get_mode(g_state.network);
     if (net_mode != NET_MODE_NONE) {
@@ -1330,10 +2341,25 @@ static void game_draw(void) {
         }
     }
 
+    // Draw current objective, then achievement unlock toast on top
+    objectives_draw();
+    achievements_draw_toast();
+
     // Draw pause menu if open (rendered on top of everything)
     if (pause_menu_is_open(g_state.pause_menu)) {
         pause_menu_draw(g_state.pause_menu);
     }
+
+    // Capture the finished frame (before EndDrawing swaps buffers)
+    if (g_state.screenshot_requested) {
+        char path[64];
+        format_capture_path(path, sizeof(path), "screenshot");
+        TakeScreenshot(path);
+        snprintf(g_state.capture_message, sizeof(g_state.capture_message), "Screenshot saved: %s", path);
+        g_state.capture_message_timer = MESSAGE_DISPLAY_TIME;
+        g_state.screenshot_requested = false;
+        printf("[GAME] Saved screenshot %s\n", path);
+    }
 }
 
 // ============================================================================
@@ -1429,8 +2455,16 @@ void game_run(void) {
         return;
     }
 
-    // Every frame: update then draw
+    // Every frame: input/player update, fixed world steps, then draw
+    // Frame dt is capped so a long stall can't move the player or mining in one jump
     float dt = GetFrameTime(); // Delta time in seconds
-    game_update(dt);
+    float max_dt = SIM_FIXED_STEP * SIM_MAX_STEPS_PER_FRAME;
+    game_update(dt < max_dt ? dt : max_dt);
+
+    int steps = game_consume_sim_steps(dt);
+    for (int i = 0; i < steps; i++) {
+        game_fixed_update(SIM_FIXED_STEP);
+    }
+
     game_draw();
 }
@@ -13,7 +13,9 @@ int main(void) {
     // Initialize Raylib window
     const int screen_width = 800;
     const int screen_height = 600;
+    SetConfigFlags(FLAG_WINDOW_RESIZABLE);
     InitWindow(screen_width, screen_height, "Katalis");
+    SetWindowMinSize(screen_width, screen_height);  // UI panels are laid out for at least this
     SetTargetFPS(60);
 
     printf("[MAIN] Katalis starting...\n");
@@ -0,0 +1,106 @@
+/**
+ * Asset Loading Implementation
+ */
+
+#include "voxel/core/assets.h"
+#include <rlgl.h>
+#include <stdio.h>
+#include <string.h>
+
+static char g_missing[ASSETS_MAX_MISSING][ASSETS_PATH_MAX];
+static int g_missing_count = 0;
+static bool g_overlay_visible = false;
+
+// ============================================================================
+// MISSING FILES
+// ============================================================================
+
+void assets_clear_missing(void) {
+    g_missing_count = 0;
+}
+
+/**
+ * Remember a missing path once, even if it is requested again
+ */
+static void record_missing(const char* path) {
+    for (int i = 0; i < g_missing_count; i++) {
+        if (strcmp(g_missing[i], path) == 0) return;
+    }
+
+    printf("[ASSETS] Missing file: %s\n", path);
+    if (g_missing_count >= ASSETS_MAX_MISSING) return;
+
+    snprintf(g_missing[g_missing_count], ASSETS_PATH_MAX, "%s", path);
+    g_missing_count++;
+}
+
+bool assets_check_file(const char* path) {
+    if (!path) return false;
+    if (FileExists(path)) return true;
+
+    record_missing(path);
+    return false;
+}
+
+Shader assets_load_shader(const char* vs_path, const char* fs_path) {
+    // Check both so the overlay lists every missing file, not just the first
+    bool vs_found = assets_check_file(vs_path);
+    bool fs_found = assets_check_file(fs_path);
+    if (!vs_found || !fs_found) {
+        return (Shader){0};
+    }
+    return LoadShader(vs_path, fs_path);
+}
+
+bool assets_shader_is_loaded(Shader shader) {
+    return shader.id > 0 && shader.id != (unsigned int)rlGetShaderIdDefault();
+}
+
+int assets_get_missing_count(void) {
+    return g_missing_count;
+}
+
+const char* assets_get_missing(int index) {
+    if (index < 0 || index >= g_missing_count) return NULL;
+    return g_missing[index];
+}
+
+// ============================================================================
+// OVERLAY
+// ============================================================================
+
+void assets_toggle_overlay(void) {
+    g_overlay_visible = !g_overlay_visible;
+}
+
+void assets_draw_overlay(void) {
+    if (!g_overlay_visible) return;
+
+    int font_size = 14;
+    int line_height = font_size + 4;
+    int padding = 8;
+    int x = 10;
+    int y = 40;
+
+    char header[48];
+    if (g_missing_count == 0) {
+        snprintf(header, sizeof(header), "No missing assets");
+    } else {
+        snprintf(header, sizeof(header), "Missing assets (%d)", g_missing_count);
+    }
+
+    int width = MeasureText(header, font_size);
+    for (int i = 0; i < g_missing_count; i++) {
+        int line_width = MeasureText(g_missing[i], font_size);
+        if (line_width > width) width = line_width;
+    }
+    width += padding * 2;
+    int height = padding * 2 + line_height * (g_missing_count + 1);
+
+    DrawRectangle(x, y, width, height, (Color){0, 0, 0, 180});
+    DrawText(header, x + padding, y + padding, font_size,
+             g_missing_count == 0 ? (Color){120, 230, 120, 255} : (Color){255, 140, 120, 255});
+    for (int i = 0; i < g_missing_count; i++) {
+        DrawText(g_missing[i], x + padding, y + padding + line_height * (i + 1), font_size, WHITE);
+    }
+}
@@ -407,6 +407,17 @@ void block_system_init(void) {
         .requires_tool = true
     };
 
+    // SAPLING (Grows into a tree)
+    g_block_properties[BLOCK_SAPLING] = (BlockProperties){
+        .name = "Sapling",
+        .is_solid = true,
+        .is_transparent = true,
+        .is_fluid = false,
+        .hardness = 0.1f,
+        .preferred_tool = TOOL_NONE,
+        .requires_tool = false
+    };
+
     g_initialized = true;
     printf("[BLOCK] Block system initialized with %d block types\n", BLOCK_COUNT);
 }
@@ -434,6 +445,14 @@ bool block_is_solid(Block block) {
     return props->is_solid;
 }
 
+bool block_blocks_movement(Block block) {
+    if (block.type == BLOCK_WOOD_DOOR || block.type == BLOCK_IRON_DOOR) {
+        return (block.metadata & 1) == 0;
+    }
+    if (block.type == BLOCK_SAPLING) return false;
+    return block_is_solid(block);
+}
+
 /**
  * Check if block is transparent
  */
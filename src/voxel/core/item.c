@@ -5,6 +5,7 @@
 #include "voxel/core/item.h"
 #include <stdio.h>
 #include <string.h>
+#include <ctype.h>
 
 // ============================================================================
 // DROP TABLE
@@ -27,6 +28,7 @@ static const DropEntry g_drop_table[] = {
     {BLOCK_WATER,       ITEM_NONE,         0, 0},  // No drop
     {BLOCK_COBBLESTONE, ITEM_COBBLESTONE,  1, 1},
     {BLOCK_BEDROCK,     ITEM_NONE,         0, 0},  // Unbreakable
+    {BLOCK_SAPLING,     ITEM_SAPLING,      1, 1},
 };
 
 // ============================================================================
@@ -414,6 +416,19 @@ static const ItemProperties g_item_properties[ITEM_COUNT] = {
         .tool_type = TOOL_PICKAXE,
         .dig_speed = 4.0f,
     },
+    [ITEM_IRON_PICKAXE] = {
+        .name = "Iron Pickaxe",
+        .max_stack_size = 1,
+        .is_placeable = false,
+        .places_as = BLOCK_AIR,
+        .is_tool = true,
+        .durability = 251,
+        .atlas_tile_x = 3,
+        .atlas_tile_y = 2,
+        .tool_type = TOOL_PICKAXE,
+        .dig_speed = 4.0f,      // No faster than stone - it clears nine blocks per swing
+        .mine_radius = 1,
+    },
     [ITEM_WOODEN_SHOVEL] = {
         .name = "Wooden Shovel",
         .max_stack_size = 1,
@@ -521,6 +536,8 @@ static const ItemProperties g_item_properties[ITEM_COUNT] = {
         .atlas_tile_y = 0,
         .tool_type = TOOL_NONE,
         .dig_speed = 1.0f,
+        .food_effect = EFFECT_SPEED,
+        .food_duration = 30.0f,
     },
 
     // Beds (16 colors)
@@ -742,6 +759,48 @@ static const ItemProperties g_item_properties[ITEM_COUNT] = {
         .tool_type = TOOL_NONE,
         .dig_speed = 1.0f,
     },
+
+    // Vegetation
+    [ITEM_SAPLING] = {
+        .name = "Sapling",
+        .max_stack_size = 64,
+        .is_placeable = true,
+        .places_as = BLOCK_SAPLING,
+        .is_tool = false,
+        .durability = 0,
+        .atlas_tile_x = 1,
+        .atlas_tile_y = 4,
+        .tool_type = TOOL_NONE,
+        .dig_speed = 1.0f,
+    },
+
+    // Upgrades
+    [ITEM_BACKPACK] = {
+        .name = "Backpack",
+        .max_stack_size = 1,
+        .is_placeable = false,
+        .places_as = BLOCK_AIR,
+        .is_tool = false,
+        .durability = 0,
+        .atlas_tile_x = 0,      // Brown wool texture
+        .atlas_tile_y = 34,
+        .tool_type = TOOL_NONE,
+        .dig_speed = 1.0f,
+    },
+
+    // Gadgets
+    [ITEM_ORE_SCANNER] = {
+        .name = "Ore Scanner",
+        .max_stack_size = 1,
+        .is_placeable = false,
+        .places_as = BLOCK_AIR,
+        .is_tool = false,
+        .durability = 0,
+        .atlas_tile_x = 6,
+        .atlas_tile_y = 1,
+        .tool_type = TOOL_NONE,
+        .dig_speed = 1.0f,
+    },
 };
 
 // ============================================================================
@@ -798,6 +857,43 @@ const char* item_get_name(ItemType type) {
     return props->name;
 }
 
+ItemType item_find_by_name(const char* name) {
+    if (!name) return ITEM_NONE;
+
+    for (int type = 1; type < ITEM_COUNT; type++) {
+        const char* a = item_get_name(type);
+        const char* b = name;
+        if (!a) continue;
+        while (*a && *b && tolower((unsigned char)*a) == tolower((unsigned char)*b)) {
+            a++;
+            b++;
+        }
+        if (*a == '\0' && *b == '\0') return (ItemType)type;
+    }
+    return ITEM_NONE;
+}
+
+bool item_is_food(ItemType type) {
+    return item_get_properties(type)->food_effect != EFFECT_NONE;
+}
+
+const char* item_get_effect_name(EffectType effect) {
+    switch (effect) {
+        case EFFECT_SPEED: return "Swiftness";
+        default:           return "None";
+    }
+}
+
+const char* item_get_tool_type_name(ToolType type) {
+    switch (type) {
+        case TOOL_PICKAXE: return "pickaxe";
+        case TOOL_AXE:     return "axe";
+        case TOOL_SHOVEL:  return "shovel";
+        case TOOL_SWORD:   return "sword";
+        default:           return "hand";
+    }
+}
+
 float item_calculate_dig_time(BlockType block, ItemType tool) {
     const BlockProperties* bp = block_get_properties(block);
     const ItemProperties* ip = item_get_properties(tool);
@@ -0,0 +1,33 @@
+/**
+ * Game RNG Implementation
+ */
+
+#include "voxel/core/rng.h"
+
+void rng_seed(GameRng* rng, uint64_t seed) {
+    if (!rng) return;
+    rng->state = seed;
+}
+
+uint32_t rng_next(GameRng* rng) {
+    // splitmix64: advance by the golden ratio, then mix
+    uint64_t z = (rng->state += 0x9E3779B97F4A7C15ull);
+    z = (z ^ (z >> 30)) * 0xBF58476D1CE4E5B9ull;
+    z = (z ^ (z >> 27)) * 0x94D049BB133111EBull;
+    z ^= z >> 31;
+    return (uint32_t)(z >> 32);
+}
+
+float rng_float(GameRng* rng) {
+    // Top 24 bits fit a float mantissa exactly, so the result stays below 1
+    return (float)(rng_next(rng) >> 8) / 16777216.0f;
+}
+
+float rng_range(GameRng* rng, float min, float max) {
+    return min + rng_float(rng) * (max - min);
+}
+
+int rng_int(GameRng* rng, int max) {
+    if (max <= 0) return 0;
+    return (int)(rng_next(rng) % (uint32_t)max);
+}
@@ -4,6 +4,7 @@
 
 #include "voxel/core/texture_atlas.h"
 #include "voxel/core/block.h"
+#include "voxel/core/assets.h"
 #include <raylib.h>
 #include <stdlib.h>
 #include <stdio.h>
@@ -58,6 +59,41 @@ static void generate_leaf_tile(Image* atlas, int tile_x, int tile_y, Color base_
     }
 }
 
+/**
+ * Generate a sapling tile: thin stem with a leaf tuft on a transparent background
+ */
+static void generate_sapling_tile(Image* atlas, int tile_x, int tile_y, Color stem_color, Color leaf_color) {
+    int start_x = tile_x * TILE_SIZE;
+    int start_y = tile_y * TILE_SIZE;
+    int center = TILE_SIZE / 2;
+
+    for (int y = 0; y < TILE_SIZE; y++) {
+        for (int x = 0; x < TILE_SIZE; x++) {
+            Color pixel_color = {0, 0, 0, 0};
+
+            // Stem: 2 pixels wide in the lower half
+            if (y >= center && (x == center - 1 || x == center)) {
+                pixel_color = stem_color;
+            }
+
+            // Leaf tuft: rough circle in the upper half
+            int dx = x - center;
+            int dy = y - (center - 2);
+            if (dx * dx + dy * dy <= 20) {
+                int noise = (x * 11 + y * 17) % 30 - 15;
+                pixel_color = (Color){
+                    (unsigned char)clamp_int(leaf_color.r + noise, 0, 255),
+                    (unsigned char)clamp_int(leaf_color.g + noise, 0, 255),
+                    (unsigned char)clamp_int(leaf_color.b + noise / 2, 0, 255),
+                    255
+                };
+            }
+
+            ImageDrawPixel(atlas, start_x + x, start_y + y, pixel_color);
+        }
+    }
+}
+
 /**
  * Draw a THICK black crack line with thin edge
  */
@@ -782,6 +818,9 @@ static Image generate_atlas_image(void) {
     // LEAVES - Row 4 (Bright foliage green with transparency holes - Luanti style)
     generate_leaf_tile(&atlas, 0, 4, (Color){60, 180, 75, 255});     // All faces: Semi-transparent leaves
 
+    // SAPLING - Row 4, next to leaves (small stem with leaf tuft)
+    generate_sapling_tile(&atlas, 1, 4, (Color){100, 70, 40, 255}, (Color){70, 160, 60, 255});
+
     // SAND - Row 5 (Warm golden sand)
     generate_tile(&atlas, 0, 5, (Color){240, 220, 130, 255}, true);   // All faces: Golden sand
 
@@ -880,6 +919,7 @@ static Image generate_atlas_image(void) {
     // PICKAXES - Column 3
     generate_pickaxe_tile(&atlas, 3, 0, wood_color);   // Wooden pickaxe
     generate_pickaxe_tile(&atlas, 3, 1, stone_color);  // Stone pickaxe
+    generate_pickaxe_tile(&atlas, 3, 2, (Color){200, 200, 210, 255});  // Iron pickaxe
 
     // SHOVELS - Column 4
     generate_shovel_tile(&atlas, 4, 0, wood_color);    // Wooden shovel
@@ -898,6 +938,9 @@ static Image generate_atlas_image(void) {
     // MEAT - Raw meat item (pinkish-red)
     generate_tile(&atlas, 6, 0, (Color){200, 100, 100, 255}, true);   // Raw meat
 
+    // ORE SCANNER - Gadget (teal casing)
+    generate_tile(&atlas, 6, 1, (Color){60, 190, 160, 255}, true);
+
     // STICK - Crafting material
     generate_stick_tile(&atlas, 2, 3);  // Stick at (2, 3) - already correct in item.c
 
@@ -970,7 +1013,7 @@ void texture_atlas_init(void) {
 
     // Create material with atlas texture
     // Load custom block shader for ambient lighting
-    Shader block_shader = LoadShader("shaders/block.vs", "shaders/block.fs");
+    Shader block_shader = assets_load_shader("shaders/block.vs", "shaders/block.fs");
 
     g_atlas_material = LoadMaterialDefault();
     if (block_shader.id > 0) {
@@ -999,6 +1042,25 @@ void texture_atlas_destroy(void) {
     printf("[ATLAS] Texture atlas destroyed\n");
 }
 
+/**
+ * Reload the block shader used by the atlas material
+ */
+bool texture_atlas_reload_shader(void) {
+    if (!g_initialized) return false;
+
+    Shader shader = assets_load_shader("shaders/block.vs", "shaders/block.fs");
+    if (!assets_shader_is_loaded(shader)) {
+        printf("[ATLAS] Block shader reload failed, keeping the current one\n");
+        return false;
+    }
+
+    // UnloadShader leaves raylib's default shader alone
+    UnloadShader(g_atlas_material.shader);
+    g_atlas_material.shader = shader;
+    printf("[ATLAS] Block shader reloaded (ID: %d)\n", shader.id);
+    return true;
+}
+
 // ============================================================================
 // TEXTURE COORDINATE LOOKUP
 // ============================================================================
@@ -1164,6 +1226,10 @@ TextureCoords texture_atlas_get_coords(BlockType block_type, BlockFace face) {
             }
             break;
 
+        case BLOCK_SAPLING:
+            tile_x = 1; tile_y = 4;
+            break;
+
         default:
             tile_x = 0; tile_y = 0;  // Default to first tile
             break;
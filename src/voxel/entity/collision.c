@@ -17,7 +17,7 @@
 // ============================================================================
 
 /**
- * Check if a point is inside a solid block
+ * Check if a point is inside a solid block (or closed door)
  */
 bool entity_is_solid_at(struct World* world, float x, float y, float z) {
     if (!world) return false;
@@ -27,7 +27,7 @@ bool entity_is_solid_at(struct World* world, float x, float y, float z) {
     int bz = (int)floorf(z);
 
     Block block = world_get_block(world, bx, by, bz);
-    return block_is_solid(block);
+    return block_blocks_movement(block);
 }
 
 // ============================================================================
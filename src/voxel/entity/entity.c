@@ -137,3 +137,13 @@ void entity_manager_render(EntityManager* manager) {
 int entity_manager_get_count(EntityManager* manager) {
     return manager ? manager->entity_count : 0;
 }
+
+int entity_manager_count_type(EntityManager* manager, EntityType type) {
+    if (!manager) return 0;
+
+    int count = 0;
+    for (Entity* entity = manager->entities; entity; entity = entity->next) {
+        if (entity->active && entity->type == type) count++;
+    }
+    return count;
+}
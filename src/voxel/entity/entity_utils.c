@@ -8,8 +8,16 @@
 #include <stdlib.h>
 #include <math.h>
 
+// Fallback generator until a world binds its own
+static GameRng g_default_rng = {0};
+static GameRng* g_rng = &g_default_rng;
+
+void entity_set_rng(GameRng* rng) {
+    g_rng = rng ? rng : &g_default_rng;
+}
+
 float entity_random_range(float min, float max) {
-    return min + ((float)rand() / RAND_MAX) * (max - min);
+    return rng_range(g_rng, min, max);
 }
 
 Vector3 entity_random_direction(void) {
@@ -0,0 +1,94 @@
+/**
+ * Mob Loot Tables Implementation
+ */
+
+#include "voxel/entity/loot.h"
+#include "voxel/entity/sheep.h"
+#include <stdlib.h>
+
+// ============================================================================
+// LOOT TABLES
+// ============================================================================
+
+static const LootEntry g_sheep_loot[] = {
+    {ITEM_MEAT,       1.0f, 1, 2},
+    {ITEM_WOOL_WHITE, 1.0f, 1, 3},  // Recolored to the sheep's wool
+    {ITEM_WOOL_WHITE, 0.25f, 1, 1}, // Occasional extra tuft
+};
+
+static const LootEntry g_pig_loot[] = {
+    {ITEM_MEAT,       1.0f, 1, 2},
+    {ITEM_MEAT,       0.5f, 1, 1},  // Pigs drop more meat on average
+};
+
+static const LootEntry g_zombie_loot[] = {
+    {ITEM_STICK,      0.5f, 1, 2},
+    {ITEM_IRON_BLOCK, 0.05f, 1, 1}, // Rare iron
+};
+
+// ============================================================================
+// API
+// ============================================================================
+
+const LootEntry* loot_get_table(EntityType type, int* out_count) {
+    const LootEntry* table = NULL;
+    int count = 0;
+
+    switch (type) {
+        case ENTITY_TYPE_SHEEP:
+            table = g_sheep_loot;
+            count = (int)(sizeof(g_sheep_loot) / sizeof(g_sheep_loot[0]));
+            break;
+        case ENTITY_TYPE_PIG:
+            table = g_pig_loot;
+            count = (int)(sizeof(g_pig_loot) / sizeof(g_pig_loot[0]));
+            break;
+        case ENTITY_TYPE_ZOMBIE:
+            table = g_zombie_loot;
+            count = (int)(sizeof(g_zombie_loot) / sizeof(g_zombie_loot[0]));
+            break;
+        default:
+            break;
+    }
+
+    if (out_count) *out_count = count;
+    return table;
+}
+
+int loot_roll(const Entity* entity, GameRng* rng, ItemStack* out_drops, int max_drops) {
+    if (!entity || !rng || !out_drops) return 0;
+
+    int entry_count = 0;
+    const LootEntry* table = loot_get_table(entity->type, &entry_count);
+    if (!table) return 0;
+
+    int drop_count = 0;
+    for (int i = 0; i < entry_count; i++) {
+        const LootEntry* entry = &table[i];
+        if (rng_float(rng) >= entry->chance) continue;
+
+        ItemType item = entry->item;
+        if (entity->type == ENTITY_TYPE_SHEEP && item == ITEM_WOOL_WHITE) {
+            item = sheep_get_wool_item(entity);
+        }
+
+        int range = entry->max_count - entry->min_count + 1;
+        int count = entry->min_count + rng_int(rng, range);
+
+        // Merge repeated entries for the same item into one stack
+        bool merged = false;
+        for (int j = 0; j < drop_count; j++) {
+            if (out_drops[j].type == item) {
+                out_drops[j].count += count;
+                merged = true;
+                break;
+            }
+        }
+        if (merged) continue;
+        if (drop_count >= max_drops) break;
+
+        out_drops[drop_count++] = (ItemStack){item, (uint8_t)count, 0, 0};
+    }
+
+    return drop_count;
+}
@@ -0,0 +1,217 @@
+/**
+ * Entity Pathfinding Implementation
+ *
+ * A* with a binary heap open list and an open-addressing hash
+ * to look up visited positions.
+ */
+
+#include "voxel/entity/pathfinding.h"
+#include "voxel/world/world.h"
+#include "voxel/core/block.h"
+#include <stdlib.h>
+#include <string.h>
+
+// Hash table size (power of two, at least twice PATH_MAX_NODES)
+#define PATH_HASH_SIZE 8192
+
+// ============================================================================
+// SEARCH STATE
+// ============================================================================
+
+typedef struct {
+    PathNode pos;
+    int parent;     // Index of parent node, -1 for start
+    int g;          // Cost from start
+    int f;          // g + heuristic
+    bool closed;
+} SearchNode;
+
+static SearchNode g_nodes[PATH_MAX_NODES];
+static int g_node_count = 0;
+
+static int g_heap[PATH_MAX_NODES];
+static int g_heap_size = 0;
+
+static int g_hash[PATH_HASH_SIZE];  // Node index + 1, 0 = empty
+
+// ============================================================================
+// INTERNAL HELPERS
+// ============================================================================
+
+/**
+ * Horizontal distance only - step-ups and drops cost the same 1 as a flat
+ * step, so counting height too would overestimate and break A*
+ */
+static int manhattan(PathNode a, PathNode b) {
+    return abs(a.x - b.x) + abs(a.z - b.z);
+}
+
+static unsigned int hash_pos(PathNode p) {
+    unsigned int h = (unsigned int)p.x * 73856093u ^
+                     (unsigned int)p.y * 19349663u ^
+                     (unsigned int)p.z * 83492791u;
+    return h & (PATH_HASH_SIZE - 1);
+}
+
+/**
+ * Find node index for a position, or -1 if not visited yet
+ */
+static int hash_find(PathNode p) {
+    unsigned int slot = hash_pos(p);
+    while (g_hash[slot] != 0) {
+        SearchNode* n = &g_nodes[g_hash[slot] - 1];
+        if (n->pos.x == p.x && n->pos.y == p.y && n->pos.z == p.z) {
+            return g_hash[slot] - 1;
+        }
+        slot = (slot + 1) & (PATH_HASH_SIZE - 1);
+    }
+    return -1;
+}
+
+static void hash_insert(PathNode p, int index) {
+    unsigned int slot = hash_pos(p);
+    while (g_hash[slot] != 0) {
+        slot = (slot + 1) & (PATH_HASH_SIZE - 1);
+    }
+    g_hash[slot] = index + 1;
+}
+
+static void heap_swap(int a, int b) {
+    int tmp = g_heap[a];
+    g_heap[a] = g_heap[b];
+    g_heap[b] = tmp;
+}
+
+static void heap_sift_up(int i) {
+    while (i > 0) {
+        int parent = (i - 1) / 2;
+        if (g_nodes[g_heap[parent]].f <= g_nodes[g_heap[i]].f) break;
+        heap_swap(i, parent);
+        i = parent;
+    }
+}
+
+static void heap_push(int node) {
+    g_heap[g_heap_size] = node;
+    heap_sift_up(g_heap_size);
+    g_heap_size++;
+}
+
+static int heap_pop(void) {
+    int top = g_heap[0];
+    g_heap[0] = g_heap[--g_heap_size];
+
+    int i = 0;
+    for (;;) {
+        int left = i * 2 + 1;
+        int right = left + 1;
+        int smallest = i;
+        if (left < g_heap_size && g_nodes[g_heap[left]].f < g_nodes[g_heap[smallest]].f) smallest = left;
+        if (right < g_heap_size && g_nodes[g_heap[right]].f < g_nodes[g_heap[smallest]].f) smallest = right;
+        if (smallest == i) break;
+        heap_swap(i, smallest);
+        i = smallest;
+    }
+    return top;
+}
+
+/**
+ * Re-sort a node after its cost decreased
+ */
+static void heap_decrease(int node) {
+    for (int i = 0; i < g_heap_size; i++) {
+        if (g_heap[i] == node) {
+            heap_sift_up(i);
+            return;
+        }
+    }
+}
+
+static bool is_passable(World* world, int x, int y, int z) {
+    Block block = world_get_block(world, x, y, z);
+    return !block_blocks_movement(block) && !block_is_fluid(block);
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+bool pathfinding_is_walkable(struct World* world, int x, int y, int z) {
+    if (!world || y <= 0) return false;
+
+    return block_blocks_movement(world_get_block(world, x, y - 1, z)) &&
+           is_passable(world, x, y, z) &&
+           is_passable(world, x, y + 1, z);
+}
+
+bool pathfinding_find_path(struct World* world, PathNode start, PathNode goal, Path* out_path) {
+    if (!world || !out_path) return false;
+    out_path->length = 0;
+
+    if (!pathfinding_is_walkable(world, goal.x, goal.y, goal.z)) return false;
+
+    g_node_count = 0;
+    g_heap_size = 0;
+    memset(g_hash, 0, sizeof(g_hash));
+
+    g_nodes[0] = (SearchNode){start, -1, 0, manhattan(start, goal), false};
+    g_node_count = 1;
+    hash_insert(start, 0);
+    heap_push(0);
+
+    static const int DIRS[4][2] = {{1, 0}, {-1, 0}, {0, 1}, {0, -1}};
+
+    while (g_heap_size > 0) {
+        int current = heap_pop();
+        SearchNode* node = &g_nodes[current];
+        if (node->closed) continue;
+        node->closed = true;
+
+        PathNode pos = node->pos;
+        if (pos.x == goal.x && pos.y == goal.y && pos.z == goal.z) {
+            // Count path length, then fill from the goal backwards
+            int length = 0;
+            for (int i = current; i != -1; i = g_nodes[i].parent) length++;
+            if (length > PATH_MAX_LENGTH) return false;
+
+            out_path->length = length;
+            for (int i = current; i != -1; i = g_nodes[i].parent) {
+                out_path->nodes[--length] = g_nodes[i].pos;
+            }
+            return true;
+        }
+
+        for (int d = 0; d < 4; d++) {
+            for (int dy = -1; dy <= 1; dy++) {
+                PathNode next = {pos.x + DIRS[d][0], pos.y + dy, pos.z + DIRS[d][1]};
+                if (!pathfinding_is_walkable(world, next.x, next.y, next.z)) continue;
+
+                // Stepping up needs headroom above the current position
+                if (dy > 0 && !is_passable(world, pos.x, pos.y + 2, pos.z)) continue;
+
+                int g = node->g + 1;
+                int existing = hash_find(next);
+                if (existing >= 0) {
+                    SearchNode* other = &g_nodes[existing];
+                    if (!other->closed && g < other->g) {
+                        other->g = g;
+                        other->f = g + manhattan(next, goal);
+                        other->parent = current;
+                        heap_decrease(existing);
+                    }
+                    continue;
+                }
+
+                // Search cap reached - treat goal as unreachable
+                if (g_node_count >= PATH_MAX_NODES) return false;
+
+                int index = g_node_count++;
+                g_nodes[index] = (SearchNode){next, current, g, g + manhattan(next, goal), false};
+                hash_insert(next, index);
+                heap_push(index);
+            }
+        }
+    }
+
+    return false;
+}
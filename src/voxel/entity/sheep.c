@@ -18,6 +18,20 @@
 #include <raymath.h>
 #include <rlgl.h>
 
+ItemType sheep_get_wool_item(const Entity* entity) {
+    const SheepData* data = entity ? (const SheepData*)entity->data : NULL;
+    if (!data) return ITEM_WOOL_WHITE;
+
+    // Simple color matching (based on RGB values)
+    Color c = data->wool_color;
+    if (c.r > 230 && c.g > 230 && c.b > 230) return ITEM_WOOL_WHITE;
+    if (c.r < 60 && c.g < 60 && c.b < 60) return ITEM_WOOL_BLACK;
+    if (c.r > 180 && c.g < 80 && c.b < 80) return ITEM_WOOL_RED;
+    if (c.r < 100 && c.g > 100 && c.b < 100) return ITEM_WOOL_GREEN;
+    if (c.r < 100 && c.g < 100 && c.b > 150) return ITEM_WOOL_BLUE;
+    return ITEM_WOOL_LIGHT_GRAY;
+}
+
 /**
  * Create sheep data with given colors
  */
@@ -1,7 +1,8 @@
 /**
- * Tree Generation & Leaf Decay System
+ * Tree Generation, Leaf Decay & Sapling Growth
  *
- * Generates procedural trees. Leaves decay over time when not connected to wood.
+ * Generates procedural trees. Leaves decay over time when not connected to wood,
+ * occasionally dropping saplings that grow back into trees.
  */
 
 #include "voxel/entity/tree.h"
@@ -686,8 +687,9 @@ static void place_cactus(Chunk* chunk, int x, int surface_y, int z) {
     }
 }
 
-void tree_generate_for_chunk(Chunk* chunk) {
+void tree_generate_for_chunk(Chunk* chunk, float density_scale) {
     if (!chunk) return;
+    if (density_scale < 0.0f) density_scale = 0.0f;
 
     for (int x = 0; x < CHUNK_SIZE; x++) {
         for (int z = 0; z < CHUNK_SIZE; z++) {
@@ -723,7 +725,7 @@ void tree_generate_for_chunk(Chunk* chunk) {
 
             // Calculate tree placement threshold based on biome density
             // Higher density = lower threshold = more trees
-            float threshold = 1.0f - bp->tree_density;
+            float threshold = 1.0f - bp->tree_density * density_scale;
 
             // Only place trees where noise exceeds threshold
             if (veg_noise > threshold) {
@@ -752,15 +754,10 @@ void tree_generate_for_chunk(Chunk* chunk) {
                     uint32_t hash = (uint32_t)(world_x * 73856093 ^ world_z * 19349663);
                     TreeSize size = (TreeSize)(hash % TREE_SIZE_COUNT);
 
-                    // Determine tree type from biome
-                    TreeType tree_type = bp->primary_tree;
-                    if (bp->secondary_tree != TREE_TYPE_COUNT) {
-                        // Use different hash bits for tree type selection
-                        float roll = (float)((hash >> 16) % 1000) / 1000.0f;
-                        if (roll < bp->secondary_chance) {
-                            tree_type = bp->secondary_tree;
-                        }
-                    }
+                    // Determine tree type from biome and elevation
+                    // Use different hash bits for tree type selection
+                    float roll = (float)((hash >> 16) % 1000) / 1000.0f;
+                    TreeType tree_type = biome_pick_tree_type(bp, surface_y, roll);
 
                     // Place tree with correct type (trunk starts above surface)
                     tree_place_at_typed(chunk, x, surface_y + 1, z, size, tree_type);
@@ -781,6 +778,18 @@ void tree_generate_for_chunk(Chunk* chunk) {
 #define MAX_DECAY_QUEUE 256
 #define LEAF_DECAY_RANGE 4  // Max distance leaves can be from wood
 
+// Sapling growth tuning
+#define MAX_SAPLINGS 128
+#define SAPLING_DROP_CHANCE 12         // 1 in N decayed leaves drops a sapling
+#define SAPLING_DROP_FALL 8            // Max blocks a sapling falls to find ground
+#define SAPLING_GROW_TIME_MIN 60.0f    // Seconds before a sapling can grow
+#define SAPLING_GROW_TIME_RANGE 60.0f  // Extra random growth time
+#define SAPLING_RETRY_TIME 30.0f       // Wait before retrying a blocked sapling
+#define SAPLING_DENSITY_RADIUS 4       // Horizontal range checked for nearby trunks
+#define SAPLING_DENSITY_CAP 3          // Max trunks in range before growth is blocked
+
+static void drop_sapling_below(struct World* world, int x, int y, int z);
+
 typedef struct {
     int x, y, z;
     float timer;  // Seconds until decay check
@@ -806,7 +815,7 @@ static bool is_in_decay_queue(int x, int y, int z) {
 }
 
 // Add a leaf to the decay queue
-static void add_to_decay_queue(int x, int y, int z) {
+static void add_to_decay_queue(GameRng* rng, int x, int y, int z) {
     if (g_decay_count >= MAX_DECAY_QUEUE) return;
     if (is_in_decay_queue(x, y, z)) return;
 
@@ -814,7 +823,7 @@ static void add_to_decay_queue(int x, int y, int z) {
     g_decay_queue[g_decay_count].y = y;
     g_decay_queue[g_decay_count].z = z;
     // Random delay between 0.5 and 2.0 seconds
-    g_decay_queue[g_decay_count].timer = 0.5f + ((float)(rand() % 150) / 100.0f);
+    g_decay_queue[g_decay_count].timer = rng_range(rng, 0.5f, 2.0f);
     g_decay_count++;
 }
 
@@ -831,7 +840,7 @@ void leaf_decay_on_wood_removed(struct World* world, int x, int y, int z) {
 
                 Block block = world_get_block(world, nx, ny, nz);
                 if (is_leaf_block(block.type)) {
-                    add_to_decay_queue(nx, ny, nz);
+                    add_to_decay_queue(&world->rng, nx, ny, nz);
                 }
             }
         }
@@ -923,6 +932,11 @@ void leaf_decay_update(struct World* world, float dt) {
                     Block air = {BLOCK_AIR, 0, 0};
                     world_set_block(world, x, y, z, air);
 
+                    // Occasionally drop a sapling onto the ground below
+                    if (rng_int(&world->rng, SAPLING_DROP_CHANCE) == 0) {
+                        drop_sapling_below(world, x, y, z);
+                    }
+
                     // Add nearby leaves to queue (chain reaction)
                     for (int dx = -1; dx <= 1; dx++) {
                         for (int dy = -1; dy <= 1; dy++) {
@@ -930,7 +944,7 @@ void leaf_decay_update(struct World* world, float dt) {
                                 if (dx == 0 && dy == 0 && dz == 0) continue;
                                 Block neighbor = world_get_block(world, x + dx, y + dy, z + dz);
                                 if (is_leaf_block(neighbor.type)) {
-                                    add_to_decay_queue(x + dx, y + dy, z + dz);
+                                    add_to_decay_queue(&world->rng, x + dx, y + dy, z + dz);
                                 }
                             }
                         }
@@ -944,3 +958,144 @@ void leaf_decay_update(struct World* world, float dt) {
         }
     }
 }
+
+// ============================================================================
+// SAPLING GROWTH SYSTEM
+// ============================================================================
+
+typedef struct {
+    int x, y, z;
+    float timer;  // Seconds until growth attempt
+} SaplingEntry;
+
+static SaplingEntry g_saplings[MAX_SAPLINGS];
+static int g_sapling_count = 0;
+
+void sapling_init(void) {
+    g_sapling_count = 0;
+}
+
+bool sapling_is_soil(BlockType type) {
+    return type == BLOCK_GRASS || type == BLOCK_DIRT;
+}
+
+// Count trunk columns near a position (bottom-most wood block per column)
+static int count_nearby_trunks(struct World* world, int x, int y, int z) {
+    int trunks = 0;
+    for (int dx = -SAPLING_DENSITY_RADIUS; dx <= SAPLING_DENSITY_RADIUS; dx++) {
+        for (int dz = -SAPLING_DENSITY_RADIUS; dz <= SAPLING_DENSITY_RADIUS; dz++) {
+            for (int dy = -2; dy <= 2; dy++) {
+                Block block = world_get_block(world, x + dx, y + dy, z + dz);
+                Block below = world_get_block(world, x + dx, y + dy - 1, z + dz);
+                if (is_wood_block(block.type) && !is_wood_block(below.type)) {
+                    trunks++;
+                    break;
+                }
+            }
+        }
+    }
+    return trunks;
+}
+
+// Place a tree template using world coordinates (may span chunk borders)
+static void place_tree_in_world(struct World* world, int x, int y, int z,
+                                TreeSize size, TreeType type) {
+    int count;
+    const TreeBlock* template = get_template_typed(size, type, &count);
+
+    for (int i = 0; i < count; i++) {
+        int bx = x + template[i].dx;
+        int by = y + template[i].dy;
+        int bz = z + template[i].dz;
+        if (by < 0 || by >= CHUNK_HEIGHT) continue;
+
+        // Don't overwrite existing solid blocks (except air and leaves)
+        Block existing = world_get_block(world, bx, by, bz);
+        if (existing.type != BLOCK_AIR && !is_leaf_block(existing.type)) continue;
+
+        Block block = {template[i].type, 0, 1};  // metadata=1: natural tree
+        world_set_block(world, bx, by, bz, block);
+    }
+}
+
+// Check that the trunk column above a sapling is clear
+static bool has_room_to_grow(struct World* world, int x, int y, int z) {
+    for (int dy = 1; dy <= 5; dy++) {
+        Block block = world_get_block(world, x, y + dy, z);
+        if (block.type != BLOCK_AIR && !is_leaf_block(block.type)) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void sapling_on_placed(struct World* world, int x, int y, int z) {
+    if (!world || g_sapling_count >= MAX_SAPLINGS) return;
+
+    g_saplings[g_sapling_count].x = x;
+    g_saplings[g_sapling_count].y = y;
+    g_saplings[g_sapling_count].z = z;
+    g_saplings[g_sapling_count].timer = SAPLING_GROW_TIME_MIN +
+        rng_float(&world->rng) * SAPLING_GROW_TIME_RANGE;
+    g_sapling_count++;
+}
+
+// Let a sapling fall from a decayed leaf and plant it on soil if the area isn't crowded
+static void drop_sapling_below(struct World* world, int x, int y, int z) {
+    for (int ny = y - 1; ny > y - SAPLING_DROP_FALL && ny > 0; ny--) {
+        Block block = world_get_block(world, x, ny, z);
+        if (block.type == BLOCK_AIR || is_leaf_block(block.type)) continue;
+
+        if (!sapling_is_soil(block.type)) return;
+        if (world_get_block(world, x, ny + 1, z).type != BLOCK_AIR) return;
+        if (count_nearby_trunks(world, x, ny + 1, z) >= SAPLING_DENSITY_CAP) return;
+
+        world_set_block(world, x, ny + 1, z, (Block){BLOCK_SAPLING, 0, 0});
+        sapling_on_placed(world, x, ny + 1, z);
+        return;
+    }
+}
+
+void sapling_update(struct World* world, float dt) {
+    if (!world || g_sapling_count == 0) return;
+
+    for (int i = g_sapling_count - 1; i >= 0; i--) {
+        SaplingEntry* sapling = &g_saplings[i];
+        sapling->timer -= dt * world_get_growth_rate(world_get_season(world->day)) *
+                          weather_get_growth_rate(&world->weather);
+        if (sapling->timer > 0) continue;
+
+        int x = sapling->x;
+        int y = sapling->y;
+        int z = sapling->z;
+
+        // Sapling was broken or lost its soil - stop tracking
+        Block block = world_get_block(world, x, y, z);
+        Block soil = world_get_block(world, x, y - 1, z);
+        if (block.type != BLOCK_SAPLING || !sapling_is_soil(soil.type)) {
+            g_saplings[i] = g_saplings[g_sapling_count - 1];
+            g_sapling_count--;
+            continue;
+        }
+
+        // Crowded or blocked - try again later
+        if (!has_room_to_grow(world, x, y, z) ||
+            count_nearby_trunks(world, x, y, z) >= SAPLING_DENSITY_CAP) {
+            sapling->timer = SAPLING_RETRY_TIME;
+            continue;
+        }
+
+        // Grow into a tree matching the local biome
+        const BiomeProperties* bp = biome_get_properties(biome_get_at(x, z));
+        float roll = rng_float(&world->rng);
+        TreeType type = biome_pick_tree_type(bp, y - 1, roll);
+        TreeSize size = (rng_int(&world->rng, 2) == 0) ? TREE_SMALL : TREE_MEDIUM;
+
+        world_set_block(world, x, y, z, (Block){BLOCK_AIR, 0, 0});
+        place_tree_in_world(world, x, y, z, size, type);
+        printf("[TREE] Sapling grew at (%d, %d, %d)\n", x, y, z);
+
+        g_saplings[i] = g_saplings[g_sapling_count - 1];
+        g_sapling_count--;
+    }
+}
@@ -0,0 +1,323 @@
+/**
+ * Zombie Entity Implementation
+ *
+ * Hostile night mob with wander/chase/attack AI
+ */
+
+#include "voxel/entity/zombie.h"
+#include "voxel/entity/collision.h"
+#include "voxel/entity/entity_utils.h"
+#include "voxel/world/world.h"
+#include "voxel/player/player.h"
+#include <stdlib.h>
+#include <stdio.h>
+#include <math.h>
+#include <float.h>
+#include <raylib.h>
+#include <raymath.h>
+
+/**
+ * Create zombie data
+ */
+static ZombieData* zombie_create_data(void) {
+    ZombieData* data = (ZombieData*)calloc(1, sizeof(ZombieData));
+    if (!data) {
+        printf("[ZOMBIE] Failed to allocate data\n");
+        return NULL;
+    }
+
+    // Green skin, torn cyan shirt, dark blue pants
+    data->body.appearance.head_color = (Color){90, 140, 80, 255};
+    data->body.appearance.torso_color = (Color){40, 150, 150, 255};
+    data->body.appearance.arm_color = (Color){90, 140, 80, 255};  // Bare arms
+    data->body.appearance.leg_color = (Color){50, 50, 120, 255};
+    data->body.ambient_light = (Vector3){1.0f, 1.0f, 1.0f};
+
+    // AI state - start wandering
+    data->state = ZOMBIE_STATE_WANDER;
+    data->wander_timer = entity_random_range(ZOMBIE_WANDER_TIME_MIN, ZOMBIE_WANDER_TIME_MAX);
+    data->wander_direction = entity_random_direction();
+    data->repath_timer = 0.0f;
+
+    // Health & damage
+    data->hp = 6;                   // Tougher than farm animals
+    data->damage_flash_timer = 0.0f;
+
+    return data;
+}
+
+// ============================================================================
+// AI HELPERS
+// ============================================================================
+
+/**
+ * Block position an entity is standing in (feet)
+ */
+static PathNode feet_node(Vector3 position) {
+    return (PathNode){(int)floorf(position.x), (int)floorf(position.y), (int)floorf(position.z)};
+}
+
+/**
+ * Pick the horizontal direction to walk toward the player
+ * Follows the current A* path, recomputing it periodically; walks straight
+ * at the player when no path is available.
+ */
+static Vector3 get_chase_direction(Entity* entity, ZombieData* data, struct World* world,
+                                   Vector3 player_pos, float dt) {
+    data->repath_timer -= dt;
+    if (data->repath_timer <= 0.0f) {
+        data->repath_timer = ZOMBIE_REPATH_TIME;
+        data->path_index = 1;  // Node 0 is where we stand
+        if (!pathfinding_find_path(world, feet_node(entity->position), feet_node(player_pos), &data->path)) {
+            data->path.length = 0;
+        }
+    }
+
+    Vector3 target = player_pos;
+    while (data->path_index < data->path.length) {
+        PathNode node = data->path.nodes[data->path_index];
+        Vector3 center = {node.x + 0.5f, entity->position.y, node.z + 0.5f};
+        Vector3 to_node = Vector3Subtract(center, entity->position);
+        to_node.y = 0;
+        if (Vector3Length(to_node) > ZOMBIE_WAYPOINT_RADIUS) {
+            target = center;
+            break;
+        }
+        data->path_index++;
+    }
+
+    Vector3 dir = Vector3Subtract(target, entity->position);
+    dir.y = 0;
+    float len = Vector3Length(dir);
+    return len > 0.01f ? Vector3Scale(dir, 1.0f / len) : (Vector3){0, 0, 0};
+}
+
+/**
+ * Hit the player: no health system yet, so hits knock the player back
+ */
+static void attack_player(Entity* entity, Player* player) {
+    Vector3 push = Vector3Subtract(player->position, entity->position);
+    push.y = 0;
+    float len = Vector3Length(push);
+    push = len > 0.01f ? Vector3Scale(push, 1.0f / len) : (Vector3){0, 0, 1};
+
+    player->velocity.x = push.x * ZOMBIE_KNOCKBACK;
+    player->velocity.z = push.z * ZOMBIE_KNOCKBACK;
+    if (player->is_grounded) {
+        player->velocity.y = ZOMBIE_KNOCKBACK_LIFT;
+        player->is_grounded = false;
+    }
+    player_shake_camera(player, ZOMBIE_HIT_SHAKE, ZOMBIE_HIT_SHAKE_TIME);
+
+    printf("[ZOMBIE] #%d hit the player\n", entity->id);
+}
+
+// ============================================================================
+// ENTITY CALLBACKS
+// ============================================================================
+
+/**
+ * Update function for zombie
+ * Handles AI state machine, movement, attacks and dawn despawn
+ */
+void zombie_update(Entity* entity, struct World* world, float dt) {
+    if (!entity || !entity->data) return;
+
+    ZombieData* data = (ZombieData*)entity->data;
+
+    // Zombies don't survive the sunrise; the spawner removes inactive ones
+    if (world && !world_is_night(world->time_of_day)) {
+        entity->active = false;
+        return;
+    }
+
+    // Horizontal distance to player
+    Player* player = world ? world->player : NULL;
+    float player_dist = FLT_MAX;
+    if (player) {
+        Vector3 to_player = Vector3Subtract(player->position, entity->position);
+        to_player.y = 0;
+        player_dist = Vector3Length(to_player);
+    }
+
+    // ========================================================================
+    // AI STATE MACHINE
+    // ========================================================================
+
+    if (player_dist <= ZOMBIE_ATTACK_RANGE) {
+        data->state = ZOMBIE_STATE_ATTACK;
+    } else if (player_dist <= ZOMBIE_DETECT_RANGE ||
+               (data->state != ZOMBIE_STATE_WANDER && player_dist <= ZOMBIE_LOSE_RANGE)) {
+        if (data->state == ZOMBIE_STATE_WANDER) {
+            data->repath_timer = 0.0f;  // Path right away when first spotting the player
+        }
+        data->state = ZOMBIE_STATE_CHASE;
+    } else {
+        data->state = ZOMBIE_STATE_WANDER;
+    }
+
+    if (data->attack_cooldown > 0) {
+        data->attack_cooldown -= dt;
+    }
+
+    Vector3 move_dir = {0, 0, 0};
+    float speed = 0.0f;
+
+    switch (data->state) {
+        case ZOMBIE_STATE_ATTACK: {
+            // Stand and swing at the player
+            Vector3 to_player = Vector3Subtract(player->position, entity->position);
+            entity->rotation.y = atan2f(to_player.x, to_player.z) * RAD2DEG;
+
+            if (data->attack_cooldown <= 0) {
+                attack_player(entity, player);
+                data->attack_cooldown = ZOMBIE_ATTACK_COOLDOWN;
+            }
+            break;
+        }
+        case ZOMBIE_STATE_CHASE:
+            move_dir = get_chase_direction(entity, data, world, player->position, dt);
+            speed = ZOMBIE_CHASE_SPEED;
+            break;
+        case ZOMBIE_STATE_WANDER:
+            data->wander_timer -= dt;
+            if (data->wander_timer <= 0) {
+                data->wander_direction = entity_random_direction();
+                data->wander_timer = entity_random_range(ZOMBIE_WANDER_TIME_MIN, ZOMBIE_WANDER_TIME_MAX);
+            }
+            move_dir = data->wander_direction;
+            speed = ZOMBIE_WANDER_SPEED;
+            break;
+    }
+
+    entity->velocity.x = move_dir.x * speed;
+    entity->velocity.z = move_dir.z * speed;
+    if (move_dir.x != 0 || move_dir.z != 0) {
+        entity->rotation.y = atan2f(move_dir.x, move_dir.z) * RAD2DEG;
+    }
+
+    // ========================================================================
+    // PHYSICS (using AABB collision system)
+    // ========================================================================
+
+    if (data->jump_cooldown > 0) {
+        data->jump_cooldown -= dt;
+    }
+
+    bool should_jump = false;
+    if (data->jump_cooldown <= 0 && (move_dir.x != 0 || move_dir.z != 0)) {
+        should_jump = entity_can_jump_obstacle(entity, world, move_dir);
+    }
+    if (should_jump) {
+        entity->velocity.y = ZOMBIE_JUMP_VELOCITY;
+        data->jump_cooldown = ZOMBIE_JUMP_COOLDOWN;
+    }
+
+    entity_apply_gravity(entity, world, dt, 20.0f);
+    int collision_flags = entity_move_with_collision(entity, world, dt);
+
+    if (COLLISION_HIT_WALL(collision_flags) && data->state == ZOMBIE_STATE_WANDER && !should_jump) {
+        data->wander_direction = entity_random_direction();
+    }
+
+    // ========================================================================
+    // ANIMATION, LIGHTING, DAMAGE FLASH
+    // ========================================================================
+
+    block_human_update(entity, world, dt);
+
+    if (data->damage_flash_timer > 0) {
+        data->damage_flash_timer -= dt;
+    }
+}
+
+/**
+ * Render function for zombie
+ * Reuses the block human model, tinted red while flashing from damage
+ */
+void zombie_render(Entity* entity) {
+    if (!entity || !entity->data) return;
+
+    ZombieData* data = (ZombieData*)entity->data;
+
+    if (data->damage_flash_timer > 0) {
+        BlockHumanAppearance saved = data->body.appearance;
+        Color flash = (Color){255, 100, 100, 255};
+        data->body.appearance = (BlockHumanAppearance){flash, flash, flash, flash};
+        block_human_render(entity);
+        data->body.appearance = saved;
+    } else {
+        block_human_render(entity);
+    }
+}
+
+/**
+ * Destroy function for zombie
+ * Frees the ZombieData
+ */
+void zombie_destroy(Entity* entity) {
+    if (!entity) return;
+
+    if (entity->data) {
+        free(entity->data);
+        entity->data = NULL;
+    }
+}
+
+// ============================================================================
+// DAMAGE API
+// ============================================================================
+
+/**
+ * Damage a zombie
+ * Returns true if the zombie died
+ */
+bool zombie_damage(Entity* entity, int damage) {
+    if (!entity || !entity->data) return false;
+
+    ZombieData* data = (ZombieData*)entity->data;
+    data->hp -= damage;
+    data->damage_flash_timer = 0.2f;
+
+    printf("[ZOMBIE] #%d took %d damage, HP: %d\n", entity->id, damage, data->hp);
+
+    return data->hp <= 0;
+}
+
+// ============================================================================
+// PUBLIC SPAWN API
+// ============================================================================
+
+/**
+ * Spawn a zombie
+ */
+Entity* zombie_spawn(EntityManager* manager, Vector3 position) {
+    if (!manager) return NULL;
+
+    Entity* entity = entity_create(ENTITY_TYPE_ZOMBIE);
+    if (!entity) return NULL;
+
+    entity->position = position;
+
+    // Same size as a block human
+    entity->bbox_min = (Vector3){-0.3f, 0.0f, -0.3f};
+    entity->bbox_max = (Vector3){0.3f, BLOCK_HUMAN_TOTAL_HEIGHT, 0.3f};
+
+    entity->update = zombie_update;
+    entity->render = zombie_render;
+    entity->destroy_data = zombie_destroy;
+
+    entity->data = zombie_create_data();
+    if (!entity->data) {
+        entity_destroy(entity);
+        return NULL;
+    }
+
+    entity->rotation.y = entity_random_range(0, 360);
+
+    entity_manager_add(manager, entity);
+
+    printf("[ZOMBIE] Spawned at (%.1f, %.1f, %.1f)\n", position.x, position.y, position.z);
+
+    return entity;
+}
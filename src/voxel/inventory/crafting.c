@@ -13,6 +13,7 @@
 
 static CraftingRecipe g_recipes[MAX_RECIPES];
 static int g_recipe_count = 0;
+static int g_recipes_dropped = 0;  // add_recipe calls past MAX_RECIPES
 
 /**
  * Helper to add a recipe to the database
@@ -21,6 +22,7 @@ static void add_recipe(RecipeType type, const ItemType inputs[9],
                       ItemType output, uint8_t output_count) {
     if (g_recipe_count >= MAX_RECIPES) {
         printf("[CRAFTING] ERROR: Recipe limit reached!\n");
+        g_recipes_dropped++;
         return;
     }
 
@@ -34,14 +36,13 @@ static void add_recipe(RecipeType type, const ItemType inputs[9],
 }
 
 // ============================================================================
-// INITIALIZATION
+// BUILT-IN RECIPES
 // ============================================================================
 
-void crafting_init(void) {
-    g_recipe_count = 0;
-
-    printf("[CRAFTING] Initializing crafting system...\n");
-
+/**
+ * Built-in recipes, used when there is no valid recipe file
+ */
+static void add_default_recipes(void) {
     // Recipe 1: Wood Log → 4 Wood Planks (shapeless)
     add_recipe(RECIPE_SHAPELESS,
         (ItemType[9]){
@@ -361,7 +362,276 @@ void crafting_init(void) {
         },
         ITEM_IRON_DOOR, 3);
 
-    printf("[CRAFTING] Loaded %d recipes\n", g_recipe_count);
+    // Recipe 47: Backpack (shaped)
+    // Pattern:
+    // W W W
+    // W I W
+    // W W W
+    add_recipe(RECIPE_SHAPED,
+        (ItemType[9]){
+            ITEM_WOOL_WHITE, ITEM_WOOL_WHITE, ITEM_WOOL_WHITE,
+            ITEM_WOOL_WHITE, ITEM_IRON_BLOCK, ITEM_WOOL_WHITE,
+            ITEM_WOOL_WHITE, ITEM_WOOL_WHITE, ITEM_WOOL_WHITE
+        },
+        ITEM_BACKPACK, 1);
+
+    // Recipe 48: Iron Pickaxe (shaped)
+    // Pattern:
+    // I I I
+    // . S .
+    // . S .
+    add_recipe(RECIPE_SHAPED,
+        (ItemType[9]){
+            ITEM_IRON_BLOCK, ITEM_IRON_BLOCK, ITEM_IRON_BLOCK,
+            ITEM_NONE, ITEM_STICK, ITEM_NONE,
+            ITEM_NONE, ITEM_STICK, ITEM_NONE
+        },
+        ITEM_IRON_PICKAXE, 1);
+
+    // Recipe 49: Ore Scanner (shaped)
+    // Pattern:
+    // . I .
+    // I D I
+    // . I .
+    add_recipe(RECIPE_SHAPED,
+        (ItemType[9]){
+            ITEM_NONE, ITEM_IRON_BLOCK, ITEM_NONE,
+            ITEM_IRON_BLOCK, ITEM_DIAMOND_BLOCK, ITEM_IRON_BLOCK,
+            ITEM_NONE, ITEM_IRON_BLOCK, ITEM_NONE
+        },
+        ITEM_ORE_SCANNER, 1);
+}
+
+// ============================================================================
+// RECIPE FILE
+// ============================================================================
+
+/**
+ * Strip leading and trailing whitespace in place
+ */
+static char* trim(char* text) {
+    while (*text == ' ' || *text == '\t') text++;
+    char* end = text + strlen(text);
+    while (end > text && (end[-1] == ' ' || end[-1] == '\t' || end[-1] == '\n' || end[-1] == '\r')) {
+        end--;
+    }
+    *end = '\0';
+    return text;
+}
+
+/**
+ * Parse a comma-separated list of item names ('.' = empty) into cells
+ * @return Number of cells, or -1 after reporting an error
+ */
+static int parse_item_row(char* text, ItemType* cells, int max_cells, const char* path, int line_no) {
+    int count = 0;
+    char* cursor = text;
+    while (cursor) {
+        char* comma = strchr(cursor, ',');
+        if (comma) *comma = '\0';
+        char* name = trim(cursor);
+        cursor = comma ? comma + 1 : NULL;
+
+        if (count >= max_cells) {
+            printf("[CRAFTING] %s:%d: more than %d items in a row\n", path, line_no, max_cells);
+            return -1;
+        }
+        if (strcmp(name, ".") == 0) {
+            cells[count++] = ITEM_NONE;
+            continue;
+        }
+        ItemType item = item_find_by_name(name);
+        if (item == ITEM_NONE) {
+            printf("[CRAFTING] %s:%d: unknown item '%s'\n", path, line_no, name);
+            return -1;
+        }
+        cells[count++] = item;
+    }
+    return count;
+}
+
+/**
+ * Check whether a line starts a new recipe ("shaped|shapeless <count> ...")
+ */
+static bool is_recipe_header(const char* line) {
+    char kind[16];
+    int count;
+    if (sscanf(line, "%15s %d", kind, &count) != 2) return false;
+    return strcmp(kind, "shaped") == 0 || strcmp(kind, "shapeless") == 0;
+}
+
+/**
+ * Add a finished recipe to the parsed list
+ * @return false after reporting an error if the list is full
+ */
+static bool store_parsed_recipe(CraftingRecipe* parsed, int* parsed_count, const CraftingRecipe* recipe,
+                                const char* path, int line_no) {
+    if (*parsed_count >= MAX_RECIPES) {
+        printf("[CRAFTING] %s:%d: more than %d recipes\n", path, line_no, MAX_RECIPES);
+        return false;
+    }
+    parsed[(*parsed_count)++] = *recipe;
+    return true;
+}
+
+bool crafting_load_file(const char* path) {
+    FILE* file = fopen(path, "r");
+    if (!file) return false;
+
+    // Parse into a scratch list so errors leave the current recipes alone
+    static CraftingRecipe parsed[MAX_RECIPES];
+    int parsed_count = 0;
+    int errors = 0;
+
+    CraftingRecipe recipe;
+    bool in_recipe = false;
+    bool skipping = false;   // After an error, skip to the next blank line
+    int rows = 0;
+
+    char buffer[256];
+    int line_no = 0;
+    while (fgets(buffer, sizeof(buffer), file)) {
+        line_no++;
+        char* comment = strchr(buffer, '#');
+        if (comment) *comment = '\0';
+        char* line = trim(buffer);
+
+        if (*line == '\0') {
+            skipping = false;
+            if (in_recipe) {
+                // A blank line ends a shaped recipe with fewer than 3 rows
+                if (rows == 0) {
+                    printf("[CRAFTING] %s:%d: recipe is missing rows\n", path, line_no);
+                    errors++;
+                } else if (!store_parsed_recipe(parsed, &parsed_count, &recipe, path, line_no)) {
+                    errors++;
+                }
+                in_recipe = false;
+            }
+            continue;
+        }
+        if (skipping) continue;
+
+        // So does the next recipe's header
+        if (in_recipe && rows > 0 && is_recipe_header(line)) {
+            if (!store_parsed_recipe(parsed, &parsed_count, &recipe, path, line_no)) {
+                errors++;
+            }
+            in_recipe = false;
+        }
+
+        if (!in_recipe) {
+            // Header: "shaped <count> <output>" or "shapeless <count> <output>"
+            char kind[16];
+            int count = 0;
+            int consumed = 0;
+            if (sscanf(line, "%15s %d %n", kind, &count, &consumed) != 2 || consumed == 0) {
+                printf("[CRAFTING] %s:%d: expected 'shaped|shapeless <count> <item>'\n", path, line_no);
+                errors++;
+                skipping = true;
+                continue;
+            }
+
+            memset(&recipe, 0, sizeof(recipe));
+            if (strcmp(kind, "shaped") == 0) {
+                recipe.type = RECIPE_SHAPED;
+            } else if (strcmp(kind, "shapeless") == 0) {
+                recipe.type = RECIPE_SHAPELESS;
+            } else {
+                printf("[CRAFTING] %s:%d: unknown recipe type '%s'\n", path, line_no, kind);
+                errors++;
+                skipping = true;
+                continue;
+            }
+
+            char* name = trim(line + consumed);
+            recipe.output = item_find_by_name(name);
+            if (recipe.output == ITEM_NONE) {
+                printf("[CRAFTING] %s:%d: unknown item '%s'\n", path, line_no, name);
+                errors++;
+                skipping = true;
+                continue;
+            }
+            int max_stack = item_get_properties(recipe.output)->max_stack_size;
+            if (count < 1 || count > max_stack) {
+                printf("[CRAFTING] %s:%d: count %d outside 1-%d\n", path, line_no, count, max_stack);
+                errors++;
+                skipping = true;
+                continue;
+            }
+            recipe.output_count = (uint8_t)count;
+            in_recipe = true;
+            rows = 0;
+            continue;
+        }
+
+        // Ingredient rows
+        bool shaped = recipe.type == RECIPE_SHAPED;
+        int max_cells = shaped ? 3 : 9;
+        if (parse_item_row(line, &recipe.inputs[shaped ? rows * 3 : 0], max_cells, path, line_no) < 0) {
+            errors++;
+            in_recipe = false;
+            skipping = true;
+            continue;
+        }
+
+        rows++;
+        if (shaped && rows < 3) continue;
+
+        in_recipe = false;
+        if (!store_parsed_recipe(parsed, &parsed_count, &recipe, path, line_no)) {
+            errors++;
+        }
+    }
+    fclose(file);
+
+    // End of file ends the last recipe like a blank line
+    if (in_recipe) {
+        if (rows == 0) {
+            printf("[CRAFTING] %s:%d: recipe is missing rows\n", path, line_no);
+            errors++;
+        } else if (!store_parsed_recipe(parsed, &parsed_count, &recipe, path, line_no)) {
+            errors++;
+        }
+    }
+    if (errors > 0 || parsed_count == 0) {
+        printf("[CRAFTING] Rejected %s (%d errors, %d recipes)\n", path, errors, parsed_count);
+        return false;
+    }
+
+    memcpy(g_recipes, parsed, sizeof(CraftingRecipe) * parsed_count);
+    g_recipe_count = parsed_count;
+    g_recipes_dropped = 0;
+    printf("[CRAFTING] Loaded %d recipes from %s\n", parsed_count, path);
+    return true;
+}
+
+// ============================================================================
+// INITIALIZATION
+// ============================================================================
+
+void crafting_init(void) {
+    g_recipe_count = 0;
+    g_recipes_dropped = 0;
+
+    printf("[CRAFTING] Initializing crafting system...\n");
+
+    if (!crafting_load_file(CRAFTING_RECIPE_FILE)) {
+        add_default_recipes();
+        printf("[CRAFTING] Loaded %d built-in recipes\n", g_recipe_count);
+    }
+
+    crafting_validate();
+}
+
+bool crafting_reload(void) {
+    if (!crafting_load_file(CRAFTING_RECIPE_FILE)) {
+        printf("[CRAFTING] No valid %s, keeping %d current recipes\n", CRAFTING_RECIPE_FILE, g_recipe_count);
+        return false;
+    }
+
+    crafting_validate();
+    return true;
 }
 
 // ============================================================================
@@ -665,7 +935,17 @@ const CraftingRecipe* crafting_get_recipe(int index) {
 bool crafting_can_craft_recipe(Inventory* inv, const CraftingRecipe* recipe) {
     if (!inv || !recipe) return false;
 
-    // Count required ingredients from recipe
+    // Count available items (hotbar + main inventory + crafting grid + held item)
+    int available[ITEM_COUNT];
+    inventory_get_totals(inv, available, true);
+
+    return crafting_count_crafts_from_totals(available, recipe) > 0;
+}
+
+int crafting_count_crafts_from_totals(const int totals[ITEM_COUNT], const CraftingRecipe* recipe) {
+    if (!totals || !recipe) return 0;
+
+    // Count required ingredients
     int required[ITEM_COUNT] = {0};
     for (int i = 0; i < 9; i++) {
         if (recipe->inputs[i] != ITEM_NONE) {
@@ -673,37 +953,18 @@ bool crafting_can_craft_recipe(Inventory* inv, const CraftingRecipe* recipe) {
         }
     }
 
-    // Count available items in inventory (hotbar + main inventory + crafting grid)
-    int available[ITEM_COUNT] = {0};
-    for (int i = 0; i < HOTBAR_SIZE; i++) {
-        if (inv->hotbar[i].type != ITEM_NONE) {
-            available[inv->hotbar[i].type] += inv->hotbar[i].count;
-        }
-    }
-    for (int i = 0; i < MAIN_INVENTORY_SIZE; i++) {
-        if (inv->main_inventory[i].type != ITEM_NONE) {
-            available[inv->main_inventory[i].type] += inv->main_inventory[i].count;
-        }
-    }
-    // Also count items in crafting grid (user may have moved items there)
-    for (int i = 0; i < 9; i++) {
-        if (inv->crafting_grid[i].type != ITEM_NONE) {
-            available[inv->crafting_grid[i].type] += inv->crafting_grid[i].count;
-        }
-    }
-    // Also count held item (cursor item being dragged)
-    if (inv->is_holding_item && inv->held_item.type != ITEM_NONE) {
-        available[inv->held_item.type] += inv->held_item.count;
-    }
-
-    // Check if all required ingredients are available
-    for (int i = 0; i < ITEM_COUNT; i++) {
-        if (required[i] > available[i]) {
-            return false;
+    // Find the minimum number of crafts possible
+    int max_crafts = 999;
+    for (int type = 0; type < ITEM_COUNT; type++) {
+        if (required[type] > 0) {
+            int possible = totals[type] / required[type];
+            if (possible < max_crafts) {
+                max_crafts = possible;
+            }
         }
     }
 
-    return true;
+    return max_crafts == 999 ? 0 : max_crafts;
 }
 
 const CraftingRecipe* crafting_find_recipe_for_output(ItemType output) {
@@ -717,47 +978,77 @@ const CraftingRecipe* crafting_find_recipe_for_output(ItemType output) {
     return NULL;
 }
 
-int crafting_count_available_crafts(Inventory* inv, const CraftingRecipe* recipe) {
-    if (!inv || !recipe) return 0;
+/**
+ * Add the raw cost of `quantity` items to raw, using and refilling leftovers
+ * from earlier batches. Returns false if a cycle is hit.
+ */
+static bool expand_raw_cost(ItemType item, int quantity, int raw[ITEM_COUNT],
+                            int leftover[ITEM_COUNT], bool in_progress[ITEM_COUNT]) {
+    int reused = leftover[item] < quantity ? leftover[item] : quantity;
+    leftover[item] -= reused;
+    quantity -= reused;
+    if (quantity == 0) return true;
+
+    const CraftingRecipe* recipe = crafting_find_recipe_for_output(item);
+    if (!recipe) {
+        raw[item] += quantity;
+        return true;
+    }
+    if (in_progress[item]) return false;
 
-    // Count required ingredients
-    int required[ITEM_COUNT] = {0};
+    in_progress[item] = true;
+    int crafts = (quantity + recipe->output_count - 1) / recipe->output_count;
     for (int i = 0; i < 9; i++) {
-        if (recipe->inputs[i] != ITEM_NONE) {
-            required[recipe->inputs[i]]++;
+        if (recipe->inputs[i] == ITEM_NONE) continue;
+        if (!expand_raw_cost(recipe->inputs[i], crafts, raw, leftover, in_progress)) {
+            return false;
         }
     }
+    leftover[item] += crafts * recipe->output_count - quantity;
+    in_progress[item] = false;
 
-    // Count available items from all sources
-    int available[ITEM_COUNT] = {0};
-    for (int i = 0; i < HOTBAR_SIZE; i++) {
-        if (inv->hotbar[i].type != ITEM_NONE) {
-            available[inv->hotbar[i].type] += inv->hotbar[i].count;
-        }
-    }
-    for (int i = 0; i < MAIN_INVENTORY_SIZE; i++) {
-        if (inv->main_inventory[i].type != ITEM_NONE) {
-            available[inv->main_inventory[i].type] += inv->main_inventory[i].count;
-        }
-    }
-    for (int i = 0; i < 9; i++) {
-        if (inv->crafting_grid[i].type != ITEM_NONE) {
-            available[inv->crafting_grid[i].type] += inv->crafting_grid[i].count;
-        }
+    return true;
+}
+
+bool crafting_get_raw_cost(ItemType item, int quantity, int raw_out[ITEM_COUNT]) {
+    memset(raw_out, 0, sizeof(int) * ITEM_COUNT);
+    if (quantity <= 0 || !crafting_find_recipe_for_output(item)) return false;
+
+    int leftover[ITEM_COUNT] = {0};
+    bool in_progress[ITEM_COUNT] = {false};
+    if (!expand_raw_cost(item, quantity, raw_out, leftover, in_progress)) {
+        memset(raw_out, 0, sizeof(int) * ITEM_COUNT);
+        return false;
     }
+    return true;
+}
 
-    // Find the minimum number of crafts possible
-    int max_crafts = 999;
-    for (int type = 0; type < ITEM_COUNT; type++) {
-        if (required[type] > 0) {
-            int possible = available[type] / required[type];
-            if (possible < max_crafts) {
-                max_crafts = possible;
+int crafting_recipes_using(ItemType input, const CraftingRecipe** out, int max_out) {
+    if (input == ITEM_NONE) return 0;
+
+    int found = 0;
+    for (int i = 0; i < g_recipe_count; i++) {
+        for (int j = 0; j < 9; j++) {
+            if (g_recipes[i].inputs[j] == input) {
+                if (out && found < max_out) {
+                    out[found] = &g_recipes[i];
+                }
+                found++;
+                break;
             }
         }
     }
+    return found;
+}
 
-    return max_crafts == 999 ? 0 : max_crafts;
+int crafting_count_available_crafts(Inventory* inv, const CraftingRecipe* recipe) {
+    if (!inv || !recipe) return 0;
+
+    // Count available items from all sources
+    int available[ITEM_COUNT];
+    inventory_get_totals(inv, available, false);
+
+    return crafting_count_crafts_from_totals(available, recipe);
 }
 
 /**
@@ -798,7 +1089,7 @@ static int take_items_from_inventory(Inventory* inv, ItemType type, int count) {
     }
 
     // Take from main inventory
-    for (int i = 0; i < MAIN_INVENTORY_SIZE && taken < count; i++) {
+    for (int i = 0; i < inventory_get_main_size(inv) && taken < count; i++) {
         if (inv->main_inventory[i].type == type) {
             int to_take = count - taken;
             if (to_take > inv->main_inventory[i].count) {
@@ -830,10 +1121,14 @@ bool crafting_auto_place_ingredients(Inventory* inv, const CraftingRecipe* recip
     // Clear crafting grid first
     for (int i = 0; i < 9; i++) {
         if (inv->crafting_grid[i].type != ITEM_NONE) {
-            // Return items to inventory
-            inventory_add_item(inv, inv->crafting_grid[i].type, inv->crafting_grid[i].count);
+            // Return items to inventory; give up if they don't all fit
+            uint8_t left = inventory_add_item_partial(inv, inv->crafting_grid[i].type, inv->crafting_grid[i].count);
+            inv->crafting_grid[i].count = left;
+            if (left > 0) {
+                crafting_update_output(inv);
+                return false;
+            }
             inv->crafting_grid[i].type = ITEM_NONE;
-            inv->crafting_grid[i].count = 0;
         }
     }
 
@@ -868,3 +1163,97 @@ bool crafting_auto_place_ingredients(Inventory* inv, const CraftingRecipe* recip
     printf("[CRAFTING] Auto-placed %d x recipe ingredients\n", actual_count);
     return true;
 }
+
+// ============================================================================
+// RECIPE VALIDATION
+// ============================================================================
+
+typedef enum {
+    VISIT_NONE,
+    VISIT_IN_PROGRESS,
+    VISIT_DONE
+} VisitState;
+
+/**
+ * Depth-first walk from an item through the recipes that produce it
+ * Returns true if the walk reaches an item already on the current path
+ */
+static bool find_recipe_cycle(ItemType item, VisitState visit[ITEM_COUNT], ItemType* cycle_item) {
+    if (visit[item] == VISIT_IN_PROGRESS) {
+        *cycle_item = item;
+        return true;
+    }
+    if (visit[item] == VISIT_DONE) return false;
+
+    visit[item] = VISIT_IN_PROGRESS;
+    for (int i = 0; i < g_recipe_count; i++) {
+        if (g_recipes[i].output != item) continue;
+        for (int j = 0; j < 9; j++) {
+            ItemType input = g_recipes[i].inputs[j];
+            if (input == ITEM_NONE) continue;
+            if (find_recipe_cycle(input, visit, cycle_item)) return true;
+        }
+    }
+    visit[item] = VISIT_DONE;
+    return false;
+}
+
+int crafting_validate(void) {
+    int problems = 0;
+
+    if (g_recipes_dropped > 0) {
+        printf("[CRAFTING] Validation: %d recipes dropped, raise MAX_RECIPES (%d)\n",
+               g_recipes_dropped, MAX_RECIPES);
+        problems++;
+    }
+
+    for (int i = 0; i < g_recipe_count; i++) {
+        const CraftingRecipe* recipe = &g_recipes[i];
+
+        if (recipe->output <= ITEM_NONE || recipe->output >= ITEM_COUNT || recipe->output_count == 0) {
+            printf("[CRAFTING] Validation: recipe %d has no output\n", i + 1);
+            problems++;
+            continue;
+        }
+
+        const char* output_name = item_get_name(recipe->output);
+        int input_count = 0;
+        bool bad_input = false;
+        for (int j = 0; j < 9; j++) {
+            if (recipe->inputs[j] < ITEM_NONE || recipe->inputs[j] >= ITEM_COUNT) {
+                bad_input = true;
+            } else if (recipe->inputs[j] != ITEM_NONE) {
+                input_count++;
+            }
+        }
+        if (bad_input || input_count == 0) {
+            printf("[CRAFTING] Validation: %s recipe has %s\n",
+                   output_name, bad_input ? "an invalid input" : "no inputs");
+            problems++;
+            continue;
+        }
+
+        // An earlier recipe with the same pattern makes this one uncraftable
+        const CraftingRecipe* match = crafting_find_match(recipe->inputs);
+        if (match != recipe) {
+            printf("[CRAFTING] Validation: %s recipe is shadowed by %s\n",
+                   output_name, match ? item_get_name(match->output) : "nothing (pattern never matches)");
+            problems++;
+        }
+    }
+
+    VisitState visit[ITEM_COUNT] = {VISIT_NONE};
+    for (int i = 0; i < g_recipe_count; i++) {
+        ItemType cycle_item = ITEM_NONE;
+        if (find_recipe_cycle(g_recipes[i].output, visit, &cycle_item)) {
+            printf("[CRAFTING] Validation: recipe cycle through %s\n", item_get_name(cycle_item));
+            problems++;
+            break;  // Visit state is left mid-walk, further results are unreliable
+        }
+    }
+
+    if (problems == 0) {
+        printf("[CRAFTING] Validation passed (%d recipes)\n", g_recipe_count);
+    }
+    return problems;
+}
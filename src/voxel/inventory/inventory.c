@@ -21,14 +21,15 @@ Inventory* inventory_create(void) {
     memset(inv->crafting_grid, 0, sizeof(inv->crafting_grid));
     memset(inv->crafting_output, 0, sizeof(inv->crafting_output));
 
+    inv->main_rows = MAIN_INVENTORY_BASE_ROWS;
     inv->selected_hotbar_slot = 0;
     inv->is_open = false;
     inv->is_holding_item = false;
     memset(&inv->held_item, 0, sizeof(ItemStack));
 
     printf("[INVENTORY] Inventory created (%d hotbar + %d main + %d crafting = %d total slots)\n",
-           HOTBAR_SIZE, MAIN_INVENTORY_SIZE, CRAFTING_GRID_SIZE + CRAFTING_OUTPUT_SIZE,
-           HOTBAR_SIZE + MAIN_INVENTORY_SIZE + CRAFTING_GRID_SIZE + CRAFTING_OUTPUT_SIZE);
+           HOTBAR_SIZE, inventory_get_main_size(inv), CRAFTING_GRID_SIZE + CRAFTING_OUTPUT_SIZE,
+           HOTBAR_SIZE + inventory_get_main_size(inv) + CRAFTING_GRID_SIZE + CRAFTING_OUTPUT_SIZE);
 
     return inv;
 }
@@ -47,6 +48,9 @@ void inventory_clear(Inventory* inv) {
     memset(inv->crafting_grid, 0, sizeof(inv->crafting_grid));
     memset(inv->crafting_output, 0, sizeof(inv->crafting_output));
     memset(&inv->held_item, 0, sizeof(ItemStack));
+    memset(&inv->trash_slot, 0, sizeof(ItemStack));
+    memset(inv->hotbar_locked, 0, sizeof(inv->hotbar_locked));
+    memset(inv->main_locked, 0, sizeof(inv->main_locked));
     inv->is_holding_item = false;
 }
 
@@ -60,6 +64,8 @@ ItemStack* inventory_get_slot(Inventory* inv, int index) {
     if (index >= 0 && index < HOTBAR_SIZE) {
         return &inv->hotbar[index];
     } else if (index >= HOTBAR_SIZE && index < HOTBAR_SIZE + MAIN_INVENTORY_SIZE) {
+        // Rows not unlocked yet have no slot
+        if (index - HOTBAR_SIZE >= inventory_get_main_size(inv)) return NULL;
         return &inv->main_inventory[index - HOTBAR_SIZE];
     } else if (index >= HOTBAR_SIZE + MAIN_INVENTORY_SIZE &&
                index < HOTBAR_SIZE + MAIN_INVENTORY_SIZE + CRAFTING_GRID_SIZE) {
@@ -76,6 +82,51 @@ int inventory_get_total_slots(Inventory* inv) {
     return HOTBAR_SIZE + MAIN_INVENTORY_SIZE + CRAFTING_GRID_SIZE + CRAFTING_OUTPUT_SIZE;
 }
 
+int inventory_get_main_size(const Inventory* inv) {
+    if (!inv) return 0;
+    return inv->main_rows * MAIN_INVENTORY_COLUMNS;
+}
+
+bool inventory_expand(Inventory* inv, int additional_rows) {
+    if (!inv || additional_rows <= 0) return false;
+    if (inv->main_rows >= MAIN_INVENTORY_MAX_ROWS) return false;
+
+    // Slots past main_rows are never written, so the new rows are already empty
+    inv->main_rows += additional_rows;
+    if (inv->main_rows > MAIN_INVENTORY_MAX_ROWS) {
+        inv->main_rows = MAIN_INVENTORY_MAX_ROWS;
+    }
+
+    printf("[INVENTORY] Expanded to %d main slots\n", inventory_get_main_size(inv));
+    return true;
+}
+
+void inventory_get_totals(Inventory* inv, int totals[ITEM_COUNT], bool include_held) {
+    memset(totals, 0, sizeof(int) * ITEM_COUNT);
+    if (!inv) return;
+
+    int main_size = inventory_get_main_size(inv);
+
+    for (int i = 0; i < HOTBAR_SIZE; i++) {
+        if (inv->hotbar[i].type != ITEM_NONE) {
+            totals[inv->hotbar[i].type] += inv->hotbar[i].count;
+        }
+    }
+    for (int i = 0; i < main_size; i++) {
+        if (inv->main_inventory[i].type != ITEM_NONE) {
+            totals[inv->main_inventory[i].type] += inv->main_inventory[i].count;
+        }
+    }
+    for (int i = 0; i < CRAFTING_GRID_SIZE; i++) {
+        if (inv->crafting_grid[i].type != ITEM_NONE) {
+            totals[inv->crafting_grid[i].type] += inv->crafting_grid[i].count;
+        }
+    }
+    if (include_held && inv->is_holding_item && inv->held_item.type != ITEM_NONE) {
+        totals[inv->held_item.type] += inv->held_item.count;
+    }
+}
+
 ItemStack* inventory_get_selected_hotbar_item(Inventory* inv) {
     if (!inv) return NULL;
     if (inv->selected_hotbar_slot < 0 || inv->selected_hotbar_slot >= HOTBAR_SIZE) {
@@ -98,6 +149,7 @@ bool inventory_can_add_item(Inventory* inv, ItemType type, uint8_t count) {
     if (!inv || type == ITEM_NONE || count == 0) return false;
 
     const ItemProperties* props = item_get_properties(type);
+    int main_size = inventory_get_main_size(inv);
     uint8_t remaining = count;
 
     // Try to stack with existing items in hotbar
@@ -110,7 +162,7 @@ bool inventory_can_add_item(Inventory* inv, ItemType type, uint8_t count) {
     }
 
     // Try to stack with existing items in main inventory
-    for (int i = 0; i < MAIN_INVENTORY_SIZE; i++) {
+    for (int i = 0; i < main_size; i++) {
         if (inv->main_inventory[i].type == type && inv->main_inventory[i].count < props->max_stack_size) {
             uint8_t space = props->max_stack_size - inv->main_inventory[i].count;
             if (space >= remaining) return true;
@@ -125,7 +177,7 @@ bool inventory_can_add_item(Inventory* inv, ItemType type, uint8_t count) {
     }
 
     // Count empty slots in main inventory
-    for (int i = 0; i < MAIN_INVENTORY_SIZE; i++) {
+    for (int i = 0; i < main_size; i++) {
         if (inv->main_inventory[i].type == ITEM_NONE) empty_slots++;
     }
 
@@ -137,8 +189,14 @@ bool inventory_can_add_item(Inventory* inv, ItemType type, uint8_t count) {
 
 bool inventory_add_item(Inventory* inv, ItemType type, uint8_t count) {
     if (!inv || type == ITEM_NONE || count == 0) return false;
+    return inventory_add_item_partial(inv, type, count) == 0;
+}
+
+uint8_t inventory_add_item_partial(Inventory* inv, ItemType type, uint8_t count) {
+    if (!inv || type == ITEM_NONE) return count;
 
     const ItemProperties* props = item_get_properties(type);
+    int main_size = inventory_get_main_size(inv);
     uint8_t remaining = count;
 
     // Phase 1: Try to stack with existing items in hotbar
@@ -152,7 +210,7 @@ bool inventory_add_item(Inventory* inv, ItemType type, uint8_t count) {
     }
 
     // Phase 2: Try to stack with existing items in main inventory
-    for (int i = 0; i < MAIN_INVENTORY_SIZE && remaining > 0; i++) {
+    for (int i = 0; i < main_size && remaining > 0; i++) {
         if (inv->main_inventory[i].type == type && inv->main_inventory[i].count < props->max_stack_size) {
             uint8_t space = props->max_stack_size - inv->main_inventory[i].count;
             uint8_t to_add = (remaining < space) ? remaining : space;
@@ -174,7 +232,7 @@ bool inventory_add_item(Inventory* inv, ItemType type, uint8_t count) {
     }
 
     // Phase 4: Create new stacks in main inventory empty slots
-    for (int i = 0; i < MAIN_INVENTORY_SIZE && remaining > 0; i++) {
+    for (int i = 0; i < main_size && remaining > 0; i++) {
         if (inv->main_inventory[i].type == ITEM_NONE) {
             uint8_t to_add = (remaining < props->max_stack_size) ? remaining : props->max_stack_size;
             inv->main_inventory[i].type = type;
@@ -185,8 +243,137 @@ bool inventory_add_item(Inventory* inv, ItemType type, uint8_t count) {
         }
     }
 
-    // Return true if all items were added
-    return remaining == 0;
+    return remaining;
+}
+
+bool inventory_return_held_item(Inventory* inv) {
+    if (!inv || !inv->is_holding_item) return true;
+
+    ItemStack* held = &inv->held_item;
+    int slot_count = HOTBAR_SIZE + inventory_get_main_size(inv);
+
+    if (held->type != ITEM_NONE && held->count > 0) {
+        const ItemProperties* props = item_get_properties(held->type);
+
+        // Top up matching stacks first
+        for (int i = 0; i < slot_count && held->count > 0; i++) {
+            ItemStack* slot = inventory_get_slot(inv, i);
+            if (slot->type != held->type || slot->count >= props->max_stack_size) continue;
+
+            uint8_t space = props->max_stack_size - slot->count;
+            uint8_t to_add = (held->count < space) ? held->count : space;
+            slot->count += to_add;
+            held->count -= to_add;
+        }
+
+        // The rest moves as a whole, so tools keep their wear
+        for (int i = 0; i < slot_count && held->count > 0; i++) {
+            ItemStack* slot = inventory_get_slot(inv, i);
+            if (slot->type != ITEM_NONE) continue;
+
+            *slot = *held;
+            held->count = 0;
+        }
+
+        if (held->count > 0) return false;  // Inventory full - keep holding the rest
+    }
+
+    *held = (ItemStack){ITEM_NONE, 0, 0, 0};
+    inv->is_holding_item = false;
+    return true;
+}
+
+// ============================================================================
+// SLOT LOCKING
+// ============================================================================
+
+bool inventory_is_slot_locked(const Inventory* inv, int index) {
+    if (!inv) return false;
+
+    if (index >= 0 && index < HOTBAR_SIZE) {
+        return inv->hotbar_locked[index];
+    } else if (index >= HOTBAR_SIZE && index < HOTBAR_SIZE + MAIN_INVENTORY_SIZE) {
+        return inv->main_locked[index - HOTBAR_SIZE];
+    }
+    return false;
+}
+
+bool inventory_toggle_slot_lock(Inventory* inv, int index) {
+    if (!inv) return false;
+
+    bool* lock = NULL;
+    if (index >= 0 && index < HOTBAR_SIZE) {
+        lock = &inv->hotbar_locked[index];
+    } else if (index >= HOTBAR_SIZE && index < HOTBAR_SIZE + inventory_get_main_size(inv)) {
+        lock = &inv->main_locked[index - HOTBAR_SIZE];
+    }
+    if (!lock) return false;
+
+    *lock = !*lock;
+    printf("[INVENTORY] Slot %d %s\n", index, *lock ? "locked" : "unlocked");
+    return *lock;
+}
+
+// ============================================================================
+// STARTING KITS
+// ============================================================================
+
+typedef struct {
+    ItemType type;
+    uint8_t count;
+} KitEntry;
+
+static const KitEntry g_kit_standard[] = {
+    {ITEM_WOODEN_PICKAXE, 1},
+    {ITEM_STICK, 4},
+};
+
+static const KitEntry g_kit_testing[] = {
+    {ITEM_STONE_PICKAXE, 1},
+    {ITEM_STONE_SHOVEL, 1},
+    {ITEM_STONE_AXE, 1},
+    {ITEM_WOOD_LOG, 16},
+    {ITEM_DIRT, 64},
+    {ITEM_COBBLESTONE, 32},
+    {ITEM_STONE_SWORD, 1},      // Sword for combat
+    {ITEM_WOOL_WHITE, 16},      // Wool for bed crafting
+    {ITEM_WOOD_PLANKS, 32},     // Planks for crafting
+    {ITEM_IRON_BLOCK, 8},       // Lands in the main inventory
+};
+
+void inventory_give_starting_kit(Inventory* inv, StartingKit kit) {
+    if (!inv) return;
+
+    const KitEntry* entries = NULL;
+    int entry_count = 0;
+    switch (kit) {
+        case STARTING_KIT_STANDARD:
+            entries = g_kit_standard;
+            entry_count = (int)(sizeof(g_kit_standard) / sizeof(g_kit_standard[0]));
+            break;
+        case STARTING_KIT_TESTING:
+            entries = g_kit_testing;
+            entry_count = (int)(sizeof(g_kit_testing) / sizeof(g_kit_testing[0]));
+            break;
+        default:
+            // Hardcore: logs, planks and sticks all come from bare-handed tree chopping
+            break;
+    }
+
+    for (int i = 0; i < entry_count; i++) {
+        inventory_add_item(inv, entries[i].type, entries[i].count);
+    }
+
+    printf("[INVENTORY] Gave %s starting kit\n", inventory_get_starting_kit_name(kit));
+}
+
+const char* inventory_get_starting_kit_name(StartingKit kit) {
+    switch (kit) {
+        case STARTING_KIT_STANDARD: return "Standard";
+        case STARTING_KIT_HARDCORE: return "Hardcore";
+        case STARTING_KIT_TESTING:  return "Testing";
+        default:                    return "Unknown";
+    }
 }
 
 bool inventory_remove_item(Inventory* inv, int slot_index, uint8_t count) {
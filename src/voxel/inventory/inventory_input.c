@@ -5,6 +5,9 @@
 #include "voxel/inventory/inventory_input.h"
 #include "voxel/core/item.h"
 #include "voxel/inventory/crafting.h"
+#include "voxel/inventory/inventory_ui.h"
+#include "voxel/player/achievements.h"
+#include "voxel/player/stats.h"
 #include <stdio.h>
 #include <math.h>
 
@@ -12,25 +15,40 @@
 // CONSTANTS (must match inventory_ui.c layout)
 // ============================================================================
 
-#define PANEL_X 50   // Adjusted to make room for crafting guide sidebar
-#define PANEL_Y 100
+// Section positions are offsets from the panel origin, which is centered
+// with the crafting guide at runtime (inventory_ui_get_panel_x/y)
 
 #define SLOT_SIZE 40
 #define SLOT_GAP 2
 
 // Crafting section
-#define CRAFT_X (PANEL_X + 20)
-#define CRAFT_Y (PANEL_Y + 50)
+#define CRAFT_X 20
+#define CRAFT_Y 50
 #define CRAFT_OUTPUT_X (CRAFT_X + 3 * (SLOT_SIZE + SLOT_GAP) + 40)
 #define CRAFT_OUTPUT_Y (CRAFT_Y + SLOT_SIZE - SLOT_SIZE / 2)
 
 // Main inventory section
-#define INV_X (PANEL_X + 20)
-#define INV_Y (PANEL_Y + 200)
+#define INV_X 20
+#define INV_Y 200
 
-// Hotbar section
+// Hotbar section (sits below however many main rows are unlocked)
 #define HOTBAR_X INV_X
-#define HOTBAR_Y (INV_Y + 3 * (SLOT_SIZE + SLOT_GAP) + 10)
+#define HOTBAR_Y(rows) (INV_Y + (rows) * (SLOT_SIZE + SLOT_GAP) + 10)
+
+// Trash slot (right of the hotbar mirror)
+#define TRASH_X (HOTBAR_X + HOTBAR_SIZE * (SLOT_SIZE + SLOT_GAP) + 8)
+
+// Hold-to-repeat on the crafting output
+#define CRAFT_REPEAT_DELAY 0.4f     // Hold this long before repeating
+#define CRAFT_REPEAT_INTERVAL 0.12f // Then craft again at this interval
+
+static float g_craft_hold_time = 0.0f;    // How long the output has been held
+static float g_craft_repeat_timer = 0.0f; // Time until the next repeat craft
+
+// Controller focus cursor (row/column in the focus layout, see get_focus_row_width)
+#define FOCUS_CRAFT_ROWS 3
+static int g_focus_row = FOCUS_CRAFT_ROWS;  // Start on the first main inventory row
+static int g_focus_col = 0;
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -44,6 +62,24 @@ static bool is_mouse_in_slot(int mouse_x, int mouse_y, int slot_x, int slot_y) {
             mouse_y >= slot_y && mouse_y < slot_y + SLOT_SIZE);
 }
 
+/**
+ * Check if a clicked hotbar/main slot is locked
+ */
+static bool is_slot_locked(const Inventory* inv, InventorySection section, int index) {
+    if (section == SECTION_HOTBAR) return inventory_is_slot_locked(inv, index);
+    if (section == SECTION_MAIN_INVENTORY) return inventory_is_slot_locked(inv, HOTBAR_SIZE + index);
+    return false;
+}
+
+/**
+ * Room left in a stack before it reaches its item's max stack size
+ * Never wraps around: a full (or overfull) stack has no room.
+ */
+static uint8_t get_stack_space(const ItemStack* slot) {
+    int max_stack = item_get_properties(slot->type)->max_stack_size;
+    return slot->count < max_stack ? (uint8_t)(max_stack - slot->count) : 0;
+}
+
 /**
  * Get pointer to a slot by section and index
  */
@@ -58,7 +94,7 @@ static ItemStack* get_slot_pointer(Inventory* inv, InventorySection section, int
             if (index == 0) return &inv->crafting_output[0];
             break;
         case SECTION_MAIN_INVENTORY:
-            if (index >= 0 && index < 27) return &inv->main_inventory[index];
+            if (index >= 0 && index < inventory_get_main_size(inv)) return &inv->main_inventory[index];
             break;
         case SECTION_HOTBAR:
             if (index >= 0 && index < 9) return &inv->hotbar[index];
@@ -74,14 +110,18 @@ static ItemStack* get_slot_pointer(Inventory* inv, InventorySection section, int
 // PUBLIC API
 // ============================================================================
 
-int inventory_input_get_clicked_slot(int mouse_x, int mouse_y, InventorySection* section) {
+int inventory_input_get_clicked_slot(const Inventory* inv, int mouse_x, int mouse_y, InventorySection* section) {
     *section = SECTION_NONE;
+    if (!inv) return -1;
+
+    int panel_x = inventory_ui_get_panel_x();
+    int panel_y = inventory_ui_get_panel_y();
 
     // Check crafting grid (3x3)
     for (int row = 0; row < 3; row++) {
         for (int col = 0; col < 3; col++) {
-            int x = CRAFT_X + col * (SLOT_SIZE + SLOT_GAP);
-            int y = CRAFT_Y + row * (SLOT_SIZE + SLOT_GAP);
+            int x = panel_x + CRAFT_X + col * (SLOT_SIZE + SLOT_GAP);
+            int y = panel_y + CRAFT_Y + row * (SLOT_SIZE + SLOT_GAP);
 
             if (is_mouse_in_slot(mouse_x, mouse_y, x, y)) {
                 *section = SECTION_CRAFTING_GRID;
@@ -91,16 +131,16 @@ int inventory_input_get_clicked_slot(int mouse_x, int mouse_y, InventorySection*
     }
 
     // Check crafting output slot
-    if (is_mouse_in_slot(mouse_x, mouse_y, CRAFT_OUTPUT_X, CRAFT_OUTPUT_Y)) {
+    if (is_mouse_in_slot(mouse_x, mouse_y, panel_x + CRAFT_OUTPUT_X, panel_y + CRAFT_OUTPUT_Y)) {
         *section = SECTION_CRAFTING_OUTPUT;
         return 0;
     }
 
-    // Check main inventory (3x9)
-    for (int row = 0; row < 3; row++) {
+    // Check main inventory (main_rows x 9)
+    for (int row = 0; row < inv->main_rows; row++) {
         for (int col = 0; col < 9; col++) {
-            int x = INV_X + col * (SLOT_SIZE + SLOT_GAP);
-            int y = INV_Y + row * (SLOT_SIZE + SLOT_GAP);
+            int x = panel_x + INV_X + col * (SLOT_SIZE + SLOT_GAP);
+            int y = panel_y + INV_Y + row * (SLOT_SIZE + SLOT_GAP);
 
             if (is_mouse_in_slot(mouse_x, mouse_y, x, y)) {
                 *section = SECTION_MAIN_INVENTORY;
@@ -111,8 +151,8 @@ int inventory_input_get_clicked_slot(int mouse_x, int mouse_y, InventorySection*
 
     // Check hotbar (1x9)
     for (int i = 0; i < 9; i++) {
-        int x = HOTBAR_X + i * (SLOT_SIZE + SLOT_GAP);
-        int y = HOTBAR_Y;
+        int x = panel_x + HOTBAR_X + i * (SLOT_SIZE + SLOT_GAP);
+        int y = panel_y + HOTBAR_Y(inv->main_rows);
 
         if (is_mouse_in_slot(mouse_x, mouse_y, x, y)) {
             *section = SECTION_HOTBAR;
@@ -120,56 +160,115 @@ int inventory_input_get_clicked_slot(int mouse_x, int mouse_y, InventorySection*
         }
     }
 
+    // Check trash slot
+    if (is_mouse_in_slot(mouse_x, mouse_y, panel_x + TRASH_X, panel_y + HOTBAR_Y(inv->main_rows))) {
+        *section = SECTION_TRASH;
+        return 0;
+    }
+
     return -1;
 }
 
-void inventory_input_handle_left_click(Inventory* inv, int mouse_x, int mouse_y) {
-    if (!inv) return;
+void inventory_input_get_trash_position(const Inventory* inv, int* x, int* y) {
+    int panel_x = inventory_ui_get_panel_x();
+    int panel_y = inventory_ui_get_panel_y();
+    int rows = inv ? inv->main_rows : MAIN_INVENTORY_BASE_ROWS;
+    *x = panel_x + TRASH_X;
+    *y = panel_y + HOTBAR_Y(rows);
+}
 
-    InventorySection section;
-    int slot_index = inventory_input_get_clicked_slot(mouse_x, mouse_y, &section);
+/**
+ * Left-click on the trash slot
+ * Only a stack explicitly dropped here is discarded; it stays in the slot
+ * and can be picked back up until the next drop replaces it.
+ */
+static void handle_trash_click(Inventory* inv) {
+    if (inv->is_holding_item) {
+        if (inv->trash_slot.type != ITEM_NONE) {
+            printf("[INPUT] Trash: discarded %d %s\n",
+                   inv->trash_slot.count, item_get_name(inv->trash_slot.type));
+        }
+        inv->trash_slot = inv->held_item;
+        inv->held_item = (ItemStack){ITEM_NONE, 0, 0, 0};
+        inv->is_holding_item = false;
+    } else if (inv->trash_slot.type != ITEM_NONE) {
+        // Take the last discarded stack back
+        inv->held_item = inv->trash_slot;
+        inv->is_holding_item = true;
+        inv->trash_slot = (ItemStack){ITEM_NONE, 0, 0, 0};
+    }
+}
 
-    if (slot_index == -1 || section == SECTION_NONE) {
-        return;  // No slot clicked
+/**
+ * Take the crafting output into the hand, consuming one set of ingredients
+ * @return true if an item was crafted
+ */
+static bool take_crafting_output(Inventory* inv) {
+    ItemStack* output_slot = &inv->crafting_output[0];
+
+    // Can only pick up if output has an item
+    if (output_slot->type == ITEM_NONE) {
+        return false;
     }
 
-    // Special case: clicking crafting output
-    if (section == SECTION_CRAFTING_OUTPUT) {
-        ItemStack* output_slot = &inv->crafting_output[0];
+    // Can only pick up if hand is empty or holding same item type
+    if (inv->is_holding_item && inv->held_item.type != output_slot->type) {
+        return false;  // Can't swap with crafting output
+    }
 
-        // Can only pick up if output has an item
-        if (output_slot->type == ITEM_NONE) {
-            return;
-        }
+    // Pick up crafted item
+    if (!inv->is_holding_item) {
+        // Empty hand - pick up output
+        inv->held_item = *output_slot;
+        inv->is_holding_item = true;
+    } else {
+        // Holding same item - try to stack
+        uint8_t space = get_stack_space(&inv->held_item);
 
-        // Can only pick up if hand is empty or holding same item type
-        if (inv->is_holding_item && inv->held_item.type != output_slot->type) {
-            return;  // Can't swap with crafting output
+        if (space < output_slot->count) {
+            return false;  // Not enough space in held stack
         }
 
-        // Pick up crafted item
-        if (!inv->is_holding_item) {
-            // Empty hand - pick up output
-            inv->held_item = *output_slot;
-            inv->is_holding_item = true;
-        } else {
-            // Holding same item - try to stack
-            const ItemProperties* props = item_get_properties(output_slot->type);
-            uint8_t space = props->max_stack_size - inv->held_item.count;
+        inv->held_item.count += output_slot->count;
+    }
 
-            if (space < output_slot->count) {
-                return;  // Not enough space in held stack
-            }
+    // Consume crafting inputs
+    ItemStack crafted = *output_slot;
+    if (crafting_try_craft(inv)) {
+        achievements_on_item_crafted(crafted.type, crafted.count);
+        stats_add(STAT_ITEMS_CRAFTED, crafted.count);
+        inv->items_crafted += crafted.count;
+    }
 
-            inv->held_item.count += output_slot->count;
-        }
+    return true;
+}
+
+void inventory_input_handle_left_click(Inventory* inv, int mouse_x, int mouse_y) {
+    if (!inv) return;
+
+    InventorySection section;
+    int slot_index = inventory_input_get_clicked_slot(inv, mouse_x, mouse_y, &section);
+
+    if (slot_index == -1 || section == SECTION_NONE) {
+        return;  // No slot clicked
+    }
 
-        // Consume crafting inputs
-        crafting_try_craft(inv);
+    if (section == SECTION_TRASH) {
+        handle_trash_click(inv);
+        return;
+    }
 
+    // Special case: clicking crafting output
+    if (section == SECTION_CRAFTING_OUTPUT) {
+        take_crafting_output(inv);
+        g_craft_hold_time = 0.0f;  // Holding from here repeats (see handle_left_hold)
+        g_craft_repeat_timer = 0.0f;
         return;
     }
 
+    // Locked slots can't be emptied or swapped by accident
+    if (is_slot_locked(inv, section, slot_index)) return;
+
     ItemStack* clicked_slot = get_slot_pointer(inv, section, slot_index);
     if (!clicked_slot) return;
 
@@ -212,8 +311,7 @@ void inventory_input_handle_left_click(Inventory* inv, int mouse_x, int mouse_y)
     if (inv->is_holding_item && clicked_slot->type != ITEM_NONE) {
         // Same item type - try to merge
         if (inv->held_item.type == clicked_slot->type) {
-            const ItemProperties* props = item_get_properties(clicked_slot->type);
-            uint8_t space_in_slot = props->max_stack_size - clicked_slot->count;
+            uint8_t space_in_slot = get_stack_space(clicked_slot);
 
             if (space_in_slot > 0) {
                 uint8_t transfer_amount = (inv->held_item.count <= space_in_slot)
@@ -249,11 +347,38 @@ void inventory_input_handle_left_click(Inventory* inv, int mouse_x, int mouse_y)
     }
 }
 
+void inventory_input_handle_left_hold(Inventory* inv, int mouse_x, int mouse_y, bool held, float dt) {
+    if (!inv) return;
+
+    InventorySection section = SECTION_NONE;
+    if (held) {
+        inventory_input_get_clicked_slot(inv, mouse_x, mouse_y, &section);
+    }
+
+    // Released or moved off the output: stop repeating
+    if (section != SECTION_CRAFTING_OUTPUT) {
+        g_craft_hold_time = 0.0f;
+        g_craft_repeat_timer = 0.0f;
+        return;
+    }
+
+    // The initial press already crafted once; wait before repeating
+    g_craft_hold_time += dt;
+    if (g_craft_hold_time < CRAFT_REPEAT_DELAY) return;
+
+    g_craft_repeat_timer -= dt;
+    if (g_craft_repeat_timer > 0.0f) return;
+    g_craft_repeat_timer = CRAFT_REPEAT_INTERVAL;
+
+    // Stops on its own once ingredients run out or the held stack is full
+    take_crafting_output(inv);
+}
+
 void inventory_input_handle_right_click(Inventory* inv, int mouse_x, int mouse_y) {
     if (!inv) return;
 
     InventorySection section;
-    int slot_index = inventory_input_get_clicked_slot(mouse_x, mouse_y, &section);
+    int slot_index = inventory_input_get_clicked_slot(inv, mouse_x, mouse_y, &section);
 
     if (slot_index == -1 || section == SECTION_NONE) {
         return;
@@ -264,6 +389,8 @@ void inventory_input_handle_right_click(Inventory* inv, int mouse_x, int mouse_y
         return;
     }
 
+    if (is_slot_locked(inv, section, slot_index)) return;
+
     ItemStack* clicked_slot = get_slot_pointer(inv, section, slot_index);
     if (!clicked_slot) return;
 
@@ -316,9 +443,7 @@ void inventory_input_handle_right_click(Inventory* inv, int mouse_x, int mouse_y
         clicked_slot->type == inv->held_item.type &&
         clicked_slot->type != ITEM_NONE) {
 
-        const ItemProperties* props = item_get_properties(clicked_slot->type);
-
-        if (clicked_slot->count < props->max_stack_size) {
+        if (get_stack_space(clicked_slot) > 0) {
             clicked_slot->count++;
             inv->held_item.count--;
 
@@ -337,11 +462,24 @@ void inventory_input_handle_right_click(Inventory* inv, int mouse_x, int mouse_y
     }
 }
 
+void inventory_input_handle_lock_click(Inventory* inv, int mouse_x, int mouse_y) {
+    if (!inv) return;
+
+    InventorySection section;
+    int slot_index = inventory_input_get_clicked_slot(inv, mouse_x, mouse_y, &section);
+
+    if (section == SECTION_HOTBAR) {
+        inventory_toggle_slot_lock(inv, slot_index);
+    } else if (section == SECTION_MAIN_INVENTORY) {
+        inventory_toggle_slot_lock(inv, HOTBAR_SIZE + slot_index);
+    }
+}
+
 void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y) {
     if (!inv) return;
 
     InventorySection section;
-    int slot_index = inventory_input_get_clicked_slot(mouse_x, mouse_y, &section);
+    int slot_index = inventory_input_get_clicked_slot(inv, mouse_x, mouse_y, &section);
 
     printf("[INPUT] Shift-click at (%d, %d) -> section=%d, slot=%d\n", mouse_x, mouse_y, section, slot_index);
 
@@ -366,6 +504,9 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         if (crafted.type == ITEM_NONE || crafted.count == 0) {
             return;
         }
+        achievements_on_item_crafted(crafted.type, crafted.count);
+        stats_add(STAT_ITEMS_CRAFTED, crafted.count);
+        inv->items_crafted += crafted.count;
 
         // Try to add to hotbar first, then main inventory
         const ItemProperties* props = item_get_properties(crafted.type);
@@ -373,8 +514,8 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
 
         // Phase 1: Stack with existing items in hotbar
         for (int i = 0; i < 9 && remaining > 0; i++) {
-            if (inv->hotbar[i].type == crafted.type) {
-                uint8_t space = props->max_stack_size - inv->hotbar[i].count;
+            if (!inv->hotbar_locked[i] && inv->hotbar[i].type == crafted.type) {
+                uint8_t space = get_stack_space(&inv->hotbar[i]);
                 uint8_t transfer = (remaining <= space) ? remaining : space;
                 inv->hotbar[i].count += transfer;
                 remaining -= transfer;
@@ -382,9 +523,9 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         }
 
         // Phase 2: Stack with existing items in main inventory
-        for (int i = 0; i < 27 && remaining > 0; i++) {
-            if (inv->main_inventory[i].type == crafted.type) {
-                uint8_t space = props->max_stack_size - inv->main_inventory[i].count;
+        for (int i = 0; i < inventory_get_main_size(inv) && remaining > 0; i++) {
+            if (!inv->main_locked[i] && inv->main_inventory[i].type == crafted.type) {
+                uint8_t space = get_stack_space(&inv->main_inventory[i]);
                 uint8_t transfer = (remaining <= space) ? remaining : space;
                 inv->main_inventory[i].count += transfer;
                 remaining -= transfer;
@@ -393,7 +534,7 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
 
         // Phase 3: Create new stacks in empty hotbar slots
         for (int i = 0; i < 9 && remaining > 0; i++) {
-            if (inv->hotbar[i].type == ITEM_NONE) {
+            if (!inv->hotbar_locked[i] && inv->hotbar[i].type == ITEM_NONE) {
                 uint8_t transfer = (remaining <= props->max_stack_size)
                     ? remaining : props->max_stack_size;
                 inv->hotbar[i].type = crafted.type;
@@ -405,8 +546,8 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         }
 
         // Phase 4: Create new stacks in empty main inventory slots
-        for (int i = 0; i < 27 && remaining > 0; i++) {
-            if (inv->main_inventory[i].type == ITEM_NONE) {
+        for (int i = 0; i < inventory_get_main_size(inv) && remaining > 0; i++) {
+            if (!inv->main_locked[i] && inv->main_inventory[i].type == ITEM_NONE) {
                 uint8_t transfer = (remaining <= props->max_stack_size)
                     ? remaining : props->max_stack_size;
                 inv->main_inventory[i].type = crafted.type;
@@ -422,6 +563,9 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         return;
     }
 
+    // Locked slots stay put
+    if (is_slot_locked(inv, section, slot_index)) return;
+
     ItemStack* clicked_slot = get_slot_pointer(inv, section, slot_index);
     if (!clicked_slot || clicked_slot->type == ITEM_NONE) {
         return;
@@ -443,7 +587,7 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         // Phase 1: Stack with existing same-type items in crafting grid
         for (int i = 0; i < 9 && remaining > 0; i++) {
             if (inv->crafting_grid[i].type == item_type) {
-                uint8_t space = props->max_stack_size - inv->crafting_grid[i].count;
+                uint8_t space = get_stack_space(&inv->crafting_grid[i]);
                 uint8_t transfer = (remaining <= space) ? remaining : space;
                 inv->crafting_grid[i].count += transfer;
                 remaining -= transfer;
@@ -485,8 +629,8 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
 
         // Phase 1: Stack with existing items in hotbar
         for (int i = 0; i < 9 && remaining > 0; i++) {
-            if (inv->hotbar[i].type == item_type) {
-                uint8_t space = props->max_stack_size - inv->hotbar[i].count;
+            if (!inv->hotbar_locked[i] && inv->hotbar[i].type == item_type) {
+                uint8_t space = get_stack_space(&inv->hotbar[i]);
                 uint8_t transfer = (remaining <= space) ? remaining : space;
                 inv->hotbar[i].count += transfer;
                 remaining -= transfer;
@@ -495,7 +639,7 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
 
         // Phase 2: Create new stacks in empty hotbar slots
         for (int i = 0; i < 9 && remaining > 0; i++) {
-            if (inv->hotbar[i].type == ITEM_NONE) {
+            if (!inv->hotbar_locked[i] && inv->hotbar[i].type == ITEM_NONE) {
                 uint8_t transfer = (remaining <= props->max_stack_size)
                     ? remaining : props->max_stack_size;
                 inv->hotbar[i].type = item_type;
@@ -507,9 +651,9 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         }
 
         // Phase 3: Stack with existing items in main inventory
-        for (int i = 0; i < 27 && remaining > 0; i++) {
-            if (inv->main_inventory[i].type == item_type) {
-                uint8_t space = props->max_stack_size - inv->main_inventory[i].count;
+        for (int i = 0; i < inventory_get_main_size(inv) && remaining > 0; i++) {
+            if (!inv->main_locked[i] && inv->main_inventory[i].type == item_type) {
+                uint8_t space = get_stack_space(&inv->main_inventory[i]);
                 uint8_t transfer = (remaining <= space) ? remaining : space;
                 inv->main_inventory[i].count += transfer;
                 remaining -= transfer;
@@ -517,8 +661,8 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         }
 
         // Phase 4: Create new stacks in empty main inventory slots
-        for (int i = 0; i < 27 && remaining > 0; i++) {
-            if (inv->main_inventory[i].type == ITEM_NONE) {
+        for (int i = 0; i < inventory_get_main_size(inv) && remaining > 0; i++) {
+            if (!inv->main_locked[i] && inv->main_inventory[i].type == ITEM_NONE) {
                 uint8_t transfer = (remaining <= props->max_stack_size)
                     ? remaining : props->max_stack_size;
                 inv->main_inventory[i].type = item_type;
@@ -545,3 +689,77 @@ void inventory_input_handle_shift_click(Inventory* inv, int mouse_x, int mouse_y
         crafting_update_output(inv);
     }
 }
+
+// ============================================================================
+// CONTROLLER FOCUS
+// ============================================================================
+
+/**
+ * Number of focus rows: crafting grid, unlocked main rows, hotbar
+ */
+static int get_focus_row_count(const Inventory* inv) {
+    return FOCUS_CRAFT_ROWS + inv->main_rows + 1;
+}
+
+/**
+ * Slots in a focus row: crafting rows are the 3x3 grid plus the output,
+ * the hotbar row ends with the trash slot
+ */
+static int get_focus_row_width(const Inventory* inv, int row) {
+    if (row < FOCUS_CRAFT_ROWS) return 4;
+    if (row == get_focus_row_count(inv) - 1) return HOTBAR_SIZE + 1;
+    return MAIN_INVENTORY_COLUMNS;
+}
+
+/**
+ * Wrap index into 0..count-1
+ */
+static int wrap_index(int index, int count) {
+    int wrapped = index % count;
+    return wrapped < 0 ? wrapped + count : wrapped;
+}
+
+void inventory_input_move_focus(const Inventory* inv, int dx, int dy) {
+    if (!inv) return;
+
+    int rows = get_focus_row_count(inv);
+    g_focus_row = wrap_index(g_focus_row + dy, rows);
+
+    // Keep the column inside the (possibly narrower) new row
+    int width = get_focus_row_width(inv, g_focus_row);
+    if (g_focus_col >= width) g_focus_col = width - 1;
+    g_focus_col = wrap_index(g_focus_col + dx, width);
+}
+
+void inventory_input_get_focus_center(const Inventory* inv, int* x, int* y) {
+    if (!inv) return;
+
+    // Rows can shrink when the inventory changes size; clamp before use
+    int rows = get_focus_row_count(inv);
+    if (g_focus_row >= rows) g_focus_row = rows - 1;
+    int width = get_focus_row_width(inv, g_focus_row);
+    if (g_focus_col >= width) g_focus_col = width - 1;
+
+    // Offsets from the panel origin
+    int slot_x, slot_y;
+    if (g_focus_row < FOCUS_CRAFT_ROWS) {
+        if (g_focus_col == 3) {
+            slot_x = CRAFT_OUTPUT_X;
+            slot_y = CRAFT_OUTPUT_Y;
+        } else {
+            slot_x = CRAFT_X + g_focus_col * (SLOT_SIZE + SLOT_GAP);
+            slot_y = CRAFT_Y + g_focus_row * (SLOT_SIZE + SLOT_GAP);
+        }
+    } else if (g_focus_row == rows - 1) {
+        slot_x = g_focus_col == HOTBAR_SIZE ? TRASH_X : HOTBAR_X + g_focus_col * (SLOT_SIZE + SLOT_GAP);
+        slot_y = HOTBAR_Y(inv->main_rows);
+    } else {
+        slot_x = INV_X + g_focus_col * (SLOT_SIZE + SLOT_GAP);
+        slot_y = INV_Y + (g_focus_row - FOCUS_CRAFT_ROWS) * (SLOT_SIZE + SLOT_GAP);
+    }
+
+    int panel_x = inventory_ui_get_panel_x();
+    int panel_y = inventory_ui_get_panel_y();
+    *x = panel_x + slot_x + SLOT_SIZE / 2;
+    *y = panel_y + slot_y + SLOT_SIZE / 2;
+}
@@ -28,9 +28,13 @@
 // Item icon size (centered in slot)
 #define ITEM_ICON_SIZE 32
 
-// Crafting guide sidebar constants (Luanti-style)
-#define GUIDE_X 520
-#define GUIDE_Y 100
+// Full inventory panel; centered together with the guide at runtime
+#define INVENTORY_PANEL_WIDTH 460   // Wide enough for 9 columns
+#define INVENTORY_PANEL_HEIGHT 480  // Same height as crafting guide
+#define LAYOUT_MARGIN 10            // Minimum distance from the window edge
+
+// Crafting guide sidebar constants (Luanti-style), right of the inventory panel
+#define GUIDE_GAP 10
 #define GUIDE_WIDTH 220
 #define GUIDE_HEIGHT 480
 
@@ -39,6 +43,13 @@
 #define BROWSER_COLS 6
 #define BROWSER_ROWS 5
 #define ITEMS_PER_PAGE (BROWSER_COLS * BROWSER_ROWS)  // 30 items
+#define BROWSER_SCROLLBAR_WIDTH 4
+
+// Tooltip "used in" list (name + stack + header + uses + overflow line)
+// plus the raw cost section (header + entries)
+#define TOOLTIP_MAX_USES 4
+#define TOOLTIP_MAX_RAW 5
+#define TOOLTIP_MAX_LINES (TOOLTIP_MAX_USES + TOOLTIP_MAX_RAW + 5)
 
 // Recipe preview
 #define PREVIEW_SLOT_SIZE 24
@@ -48,7 +59,7 @@
 // CRAFTING GUIDE STATE
 // ============================================================================
 
-static int guide_current_page = 0;
+static int guide_scroll_row = 0;  // First visible row of the item browser
 static ItemType guide_selected_item = ITEM_NONE;
 static char guide_search_text[64] = "";
 static bool guide_search_active = false;
@@ -56,10 +67,71 @@ static ItemType guide_filtered_items[ITEM_COUNT];
 static int guide_filtered_count = 0;
 static bool guide_initialized = false;
 
+// ============================================================================
+// CRAFT STATUS
+// ============================================================================
+
+typedef enum {
+    CRAFT_STATUS_READY,         // Enough ingredients for at least one craft
+    CRAFT_STATUS_MISSING,       // Has a recipe, ingredients missing
+    CRAFT_STATUS_NO_RECIPE      // Raw item, can't be crafted
+} CraftStatus;
+
+// Color-blind palette: blue/orange instead of green/red, plus status glyphs
+static bool g_colorblind_palette = false;
+
+static CraftStatus get_craft_status(const CraftingRecipe* recipe, int available) {
+    if (!recipe) return CRAFT_STATUS_NO_RECIPE;
+    return available > 0 ? CRAFT_STATUS_READY : CRAFT_STATUS_MISSING;
+}
+
+static Color craft_status_color(CraftStatus status) {
+    switch (status) {
+        case CRAFT_STATUS_READY:
+            return g_colorblind_palette ? (Color){86, 180, 233, 255} : GREEN;
+        case CRAFT_STATUS_MISSING:
+            return g_colorblind_palette ? (Color){230, 159, 0, 255} : RED;
+        default:
+            return GRAY;
+    }
+}
+
+/**
+ * Draw a check (ready) or cross (missing) so status doesn't rely on color
+ * Drawn with lines, so it shows the same over an item icon or an empty slot
+ */
+static void draw_craft_status_glyph(CraftStatus status, int x, int y, int size) {
+    if (status == CRAFT_STATUS_NO_RECIPE) return;
+
+    Color color = craft_status_color(status);
+    DrawRectangle(x, y, size, size, (Color){0, 0, 0, 180});
+
+    float pad = size * 0.2f;
+    float thick = size > 10 ? 2.0f : 1.5f;
+    if (status == CRAFT_STATUS_READY) {
+        Vector2 a = {x + pad, y + size * 0.55f};
+        Vector2 b = {x + size * 0.42f, y + size - pad};
+        Vector2 c = {x + size - pad, y + pad};
+        DrawLineEx(a, b, thick, color);
+        DrawLineEx(b, c, thick, color);
+    } else {
+        DrawLineEx((Vector2){x + pad, y + pad}, (Vector2){x + size - pad, y + size - pad}, thick, color);
+        DrawLineEx((Vector2){x + size - pad, y + pad}, (Vector2){x + pad, y + size - pad}, thick, color);
+    }
+}
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
 
+/**
+ * Center a panel of the given size along one window axis, keeping a margin
+ */
+static int center_in_window(int window_size, int panel_size) {
+    int pos = (window_size - panel_size) / 2;
+    return pos < LAYOUT_MARGIN ? LAYOUT_MARGIN : pos;
+}
+
 /**
  * Draw a single inventory slot
  */
@@ -81,6 +153,15 @@ static void draw_hotbar_slot(int x, int y, int size, bool selected) {
     }
 }
 
+/**
+ * Draw the locked-slot marker: gold inner border and a corner tab
+ */
+static void draw_lock_marker(int x, int y, int size) {
+    Color gold = (Color){220, 180, 60, 255};
+    DrawRectangleLines(x + 2, y + 2, size - 4, size - 4, gold);
+    DrawRectangle(x + 3, y + 3, 6, 6, gold);
+}
+
 /**
  * Draw item count in bottom-right of slot
  */
@@ -165,8 +246,8 @@ static void guide_update_filter(void) {
         }
     }
 
-    // Reset to first page when filter changes
-    guide_current_page = 0;
+    // Reset scroll when filter changes
+    guide_scroll_row = 0;
 }
 
 /**
@@ -186,6 +267,25 @@ static int guide_get_total_pages(void) {
     return (guide_filtered_count + ITEMS_PER_PAGE - 1) / ITEMS_PER_PAGE;
 }
 
+/**
+ * Get the largest valid scroll row (last row sits at the bottom of the browser)
+ */
+static int guide_get_max_scroll_row(void) {
+    int total_rows = (guide_filtered_count + BROWSER_COLS - 1) / BROWSER_COLS;
+    int max_row = total_rows - BROWSER_ROWS;
+    return max_row > 0 ? max_row : 0;
+}
+
+/**
+ * Scroll the item browser by a number of rows, clamped to the valid range
+ */
+static void guide_scroll_by(int rows) {
+    guide_scroll_row += rows;
+    int max_row = guide_get_max_scroll_row();
+    if (guide_scroll_row > max_row) guide_scroll_row = max_row;
+    if (guide_scroll_row < 0) guide_scroll_row = 0;
+}
+
 /**
  * Draw the search box
  */
@@ -213,7 +313,11 @@ static void draw_search_box(int x, int y, int width) {
  * Draw the item browser grid
  */
 static void draw_item_browser(int x, int y, Texture2D atlas, Inventory* inv) {
-    int start_idx = guide_current_page * ITEMS_PER_PAGE;
+    int start_idx = guide_scroll_row * BROWSER_COLS;
+
+    // Scan the inventory once for all visible recipes
+    int totals[ITEM_COUNT];
+    inventory_get_totals(inv, totals, true);
 
     for (int row = 0; row < BROWSER_ROWS; row++) {
         for (int col = 0; col < BROWSER_COLS; col++) {
@@ -239,8 +343,9 @@ static void draw_item_browser(int x, int y, Texture2D atlas, Inventory* inv) {
 
                 // Check if item has a recipe (tint if not craftable)
                 const CraftingRecipe* recipe = crafting_find_recipe_for_output(item);
-                bool can_craft = recipe && crafting_can_craft_recipe(inv, recipe);
-                Color tint = can_craft ? WHITE : (Color){150, 150, 150, 200};
+                int available = recipe ? crafting_count_crafts_from_totals(totals, recipe) : 0;
+                CraftStatus status = get_craft_status(recipe, available);
+                Color tint = status == CRAFT_STATUS_READY ? WHITE : (Color){150, 150, 150, 200};
 
                 // No recipe = darker
                 if (!recipe) tint = (Color){100, 100, 100, 150};
@@ -248,9 +353,28 @@ static void draw_item_browser(int x, int y, Texture2D atlas, Inventory* inv) {
                 int icon_x = slot_x + (BROWSER_ITEM_SIZE - 24) / 2;
                 int icon_y = slot_y + (BROWSER_ITEM_SIZE - 24) / 2;
                 draw_mini_item_icon(item, icon_x, icon_y, 24, atlas, tint);
+
+                if (g_colorblind_palette) {
+                    int glyph = 10;
+                    draw_craft_status_glyph(status, slot_x + BROWSER_ITEM_SIZE - glyph - 1,
+                                            slot_y + BROWSER_ITEM_SIZE - glyph - 1, glyph);
+                }
             }
         }
     }
+
+    // Scrollbar (only when the filtered list overflows the grid)
+    int max_row = guide_get_max_scroll_row();
+    if (max_row > 0) {
+        int track_x = x + BROWSER_COLS * (BROWSER_ITEM_SIZE + 2);
+        int track_height = BROWSER_ROWS * (BROWSER_ITEM_SIZE + 2) - 2;
+        int total_rows = max_row + BROWSER_ROWS;
+        int thumb_height = track_height * BROWSER_ROWS / total_rows;
+        int thumb_y = y + (track_height - thumb_height) * guide_scroll_row / max_row;
+
+        DrawRectangle(track_x, y, BROWSER_SCROLLBAR_WIDTH, track_height, (Color){30, 30, 30, 200});
+        DrawRectangle(track_x, thumb_y, BROWSER_SCROLLBAR_WIDTH, thumb_height, (Color){140, 140, 140, 255});
+    }
 }
 
 /**
@@ -260,17 +384,21 @@ static void draw_pagination(int x, int y) {
     int total_pages = guide_get_total_pages();
     if (total_pages <= 0) total_pages = 1;
 
+    // Page containing the last visible row (reaches total_pages at the bottom)
+    int max_row = guide_get_max_scroll_row();
+    int current_page = (guide_scroll_row >= max_row) ? total_pages - 1 : guide_scroll_row / BROWSER_ROWS;
+
     // Previous button
-    Color prev_color = (guide_current_page > 0) ? WHITE : DARKGRAY;
+    Color prev_color = (guide_scroll_row > 0) ? WHITE : DARKGRAY;
     DrawText("<", x, y, 16, prev_color);
 
     // Page indicator
-    const char* page_text = TextFormat("%d/%d", guide_current_page + 1, total_pages);
+    const char* page_text = TextFormat("%d/%d", current_page + 1, total_pages);
     int text_width = MeasureText(page_text, 12);
     DrawText(page_text, x + 60 - text_width / 2, y + 2, 12, LIGHTGRAY);
 
     // Next button
-    Color next_color = (guide_current_page < total_pages - 1) ? WHITE : DARKGRAY;
+    Color next_color = (guide_scroll_row < max_row) ? WHITE : DARKGRAY;
     DrawText(">", x + 110, y, 16, next_color);
 }
 
@@ -335,8 +463,14 @@ static void draw_recipe_preview(int x, int y, Texture2D atlas, Inventory* inv) {
 
     // Draw available crafts count
     int available = crafting_count_available_crafts(inv, recipe);
-    DrawText(TextFormat("Can craft: %d", available), x, grid_y + 3 * (PREVIEW_SLOT_SIZE + PREVIEW_GAP) + 4, 10,
-             available > 0 ? GREEN : RED);
+    CraftStatus status = get_craft_status(recipe, available);
+    int text_x = x;
+    int text_y = grid_y + 3 * (PREVIEW_SLOT_SIZE + PREVIEW_GAP) + 4;
+    if (g_colorblind_palette) {
+        draw_craft_status_glyph(status, text_x, text_y, 10);
+        text_x += 13;
+    }
+    DrawText(TextFormat("Can craft: %d", available), text_x, text_y, 10, craft_status_color(status));
 }
 
 /**
@@ -346,7 +480,8 @@ static void draw_craft_buttons(int x, int y, Inventory* inv) {
     const CraftingRecipe* recipe = crafting_find_recipe_for_output(guide_selected_item);
     bool can_craft = recipe && crafting_count_available_crafts(inv, recipe) > 0;
 
-    Color btn_color = can_craft ? (Color){60, 100, 60, 255} : (Color){60, 60, 60, 200};
+    Color ready_color = g_colorblind_palette ? (Color){40, 80, 120, 255} : (Color){60, 100, 60, 255};
+    Color btn_color = can_craft ? ready_color : (Color){60, 60, 60, 200};
     Color text_color = can_craft ? WHITE : GRAY;
 
     // Button dimensions
@@ -376,41 +511,44 @@ static void draw_craft_buttons(int x, int y, Inventory* inv) {
 static void draw_crafting_guide(Inventory* inv, Texture2D atlas) {
     guide_init_if_needed();
 
+    int guide_x = inventory_ui_get_panel_x() + INVENTORY_PANEL_WIDTH + GUIDE_GAP;
+    int guide_y = inventory_ui_get_panel_y();
+
     // Draw sidebar panel background
-    DrawRectangle(GUIDE_X, GUIDE_Y, GUIDE_WIDTH, GUIDE_HEIGHT, (Color){40, 40, 40, 240});
-    DrawRectangleLines(GUIDE_X, GUIDE_Y, GUIDE_WIDTH, GUIDE_HEIGHT, (Color){150, 150, 150, 255});
+    DrawRectangle(guide_x, guide_y, GUIDE_WIDTH, GUIDE_HEIGHT, (Color){40, 40, 40, 240});
+    DrawRectangleLines(guide_x, guide_y, GUIDE_WIDTH, GUIDE_HEIGHT, (Color){150, 150, 150, 255});
 
     // Draw "Crafting Guide" title
-    DrawText("Crafting Guide", GUIDE_X + 10, GUIDE_Y + 8, 14, WHITE);
+    DrawText("Crafting Guide", guide_x + 10, guide_y + 8, 14, WHITE);
 
     // Search box
-    draw_search_box(GUIDE_X + 10, GUIDE_Y + 28, GUIDE_WIDTH - 20);
+    draw_search_box(guide_x + 10, guide_y + 28, GUIDE_WIDTH - 20);
 
     // Divider
-    DrawLine(GUIDE_X + 10, GUIDE_Y + 52, GUIDE_X + GUIDE_WIDTH - 10, GUIDE_Y + 52,
+    DrawLine(guide_x + 10, guide_y + 52, guide_x + GUIDE_WIDTH - 10, guide_y + 52,
              (Color){100, 100, 100, 255});
 
     // Item browser grid
-    draw_item_browser(GUIDE_X + 10, GUIDE_Y + 58, atlas, inv);
+    draw_item_browser(guide_x + 10, guide_y + 58, atlas, inv);
 
     // Pagination
     int browser_height = BROWSER_ROWS * (BROWSER_ITEM_SIZE + 2);
-    draw_pagination(GUIDE_X + 45, GUIDE_Y + 60 + browser_height + 5);
+    draw_pagination(guide_x + 45, guide_y + 60 + browser_height + 5);
 
     // Divider before recipe preview
-    int preview_y = GUIDE_Y + 60 + browser_height + 28;
-    DrawLine(GUIDE_X + 10, preview_y - 3, GUIDE_X + GUIDE_WIDTH - 10, preview_y - 3,
+    int preview_y = guide_y + 60 + browser_height + 28;
+    DrawLine(guide_x + 10, preview_y - 3, guide_x + GUIDE_WIDTH - 10, preview_y - 3,
              (Color){100, 100, 100, 255});
 
     // Recipe preview (only if an item is selected)
     if (guide_selected_item != ITEM_NONE) {
-        draw_recipe_preview(GUIDE_X + 10, preview_y, atlas, inv);
+        draw_recipe_preview(guide_x + 10, preview_y, atlas, inv);
 
         // Craft buttons
-        draw_craft_buttons(GUIDE_X + 10, GUIDE_Y + GUIDE_HEIGHT - 32, inv);
+        draw_craft_buttons(guide_x + 10, guide_y + GUIDE_HEIGHT - 32, inv);
     } else {
-        DrawText("Click an item above", GUIDE_X + 20, preview_y + 20, 11, GRAY);
-        DrawText("to see its recipe", GUIDE_X + 25, preview_y + 35, 11, GRAY);
+        DrawText("Click an item above", guide_x + 20, preview_y + 20, 11, GRAY);
+        DrawText("to see its recipe", guide_x + 25, preview_y + 35, 11, GRAY);
     }
 }
 
@@ -440,6 +578,19 @@ void inventory_ui_draw_item_icon(ItemType type, int x, int y, int size, Texture2
     DrawTexturePro(atlas, source, dest, (Vector2){0, 0}, 0.0f, WHITE);
 }
 
+int inventory_ui_get_panel_x(void) {
+    // Inventory panel and crafting guide are centered as one block
+    return center_in_window(GetScreenWidth(), INVENTORY_PANEL_WIDTH + GUIDE_GAP + GUIDE_WIDTH);
+}
+
+int inventory_ui_get_panel_y(void) {
+    return center_in_window(GetScreenHeight(), INVENTORY_PANEL_HEIGHT);
+}
+
+void inventory_ui_set_colorblind_palette(bool enabled) {
+    g_colorblind_palette = enabled;
+}
+
 void inventory_ui_draw_hotbar(Inventory* inv, Texture2D atlas) {
     if (!inv) return;
 
@@ -457,6 +608,7 @@ void inventory_ui_draw_hotbar(Inventory* inv, Texture2D atlas) {
         bool selected = (i == inv->selected_hotbar_slot);
 
         draw_hotbar_slot(x, y, HOTBAR_SLOT_SIZE, selected);
+        if (inv->hotbar_locked[i]) draw_lock_marker(x, y, HOTBAR_SLOT_SIZE);
 
         // Draw item icon if slot has an item
         ItemStack* slot = &inv->hotbar[i];
@@ -502,6 +654,53 @@ void inventory_ui_draw_hotbar(Inventory* inv, Texture2D atlas) {
     }
 }
 
+void inventory_ui_draw_xp_bar(int level, int xp, int xp_to_next) {
+    int screen_width = GetScreenWidth();
+    int screen_height = GetScreenHeight();
+    int total_width = (HOTBAR_SLOT_SIZE * HOTBAR_SIZE) + (HOTBAR_GAP * (HOTBAR_SIZE - 1));
+    int bar_height = 5;
+    int bar_x = (screen_width - total_width) / 2;
+    int bar_y = screen_height - HOTBAR_PADDING_BOTTOM - bar_height - 4;
+
+    float progress = xp_to_next > 0 ? (float)xp / (float)xp_to_next : 0.0f;
+    if (progress > 1.0f) progress = 1.0f;
+
+    DrawRectangle(bar_x, bar_y, total_width, bar_height, (Color){0, 0, 0, 150});
+    DrawRectangle(bar_x, bar_y, (int)(total_width * progress), bar_height, (Color){120, 230, 60, 255});
+
+    // Level number centered above the bar, outlined like item counts
+    char text[8];
+    snprintf(text, sizeof(text), "%d", level);
+    int font_size = 16;
+    int text_x = bar_x + (total_width - MeasureText(text, font_size)) / 2;
+    int text_y = bar_y - font_size - 2;
+    DrawText(text, text_x + 1, text_y + 1, font_size, BLACK);
+    DrawText(text, text_x, text_y, font_size, (Color){120, 230, 60, 255});
+}
+
+void inventory_ui_draw_effects(const float effect_times[EFFECT_COUNT]) {
+    int screen_width = GetScreenWidth();
+    int screen_height = GetScreenHeight();
+    int total_width = (HOTBAR_SLOT_SIZE * HOTBAR_SIZE) + (HOTBAR_GAP * (HOTBAR_SIZE - 1));
+    int x = (screen_width + total_width) / 2 + 12;
+    int y = screen_height - HOTBAR_PADDING_BOTTOM + HOTBAR_SLOT_SIZE;
+    int font_size = 16;
+
+    // Stack upward from the hotbar's bottom edge
+    for (int i = EFFECT_NONE + 1; i < EFFECT_COUNT; i++) {
+        if (effect_times[i] <= 0.0f) continue;
+
+        char text[48];
+        int seconds = (int)ceilf(effect_times[i]);
+        snprintf(text, sizeof(text), "%s %d:%02d", item_get_effect_name((EffectType)i),
+                 seconds / 60, seconds % 60);
+
+        y -= font_size + 4;
+        DrawText(text, x + 1, y + 1, font_size, BLACK);
+        DrawText(text, x, y, font_size, (Color){255, 220, 120, 255});
+    }
+}
+
 void inventory_ui_draw_full_screen(Inventory* inv, Texture2D atlas) {
     if (!inv) return;
 
@@ -513,11 +712,11 @@ void inventory_ui_draw_full_screen(Inventory* inv, Texture2D atlas) {
     // Draw semi-transparent background overlay
     DrawRectangle(0, 0, screen_width, screen_height, (Color){0, 0, 0, 150});
 
-    // Draw inventory panel (adjusted to make room for crafting guide)
-    int panel_x = 50;
-    int panel_y = 100;
-    int panel_w = 460;  // Wide enough for 9 columns
-    int panel_h = 480;  // Same height as crafting guide
+    // Draw inventory panel (left of the crafting guide)
+    int panel_x = inventory_ui_get_panel_x();
+    int panel_y = inventory_ui_get_panel_y();
+    int panel_w = INVENTORY_PANEL_WIDTH;
+    int panel_h = INVENTORY_PANEL_HEIGHT;
 
     DrawRectangle(panel_x, panel_y, panel_w, panel_h, (Color){40, 40, 40, 240});
     DrawRectangleLines(panel_x, panel_y, panel_w, panel_h, (Color){150, 150, 150, 255});
@@ -568,19 +767,20 @@ void inventory_ui_draw_full_screen(Inventory* inv, Texture2D atlas) {
         draw_item_count(output_x, output_y, SLOT_SIZE, output_slot->count);
     }
 
-    // Section 2: Main Inventory (3 rows x 9 columns)
+    // Section 2: Main Inventory (main_rows x 9 columns, grows with backpacks)
     int inv_x = panel_x + 20;
     int inv_y = panel_y + 200;
 
     DrawText("Storage", inv_x, inv_y - 20, 16, LIGHTGRAY);
 
-    for (int row = 0; row < 3; row++) {
+    for (int row = 0; row < inv->main_rows; row++) {
         for (int col = 0; col < 9; col++) {
             int x = inv_x + col * (SLOT_SIZE + SLOT_GAP);
             int y = inv_y + row * (SLOT_SIZE + SLOT_GAP);
             int slot_index = row * 9 + col;
 
             draw_slot(x, y, SLOT_SIZE);
+            if (inv->main_locked[slot_index]) draw_lock_marker(x, y, SLOT_SIZE);
 
             // Draw item if present
             ItemStack* slot = &inv->main_inventory[slot_index];
@@ -595,13 +795,14 @@ void inventory_ui_draw_full_screen(Inventory* inv, Texture2D atlas) {
 
     // Section 3: Hotbar Mirror (1 row x 9 columns)
     int hotbar_x = inv_x;
-    int hotbar_y = inv_y + 3 * (SLOT_SIZE + SLOT_GAP) + 10;
+    int hotbar_y = inv_y + inv->main_rows * (SLOT_SIZE + SLOT_GAP) + 10;
 
     for (int i = 0; i < 9; i++) {
         int x = hotbar_x + i * (SLOT_SIZE + SLOT_GAP);
         int y = hotbar_y;
 
         draw_slot(x, y, SLOT_SIZE);
+        if (inv->hotbar_locked[i]) draw_lock_marker(x, y, SLOT_SIZE);
 
         // Draw item if present
         ItemStack* slot = &inv->hotbar[i];
@@ -613,6 +814,22 @@ void inventory_ui_draw_full_screen(Inventory* inv, Texture2D atlas) {
         }
     }
 
+    // Section 4: Trash slot (drop a held stack here to discard it)
+    int trash_x, trash_y;
+    inventory_input_get_trash_position(inv, &trash_x, &trash_y);
+    DrawRectangle(trash_x, trash_y, SLOT_SIZE, SLOT_SIZE, (Color){80, 30, 30, 200});
+    DrawRectangleLines(trash_x, trash_y, SLOT_SIZE, SLOT_SIZE, (Color){160, 60, 60, 255});
+    if (inv->trash_slot.type != ITEM_NONE) {
+        int icon_x = trash_x + (SLOT_SIZE - 28) / 2;
+        int icon_y = trash_y + (SLOT_SIZE - 28) / 2;
+        inventory_ui_draw_item_icon(inv->trash_slot.type, icon_x, icon_y, 28, atlas);
+        draw_item_count(trash_x, trash_y, SLOT_SIZE, inv->trash_slot.count);
+    } else {
+        int x_width = MeasureText("X", 20);
+        DrawText("X", trash_x + (SLOT_SIZE - x_width) / 2, trash_y + (SLOT_SIZE - 20) / 2, 20,
+                 (Color){160, 60, 60, 255});
+    }
+
     // Draw crafting guide sidebar
     draw_crafting_guide(inv, atlas);
 }
@@ -622,7 +839,7 @@ void inventory_ui_draw_tooltip(Inventory* inv, int mouse_x, int mouse_y) {
 
     // Get the slot being hovered
     InventorySection section;
-    int slot_index = inventory_input_get_clicked_slot(mouse_x, mouse_y, &section);
+    int slot_index = inventory_input_get_clicked_slot(inv, mouse_x, mouse_y, &section);
 
     if (slot_index == -1 || section == SECTION_NONE) {
         return;  // No slot hovered
@@ -641,7 +858,7 @@ void inventory_ui_draw_tooltip(Inventory* inv, int mouse_x, int mouse_y) {
             slot = &inv->crafting_output[0];
             break;
         case SECTION_MAIN_INVENTORY:
-            if (slot_index >= 0 && slot_index < 27) {
+            if (slot_index >= 0 && slot_index < inventory_get_main_size(inv)) {
                 slot = &inv->main_inventory[slot_index];
             }
             break;
@@ -650,6 +867,13 @@ void inventory_ui_draw_tooltip(Inventory* inv, int mouse_x, int mouse_y) {
                 slot = &inv->hotbar[slot_index];
             }
             break;
+        case SECTION_TRASH:
+            if (inv->trash_slot.type == ITEM_NONE) {
+                DrawText("Trash", mouse_x + 12, mouse_y + 12, 16, WHITE);
+                return;
+            }
+            slot = &inv->trash_slot;
+            break;
         default:
             return;
     }
@@ -658,14 +882,63 @@ void inventory_ui_draw_tooltip(Inventory* inv, int mouse_x, int mouse_y) {
         return;  // Empty slot
     }
 
-    // Get item name
-    const char* item_name = item_get_name(slot->type);
+    // Build tooltip lines: name, stack size, and recipes this item is used in
+    char lines[TOOLTIP_MAX_LINES][64];
+    int line_count = 0;
+
+    snprintf(lines[line_count++], sizeof(lines[0]), "%s", item_get_name(slot->type));
+
+    const ItemProperties* props = item_get_properties(slot->type);
+    if (props && props->max_stack_size > 1) {
+        snprintf(lines[line_count++], sizeof(lines[0]), "Stack: %d / %d",
+                 slot->count, props->max_stack_size);
+    }
+
+    const CraftingRecipe* uses[TOOLTIP_MAX_USES];
+    int use_count = crafting_recipes_using(slot->type, uses, TOOLTIP_MAX_USES);
+    if (use_count > 0) {
+        snprintf(lines[line_count++], sizeof(lines[0]), "Used in:");
+        int shown = use_count < TOOLTIP_MAX_USES ? use_count : TOOLTIP_MAX_USES;
+        for (int i = 0; i < shown; i++) {
+            snprintf(lines[line_count++], sizeof(lines[0]), "  %s", item_get_name(uses[i]->output));
+        }
+        if (use_count > shown) {
+            snprintf(lines[line_count++], sizeof(lines[0]), "  +%d more", use_count - shown);
+        }
+    }
+
+    // Raw cost of one craft, expanded while Shift is held
+    const CraftingRecipe* recipe = crafting_find_recipe_for_output(slot->type);
+    if (recipe) {
+        bool expanded = IsKeyDown(KEY_LEFT_SHIFT) || IsKeyDown(KEY_RIGHT_SHIFT);
+        int raw[ITEM_COUNT];
+        if (!expanded) {
+            snprintf(lines[line_count++], sizeof(lines[0]), "[Shift] Raw cost");
+        } else if (crafting_get_raw_cost(slot->type, recipe->output_count, raw)) {
+            snprintf(lines[line_count++], sizeof(lines[0]), "Raw cost (x%d):", recipe->output_count);
+            int shown = 0;
+            for (int type = 0; type < ITEM_COUNT && shown < TOOLTIP_MAX_RAW; type++) {
+                if (raw[type] == 0) continue;
+                snprintf(lines[line_count++], sizeof(lines[0]), "  %d %s", raw[type], item_get_name(type));
+                shown++;
+            }
+        }
+    }
 
     // Measure text size for background
     int font_size = 16;
-    int text_width = MeasureText(item_name, font_size);
+    int small_font_size = 12;
+    int line_gap = 2;
     int padding = 6;
 
+    int text_width = MeasureText(lines[0], font_size);
+    int text_height = font_size;
+    for (int i = 1; i < line_count; i++) {
+        int w = MeasureText(lines[i], small_font_size);
+        if (w > text_width) text_width = w;
+        text_height += small_font_size + line_gap;
+    }
+
     // Position tooltip near cursor (offset to avoid covering item)
     int tooltip_x = mouse_x + 12;
     int tooltip_y = mouse_y + 12;
@@ -676,18 +949,23 @@ void inventory_ui_draw_tooltip(Inventory* inv, int mouse_x, int mouse_y) {
     if (tooltip_x + text_width + padding * 2 > screen_width) {
         tooltip_x = mouse_x - text_width - padding * 2 - 12;
     }
-    if (tooltip_y + font_size + padding * 2 > screen_height) {
-        tooltip_y = mouse_y - font_size - padding * 2 - 12;
+    if (tooltip_y + text_height + padding * 2 > screen_height) {
+        tooltip_y = mouse_y - text_height - padding * 2 - 12;
     }
 
     // Draw tooltip background
-    DrawRectangle(tooltip_x, tooltip_y, text_width + padding * 2, font_size + padding * 2,
+    DrawRectangle(tooltip_x, tooltip_y, text_width + padding * 2, text_height + padding * 2,
                    (Color){40, 40, 40, 240});
-    DrawRectangleLines(tooltip_x, tooltip_y, text_width + padding * 2, font_size + padding * 2,
+    DrawRectangleLines(tooltip_x, tooltip_y, text_width + padding * 2, text_height + padding * 2,
                        (Color){150, 150, 150, 255});
 
-    // Draw item name
-    DrawText(item_name, tooltip_x + padding, tooltip_y + padding, font_size, WHITE);
+    // Draw item name, then details in a smaller font
+    DrawText(lines[0], tooltip_x + padding, tooltip_y + padding, font_size, WHITE);
+    int line_y = tooltip_y + padding + font_size + line_gap;
+    for (int i = 1; i < line_count; i++) {
+        DrawText(lines[i], tooltip_x + padding, line_y, small_font_size, (Color){180, 180, 180, 255});
+        line_y += small_font_size + line_gap;
+    }
 }
 
 // ============================================================================
@@ -695,10 +973,37 @@ void inventory_ui_draw_tooltip(Inventory* inv, int mouse_x, int mouse_y) {
 // ============================================================================
 
 // Chest UI layout constants
-#define CHEST_PANEL_X 150
-#define CHEST_PANEL_Y 80
+#define CHEST_PANEL_WIDTH 500
+#define CHEST_PANEL_BASE_HEIGHT 450  // Grows with unlocked inventory rows
 #define CHEST_SLOT_SIZE 40
 #define CHEST_SLOT_GAP 2
+#define CHEST_BUTTON_HEIGHT 22
+
+// Buttons in the chest title row, right-aligned
+typedef enum {
+    CHEST_BUTTON_DEPOSIT,        // Store stacks whose type the chest already holds
+    CHEST_BUTTON_TAKE_ALL,       // Take everything that fits
+    CHEST_BUTTON_COUNT
+} ChestButton;
+
+static const char* CHEST_BUTTON_LABELS[CHEST_BUTTON_COUNT] = {"Deposit matching", "Take all"};
+static const int CHEST_BUTTON_WIDTHS[CHEST_BUTTON_COUNT] = {130, 80};
+
+static int chest_panel_height(const Inventory* inv) {
+    return CHEST_PANEL_BASE_HEIGHT +
+           (inv->main_rows - MAIN_INVENTORY_BASE_ROWS) * (CHEST_SLOT_SIZE + CHEST_SLOT_GAP);
+}
+
+/**
+ * Screen position of a chest button's top-left corner
+ */
+static void get_chest_button_pos(int panel_x, int panel_y, ChestButton button, int* x, int* y) {
+    *x = panel_x + CHEST_PANEL_WIDTH - 20 - CHEST_BUTTON_WIDTHS[button];
+    for (int i = button + 1; i < CHEST_BUTTON_COUNT; i++) {
+        *x -= CHEST_BUTTON_WIDTHS[i] + 5;
+    }
+    *y = panel_y + 12;
+}
 
 void inventory_ui_draw_chest(ChestData* chest, Inventory* inv, Texture2D atlas) {
     if (!chest || !inv) return;
@@ -710,10 +1015,10 @@ void inventory_ui_draw_chest(ChestData* chest, Inventory* inv, Texture2D atlas)
     DrawRectangle(0, 0, GetScreenWidth(), GetScreenHeight(), (Color){0, 0, 0, 150});
 
     // Calculate panel dimensions
-    int panel_x = CHEST_PANEL_X;
-    int panel_y = CHEST_PANEL_Y;
-    int panel_w = 500;
-    int panel_h = 450;
+    int panel_w = CHEST_PANEL_WIDTH;
+    int panel_h = chest_panel_height(inv);
+    int panel_x = center_in_window(GetScreenWidth(), panel_w);
+    int panel_y = center_in_window(GetScreenHeight(), panel_h);
 
     // Draw panel background
     DrawRectangle(panel_x, panel_y, panel_w, panel_h, (Color){40, 40, 40, 240});
@@ -722,6 +1027,16 @@ void inventory_ui_draw_chest(ChestData* chest, Inventory* inv, Texture2D atlas)
     // Title
     DrawText("Chest", panel_x + 20, panel_y + 10, 24, WHITE);
 
+    for (int i = 0; i < CHEST_BUTTON_COUNT; i++) {
+        int btn_x, btn_y;
+        get_chest_button_pos(panel_x, panel_y, (ChestButton)i, &btn_x, &btn_y);
+        int btn_w = CHEST_BUTTON_WIDTHS[i];
+        DrawRectangle(btn_x, btn_y, btn_w, CHEST_BUTTON_HEIGHT, (Color){60, 60, 60, 255});
+        DrawRectangleLines(btn_x, btn_y, btn_w, CHEST_BUTTON_HEIGHT, (Color){100, 100, 100, 255});
+        int text_w = MeasureText(CHEST_BUTTON_LABELS[i], 14);
+        DrawText(CHEST_BUTTON_LABELS[i], btn_x + (btn_w - text_w) / 2, btn_y + 4, 14, WHITE);
+    }
+
     // Section 1: Chest contents (3 rows x 9 columns = 27 slots)
     int chest_x = panel_x + 20;
     int chest_y = panel_y + 50;
@@ -751,19 +1066,20 @@ void inventory_ui_draw_chest(ChestData* chest, Inventory* inv, Texture2D atlas)
     int div_y = chest_y + 3 * (SLOT_SIZE + SLOT_GAP) + 10;
     DrawLine(panel_x + 20, div_y, panel_x + panel_w - 20, div_y, (Color){100, 100, 100, 255});
 
-    // Section 2: Player main inventory (3 rows x 9 columns)
+    // Section 2: Player main inventory (main_rows x 9 columns)
     int inv_x = panel_x + 20;
     int inv_y = div_y + 20;
 
     DrawText("Inventory", inv_x, inv_y - 15, 14, LIGHTGRAY);
 
-    for (int row = 0; row < 3; row++) {
+    for (int row = 0; row < inv->main_rows; row++) {
         for (int col = 0; col < 9; col++) {
             int x = inv_x + col * (SLOT_SIZE + SLOT_GAP);
             int y = inv_y + row * (SLOT_SIZE + SLOT_GAP);
             int slot_index = row * 9 + col;
 
             draw_slot(x, y, SLOT_SIZE);
+            if (inv->main_locked[slot_index]) draw_lock_marker(x, y, SLOT_SIZE);
 
             // Draw item if present
             ItemStack* slot = &inv->main_inventory[slot_index];
@@ -778,13 +1094,14 @@ void inventory_ui_draw_chest(ChestData* chest, Inventory* inv, Texture2D atlas)
 
     // Section 3: Player hotbar (1 row x 9 columns)
     int hotbar_x = inv_x;
-    int hotbar_y = inv_y + 3 * (SLOT_SIZE + SLOT_GAP) + 10;
+    int hotbar_y = inv_y + inv->main_rows * (SLOT_SIZE + SLOT_GAP) + 10;
 
     for (int i = 0; i < 9; i++) {
         int x = hotbar_x + i * (SLOT_SIZE + SLOT_GAP);
         int y = hotbar_y;
 
         draw_slot(x, y, SLOT_SIZE);
+        if (inv->hotbar_locked[i]) draw_lock_marker(x, y, SLOT_SIZE);
 
         // Draw item if present
         ItemStack* slot = &inv->hotbar[i];
@@ -797,16 +1114,102 @@ void inventory_ui_draw_chest(ChestData* chest, Inventory* inv, Texture2D atlas)
     }
 
     // Instructions
-    DrawText("Click items to transfer. Press E or ESC to close.", panel_x + 20, panel_y + panel_h - 25, 14, GRAY);
+    DrawText("Click items to transfer, right-click for half. Press E or ESC to close.",
+             panel_x + 20, panel_y + panel_h - 25, 14, GRAY);
 }
 
-void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse_x, int mouse_y) {
+/**
+ * Move a player slot (or half of it) into the chest
+ */
+static void store_in_chest(ChestData* chest, ItemStack* slot, bool split) {
+    ItemStack item = *slot;
+    if (split) item.count = (uint8_t)((item.count + 1) / 2);
+
+    if (chest_add_item(chest, item)) {
+        slot->count -= item.count;
+        if (slot->count == 0) {
+            *slot = (ItemStack){ITEM_NONE, 0, 0, 0};
+        }
+        printf("[CHEST] Stored %d %s\n", item.count, item_get_name(item.type));
+    }
+}
+
+/**
+ * Store every unlocked player stack whose item type is already in the chest
+ * Stacks go in whole when they fit, otherwise one item at a time until the
+ * chest is full; the overflow stays with the player.
+ */
+static void deposit_matching(ChestData* chest, Inventory* inv) {
+    bool in_chest[ITEM_COUNT] = {false};
+    for (int i = 0; i < CHEST_SLOTS; i++) {
+        if (chest->slots[i].count > 0) in_chest[chest->slots[i].type] = true;
+    }
+
+    int stored = 0;
+    int slot_count = HOTBAR_SIZE + inventory_get_main_size(inv);
+    for (int i = 0; i < slot_count; i++) {
+        ItemStack* slot = inventory_get_slot(inv, i);
+        if (slot->type == ITEM_NONE || !in_chest[slot->type] || inventory_is_slot_locked(inv, i)) continue;
+
+        if (chest_add_item(chest, *slot)) {
+            stored += slot->count;
+            slot->count = 0;
+        } else {
+            ItemStack one = *slot;
+            one.count = 1;
+            while (slot->count > 0 && chest_add_item(chest, one)) {
+                slot->count--;
+                stored++;
+            }
+        }
+        if (slot->count == 0) {
+            *slot = (ItemStack){ITEM_NONE, 0, 0, 0};
+        }
+    }
+    printf("[CHEST] Deposited %d matching items\n", stored);
+}
+
+/**
+ * Move everything from the chest that fits into the player inventory
+ */
+static void take_all(ChestData* chest, Inventory* inv) {
+    int taken = 0;
+    for (int i = 0; i < CHEST_SLOTS; i++) {
+        ItemStack* slot = &chest->slots[i];
+        if (slot->type == ITEM_NONE || slot->count == 0) continue;
+
+        uint8_t moved = slot->count - inventory_add_item_partial(inv, slot->type, slot->count);
+        slot->count -= moved;
+        taken += moved;
+        if (slot->count == 0) {
+            *slot = (ItemStack){ITEM_NONE, 0, 0, 0};
+        }
+    }
+    printf("[CHEST] Took %d items\n", taken);
+}
+
+void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse_x, int mouse_y, bool split) {
     if (!chest || !inv) return;
 
     const int SLOT_SIZE = CHEST_SLOT_SIZE;
     const int SLOT_GAP = CHEST_SLOT_GAP;
-    int panel_x = CHEST_PANEL_X;
-    int panel_y = CHEST_PANEL_Y;
+    int panel_x = center_in_window(GetScreenWidth(), CHEST_PANEL_WIDTH);
+    int panel_y = center_in_window(GetScreenHeight(), chest_panel_height(inv));
+
+    // Title row buttons
+    for (int i = 0; i < CHEST_BUTTON_COUNT; i++) {
+        int btn_x, btn_y;
+        get_chest_button_pos(panel_x, panel_y, (ChestButton)i, &btn_x, &btn_y);
+        if (mouse_x >= btn_x && mouse_x < btn_x + CHEST_BUTTON_WIDTHS[i] &&
+            mouse_y >= btn_y && mouse_y < btn_y + CHEST_BUTTON_HEIGHT) {
+            if (i == CHEST_BUTTON_DEPOSIT) {
+                deposit_matching(chest, inv);
+            } else {
+                take_all(chest, inv);
+            }
+            return;
+        }
+    }
 
     // Check chest slots (3 rows x 9 columns)
     int chest_x = panel_x + 20;
@@ -822,13 +1225,19 @@ void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse
                 mouse_y >= y && mouse_y < y + SLOT_SIZE) {
                 // Clicked on chest slot - transfer to player inventory
                 if (slot_index < CHEST_SLOTS && chest->slots[slot_index].type != ITEM_NONE) {
-                    ItemStack item = chest->slots[slot_index];
+                    ItemStack* slot = &chest->slots[slot_index];
+                    uint8_t count = split ? (uint8_t)((slot->count + 1) / 2) : slot->count;
+
+                    // Take as many as fit; the rest stays in the chest
+                    count -= inventory_add_item_partial(inv, slot->type, count);
+                    if (count > 0) {
+                        printf("[CHEST] Took %d %s\n", count, item_get_name(slot->type));
 
-                    // Try to add to player inventory
-                    if (inventory_add_item(inv, item.type, item.count)) {
                         // Remove from chest
-                        chest->slots[slot_index] = (ItemStack){ITEM_NONE, 0, 0, 0};
-                        printf("[CHEST] Took %d %s\n", item.count, item_get_name(item.type));
+                        slot->count -= count;
+                        if (slot->count == 0) {
+                            *slot = (ItemStack){ITEM_NONE, 0, 0, 0};
+                        }
                     }
                 }
                 return;
@@ -841,8 +1250,8 @@ void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse
     int inv_x = panel_x + 20;
     int inv_y = div_y + 20;
 
-    // Main inventory (3 rows x 9)
-    for (int row = 0; row < 3; row++) {
+    // Main inventory (main_rows x 9)
+    for (int row = 0; row < inv->main_rows; row++) {
         for (int col = 0; col < 9; col++) {
             int x = inv_x + col * (SLOT_SIZE + SLOT_GAP);
             int y = inv_y + row * (SLOT_SIZE + SLOT_GAP);
@@ -851,15 +1260,8 @@ void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse
             if (mouse_x >= x && mouse_x < x + SLOT_SIZE &&
                 mouse_y >= y && mouse_y < y + SLOT_SIZE) {
                 // Clicked on inventory slot - transfer to chest
-                if (inv->main_inventory[slot_index].type != ITEM_NONE) {
-                    ItemStack item = inv->main_inventory[slot_index];
-
-                    // Try to add to chest
-                    if (chest_add_item(chest, item)) {
-                        // Remove from inventory
-                        inv->main_inventory[slot_index] = (ItemStack){ITEM_NONE, 0, 0, 0};
-                        printf("[CHEST] Stored %d %s\n", item.count, item_get_name(item.type));
-                    }
+                if (!inv->main_locked[slot_index] && inv->main_inventory[slot_index].type != ITEM_NONE) {
+                    store_in_chest(chest, &inv->main_inventory[slot_index], split);
                 }
                 return;
             }
@@ -868,7 +1270,7 @@ void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse
 
     // Hotbar (1 row x 9)
     int hotbar_x = inv_x;
-    int hotbar_y = inv_y + 3 * (SLOT_SIZE + SLOT_GAP) + 10;
+    int hotbar_y = inv_y + inv->main_rows * (SLOT_SIZE + SLOT_GAP) + 10;
 
     for (int i = 0; i < 9; i++) {
         int x = hotbar_x + i * (SLOT_SIZE + SLOT_GAP);
@@ -877,15 +1279,8 @@ void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse
         if (mouse_x >= x && mouse_x < x + SLOT_SIZE &&
             mouse_y >= y && mouse_y < y + SLOT_SIZE) {
             // Clicked on hotbar slot - transfer to chest
-            if (inv->hotbar[i].type != ITEM_NONE) {
-                ItemStack item = inv->hotbar[i];
-
-                // Try to add to chest
-                if (chest_add_item(chest, item)) {
-                    // Remove from hotbar
-                    inv->hotbar[i] = (ItemStack){ITEM_NONE, 0, 0, 0};
-                    printf("[CHEST] Stored %d %s\n", item.count, item_get_name(item.type));
-                }
+            if (!inv->hotbar_locked[i] && inv->hotbar[i].type != ITEM_NONE) {
+                store_in_chest(chest, &inv->hotbar[i], split);
             }
             return;
         }
@@ -897,8 +1292,10 @@ void inventory_ui_handle_chest_click(ChestData* chest, Inventory* inv, int mouse
 // ============================================================================
 
 void inventory_ui_handle_scroll(int scroll_delta) {
-    // No longer used for recipe list - now using pagination
-    (void)scroll_delta;
+    guide_init_if_needed();
+
+    // Wheel up (positive) scrolls towards the first row
+    guide_scroll_by(-scroll_delta);
 }
 
 /**
@@ -908,15 +1305,18 @@ void inventory_ui_handle_scroll(int scroll_delta) {
 bool inventory_ui_handle_guide_click(Inventory* inv, int mouse_x, int mouse_y) {
     if (!inv) return false;
 
+    int guide_x = inventory_ui_get_panel_x() + INVENTORY_PANEL_WIDTH + GUIDE_GAP;
+    int guide_y = inventory_ui_get_panel_y();
+
     // Check if click is in guide area
-    if (mouse_x < GUIDE_X || mouse_x > GUIDE_X + GUIDE_WIDTH ||
-        mouse_y < GUIDE_Y || mouse_y > GUIDE_Y + GUIDE_HEIGHT) {
+    if (mouse_x < guide_x || mouse_x > guide_x + GUIDE_WIDTH ||
+        mouse_y < guide_y || mouse_y > guide_y + GUIDE_HEIGHT) {
         return false;
     }
 
     // Check search box click
-    int search_x = GUIDE_X + 10;
-    int search_y = GUIDE_Y + 28;
+    int search_x = guide_x + 10;
+    int search_y = guide_y + 28;
     if (mouse_x >= search_x && mouse_x < search_x + GUIDE_WIDTH - 20 &&
         mouse_y >= search_y && mouse_y < search_y + 20) {
         guide_search_active = true;
@@ -926,8 +1326,8 @@ bool inventory_ui_handle_guide_click(Inventory* inv, int mouse_x, int mouse_y) {
     }
 
     // Check item browser clicks
-    int browser_x = GUIDE_X + 10;
-    int browser_y = GUIDE_Y + 58;
+    int browser_x = guide_x + 10;
+    int browser_y = guide_y + 58;
     int browser_width = BROWSER_COLS * (BROWSER_ITEM_SIZE + 2);
     int browser_height = BROWSER_ROWS * (BROWSER_ITEM_SIZE + 2);
 
@@ -936,7 +1336,7 @@ bool inventory_ui_handle_guide_click(Inventory* inv, int mouse_x, int mouse_y) {
         // Calculate which item was clicked
         int col = (mouse_x - browser_x) / (BROWSER_ITEM_SIZE + 2);
         int row = (mouse_y - browser_y) / (BROWSER_ITEM_SIZE + 2);
-        int idx = guide_current_page * ITEMS_PER_PAGE + row * BROWSER_COLS + col;
+        int idx = (guide_scroll_row + row) * BROWSER_COLS + col;
 
         if (idx < guide_filtered_count) {
             guide_selected_item = guide_filtered_items[idx];
@@ -946,32 +1346,27 @@ bool inventory_ui_handle_guide_click(Inventory* inv, int mouse_x, int mouse_y) {
     }
 
     // Check pagination clicks
-    int pagination_y = GUIDE_Y + 60 + browser_height + 5;
-    int pagination_x = GUIDE_X + 45;
+    int pagination_y = guide_y + 60 + browser_height + 5;
+    int pagination_x = guide_x + 45;
 
     // Previous button
     if (mouse_x >= pagination_x && mouse_x < pagination_x + 20 &&
         mouse_y >= pagination_y && mouse_y < pagination_y + 20) {
-        if (guide_current_page > 0) {
-            guide_current_page--;
-        }
+        guide_scroll_by(-BROWSER_ROWS);
         return true;
     }
 
     // Next button
     if (mouse_x >= pagination_x + 110 && mouse_x < pagination_x + 130 &&
         mouse_y >= pagination_y && mouse_y < pagination_y + 20) {
-        int total_pages = guide_get_total_pages();
-        if (guide_current_page < total_pages - 1) {
-            guide_current_page++;
-        }
+        guide_scroll_by(BROWSER_ROWS);
         return true;
     }
 
     // Check craft button clicks
     if (guide_selected_item != ITEM_NONE) {
-        int btn_y = GUIDE_Y + GUIDE_HEIGHT - 32;
-        int btn_x = GUIDE_X + 10;
+        int btn_y = guide_y + GUIDE_HEIGHT - 32;
+        int btn_x = guide_x + 10;
         int btn_w = 45;
         int btn_h = 22;
         int gap = 5;
@@ -1324,6 +1719,7 @@ void inventory_ui_draw_held_item_3d(Player* player, Camera3D camera, Texture2D a
                     draw_pickaxe_3d(false);
                     break;
                 case ITEM_STONE_PICKAXE:
+                case ITEM_IRON_PICKAXE:
                     draw_pickaxe_3d(true);
                     break;
                 case ITEM_WOODEN_SHOVEL:
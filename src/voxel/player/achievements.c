@@ -0,0 +1,149 @@
+/**
+ * Achievements Implementation
+ */
+
+#include "voxel/player/achievements.h"
+#include <raylib.h>
+#include <stdio.h>
+#include <string.h>
+
+typedef struct {
+    const char* title;
+    const char* description;
+    int threshold;
+} AchievementInfo;
+
+static const AchievementInfo g_achievement_info[ACHIEVEMENT_COUNT] = {
+    [ACHIEVEMENT_FIRST_BLOCK]   = {"Builder",       "Place your first block",   1},
+    [ACHIEVEMENT_LUMBERJACK]    = {"Lumberjack",    "Mine 100 logs",            100},
+    [ACHIEVEMENT_FIRST_PICKAXE] = {"First Pickaxe", "Craft a wooden pickaxe",   1},
+    [ACHIEVEMENT_STONE_AGE]     = {"Stone Age",     "Craft a stone pickaxe",    1},
+    [ACHIEVEMENT_DIAMONDS]      = {"Diamonds!",     "Mine diamond ore",         1},
+    [ACHIEVEMENT_FIRST_HUNT]    = {"Hunter",        "Kill an animal",           1},
+    [ACHIEVEMENT_GOOD_NIGHT]    = {"Good Night",    "Sleep in a bed",           1},
+};
+
+static int g_progress[ACHIEVEMENT_COUNT];
+static bool g_unlocked[ACHIEVEMENT_COUNT];
+
+// Unlocks waiting to be shown, oldest first
+static AchievementId g_toast_queue[ACHIEVEMENT_TOAST_QUEUE];
+static int g_toast_count = 0;
+static float g_toast_timer = 0.0f;
+
+// ============================================================================
+// PROGRESS
+// ============================================================================
+
+void achievements_init(void) {
+    memset(g_progress, 0, sizeof(g_progress));
+    memset(g_unlocked, 0, sizeof(g_unlocked));
+    g_toast_count = 0;
+    g_toast_timer = 0.0f;
+}
+
+bool achievements_add_progress(AchievementId id, int amount) {
+    if (id < 0 || id >= ACHIEVEMENT_COUNT || amount <= 0) return false;
+    if (g_unlocked[id]) return false;
+
+    g_progress[id] += amount;
+    if (g_progress[id] < g_achievement_info[id].threshold) return false;
+
+    g_unlocked[id] = true;
+    printf("[ACHIEVEMENT] Unlocked: %s\n", g_achievement_info[id].title);
+
+    if (g_toast_count < ACHIEVEMENT_TOAST_QUEUE) {
+        if (g_toast_count == 0) g_toast_timer = ACHIEVEMENT_TOAST_TIME;
+        g_toast_queue[g_toast_count++] = id;
+    }
+    return true;
+}
+
+bool achievements_is_unlocked(AchievementId id) {
+    if (id < 0 || id >= ACHIEVEMENT_COUNT) return false;
+    return g_unlocked[id];
+}
+
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+void achievements_on_block_placed(BlockType type) {
+    (void)type;
+    achievements_add_progress(ACHIEVEMENT_FIRST_BLOCK, 1);
+}
+
+void achievements_on_block_mined(BlockType type) {
+    switch (type) {
+        case BLOCK_WOOD:
+        case BLOCK_BIRCH_WOOD:
+        case BLOCK_SPRUCE_WOOD:
+        case BLOCK_ACACIA_WOOD:
+            achievements_add_progress(ACHIEVEMENT_LUMBERJACK, 1);
+            break;
+        case BLOCK_DIAMOND_ORE:
+            achievements_add_progress(ACHIEVEMENT_DIAMONDS, 1);
+            break;
+        default:
+            break;
+    }
+}
+
+void achievements_on_item_crafted(ItemType type, int count) {
+    switch (type) {
+        case ITEM_WOODEN_PICKAXE:
+            achievements_add_progress(ACHIEVEMENT_FIRST_PICKAXE, count);
+            break;
+        case ITEM_STONE_PICKAXE:
+            achievements_add_progress(ACHIEVEMENT_STONE_AGE, count);
+            break;
+        default:
+            break;
+    }
+}
+
+// ============================================================================
+// TOASTS
+// ============================================================================
+
+void achievements_update(float dt) {
+    if (g_toast_count == 0) return;
+
+    g_toast_timer -= dt;
+    if (g_toast_timer > 0.0f) return;
+
+    // Show the next queued unlock
+    for (int i = 1; i < g_toast_count; i++) {
+        g_toast_queue[i - 1] = g_toast_queue[i];
+    }
+    g_toast_count--;
+    g_toast_timer = ACHIEVEMENT_TOAST_TIME;
+}
+
+void achievements_draw_toast(void) {
+    if (g_toast_count == 0) return;
+
+    const AchievementInfo* info = &g_achievement_info[g_toast_queue[0]];
+
+    int title_size = 18;
+    int desc_size = 14;
+    int padding = 8;
+    int width = MeasureText(info->description, desc_size);
+    int title_width = MeasureText(info->title, title_size) + MeasureText("Achievement: ", title_size);
+    if (title_width > width) width = title_width;
+    width += padding * 2;
+    int height = title_size + desc_size + padding * 3;
+
+    // Slide in from the left during the first quarter second
+    float elapsed = ACHIEVEMENT_TOAST_TIME - g_toast_timer;
+    float slide = elapsed < 0.25f ? elapsed / 0.25f : 1.0f;
+    int x = (int)(-width + (width + 10) * slide);
+    int y = 10;
+
+    DrawRectangle(x, y, width, height, (Color){30, 30, 40, 230});
+    DrawRectangleLines(x, y, width, height, (Color){255, 200, 60, 255});
+    DrawText(TextFormat("Achievement: %s", info->title), x + padding, y + padding, title_size,
+             (Color){255, 200, 60, 255});
+    DrawText(info->description, x + padding, y + padding * 2 + title_size, desc_size,
+             (Color){200, 200, 200, 255});
+}
@@ -0,0 +1,56 @@
+/**
+ * Gamepad Input Implementation
+ */
+
+#include "voxel/player/gamepad.h"
+#include <raylib.h>
+#include <math.h>
+
+/**
+ * Read a stick axis with the deadzone removed and the rest rescaled to 0-1
+ */
+static float read_axis(int axis) {
+    float value = GetGamepadAxisMovement(GAMEPAD_ID, axis);
+    if (fabsf(value) < GAMEPAD_STICK_DEADZONE) return 0.0f;
+
+    float magnitude = (fabsf(value) - GAMEPAD_STICK_DEADZONE) / (1.0f - GAMEPAD_STICK_DEADZONE);
+    return value > 0.0f ? magnitude : -magnitude;
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+bool gamepad_is_available(void) {
+    return IsGamepadAvailable(GAMEPAD_ID);
+}
+
+bool gamepad_is_button_pressed(int button) {
+    return gamepad_is_available() && IsGamepadButtonPressed(GAMEPAD_ID, button);
+}
+
+bool gamepad_is_button_down(int button) {
+    return gamepad_is_available() && IsGamepadButtonDown(GAMEPAD_ID, button);
+}
+
+void gamepad_read_player_input(PlayerInput* input, float dt) {
+    if (!input || !gamepad_is_available()) return;
+
+    // Left stick moves (stick up is negative Y, PlayerInput strafe +1 is left)
+    input->forward -= read_axis(GAMEPAD_AXIS_LEFT_Y);
+    input->strafe -= read_axis(GAMEPAD_AXIS_LEFT_X);
+    if (input->forward > 1.0f) input->forward = 1.0f;
+    if (input->forward < -1.0f) input->forward = -1.0f;
+    if (input->strafe > 1.0f) input->strafe = 1.0f;
+    if (input->strafe < -1.0f) input->strafe = -1.0f;
+
+    // Right stick looks, converted to the mouse delta it stands in for
+    input->look_delta.x += read_axis(GAMEPAD_AXIS_RIGHT_X) * GAMEPAD_LOOK_SPEED * dt;
+    input->look_delta.y += read_axis(GAMEPAD_AXIS_RIGHT_Y) * GAMEPAD_LOOK_SPEED * dt;
+
+    input->jump |= IsGamepadButtonDown(GAMEPAD_ID, GAMEPAD_BUTTON_RIGHT_FACE_DOWN);
+    input->descend |= IsGamepadButtonDown(GAMEPAD_ID, GAMEPAD_BUTTON_RIGHT_FACE_RIGHT);
+    input->sprint |= IsGamepadButtonDown(GAMEPAD_ID, GAMEPAD_BUTTON_LEFT_THUMB);
+    input->toggle_flying |= IsGamepadButtonPressed(GAMEPAD_ID, GAMEPAD_BUTTON_RIGHT_FACE_LEFT);
+    input->toggle_view |= IsGamepadButtonPressed(GAMEPAD_ID, GAMEPAD_BUTTON_RIGHT_THUMB);
+}
@@ -0,0 +1,147 @@
+/**
+ * Objectives Implementation
+ */
+
+#include "voxel/player/objectives.h"
+#include <raylib.h>
+#include <stdio.h>
+#include <string.h>
+
+typedef bool (*ObjectivePredicate)(const int totals[ITEM_COUNT]);
+
+typedef struct {
+    const char* text;
+    ObjectivePredicate done;
+} Objective;
+
+// ============================================================================
+// PREDICATES
+// ============================================================================
+
+static bool has_log(const int totals[ITEM_COUNT]) {
+    return totals[ITEM_WOOD_LOG] > 0;
+}
+
+static bool has_planks(const int totals[ITEM_COUNT]) {
+    return totals[ITEM_WOOD_PLANKS] > 0;
+}
+
+static bool has_sticks(const int totals[ITEM_COUNT]) {
+    return totals[ITEM_STICK] > 0;
+}
+
+static bool has_pickaxe(const int totals[ITEM_COUNT]) {
+    // A stone pickaxe means the player has moved past this step
+    return totals[ITEM_WOODEN_PICKAXE] > 0 || totals[ITEM_STONE_PICKAXE] > 0;
+}
+
+static bool has_cobblestone(const int totals[ITEM_COUNT]) {
+    return totals[ITEM_COBBLESTONE] > 0;
+}
+
+static bool has_stone_pickaxe(const int totals[ITEM_COUNT]) {
+    return totals[ITEM_STONE_PICKAXE] > 0;
+}
+
+static bool has_bed(const int totals[ITEM_COUNT]) {
+    for (int type = 1; type < ITEM_COUNT; type++) {
+        if (totals[type] > 0 && item_get_properties(type)->places_as == BLOCK_BED_FOOT) {
+            return true;
+        }
+    }
+    return false;
+}
+
+static const Objective g_objectives[] = {
+    {"Chop a tree for a wood log",   has_log},
+    {"Craft wood planks",            has_planks},
+    {"Craft sticks",                 has_sticks},
+    {"Craft a wooden pickaxe",       has_pickaxe},
+    {"Mine some cobblestone",        has_cobblestone},
+    {"Craft a stone pickaxe",        has_stone_pickaxe},
+    {"Craft a bed from wool",        has_bed},
+};
+#define OBJECTIVE_COUNT (int)(sizeof(g_objectives) / sizeof(g_objectives[0]))
+
+static bool g_completed[OBJECTIVE_COUNT];
+static float g_free_play_timer = 0.0f;
+
+// ============================================================================
+// API
+// ============================================================================
+
+/**
+ * Index of the first incomplete objective, or OBJECTIVE_COUNT if done
+ */
+static int current_objective(void) {
+    for (int i = 0; i < OBJECTIVE_COUNT; i++) {
+        if (!g_completed[i]) return i;
+    }
+    return OBJECTIVE_COUNT;
+}
+
+void objectives_init(void) {
+    memset(g_completed, 0, sizeof(g_completed));
+    g_free_play_timer = 0.0f;
+}
+
+void objectives_update(Inventory* inv, float dt) {
+    if (g_free_play_timer > 0.0f) g_free_play_timer -= dt;
+    if (!inv || objectives_all_complete()) return;
+
+    int totals[ITEM_COUNT];
+    inventory_get_totals(inv, totals, true);
+
+    // Check every goal, not just the current one, so out-of-order progress latches
+    for (int i = 0; i < OBJECTIVE_COUNT; i++) {
+        if (!g_completed[i] && g_objectives[i].done(totals)) {
+            g_completed[i] = true;
+            printf("[OBJECTIVES] Completed: %s\n", g_objectives[i].text);
+        }
+    }
+
+    if (objectives_all_complete()) {
+        g_free_play_timer = OBJECTIVES_FREE_PLAY_TIME;
+        printf("[OBJECTIVES] All objectives complete - free play\n");
+    }
+}
+
+bool objectives_all_complete(void) {
+    return current_objective() == OBJECTIVE_COUNT;
+}
+
+void objectives_draw(void) {
+    int current = current_objective();
+    if (current == OBJECTIVE_COUNT && g_free_play_timer <= 0.0f) return;
+
+    char header[32];
+    const char* text;
+    if (current < OBJECTIVE_COUNT) {
+        int done = 0;
+        for (int i = 0; i < OBJECTIVE_COUNT; i++) {
+            if (g_completed[i]) done++;
+        }
+        snprintf(header, sizeof(header), "Objective %d/%d", done + 1, OBJECTIVE_COUNT);
+        text = g_objectives[current].text;
+    } else {
+        snprintf(header, sizeof(header), "All objectives complete");
+        text = "Free play unlocked";
+    }
+
+    int header_size = 14;
+    int text_size = 16;
+    int padding = 6;
+    int width = MeasureText(text, text_size);
+    int header_width = MeasureText(header, header_size);
+    if (header_width > width) width = header_width;
+    width += padding * 2;
+    int height = header_size + text_size + padding * 3;
+
+    // Left side, below the achievement toast area
+    int x = 10;
+    int y = 80;
+
+    DrawRectangle(x, y, width, height, (Color){0, 0, 0, 150});
+    DrawText(header, x + padding, y + padding, header_size, (Color){180, 200, 255, 255});
+    DrawText(text, x + padding, y + padding * 2 + header_size, text_size, WHITE);
+}
@@ -3,6 +3,7 @@
  */
 
 #include "voxel/player/player.h"
+#include "voxel/player/gamepad.h"
 #include "voxel/world/world.h"
 #include "voxel/core/block.h"
 #include "voxel/inventory/inventory.h"
@@ -11,6 +12,7 @@
 #include <rlgl.h>
 #include <stdlib.h>
 #include <stdio.h>
+#include <string.h>
 #include <math.h>
 
 // ============================================================================
@@ -21,11 +23,36 @@
 #define JUMP_VELOCITY 10.0f
 #define MAX_PITCH 89.0f  // Prevent camera flip
 
+// Walking acceleration (per second rates; higher = snappier)
+#define WALK_ACCELERATION 12.0f  // Ramp toward target speed while keys are held
+#define WALK_FRICTION 16.0f      // Slow down when keys are released
+
 // Player collision box (AABB)
 #define PLAYER_WIDTH 0.8f    // Player width (X and Z)
 #define PLAYER_HEIGHT 1.8f   // Player height (Y)
 #define PLAYER_EYE_HEIGHT 1.6f  // Camera offset from feet
 
+// Camera shake on hard landings
+#define HARD_LANDING_SPEED 18.0f     // Falls faster than this shake the view (about 5 blocks)
+#define HARD_LANDING_SHAKE 0.02f     // Shake per unit of speed over the threshold
+#define MAX_LANDING_SHAKE 0.25f
+#define LANDING_SHAKE_TIME 0.3f
+
+static bool g_camera_shake_enabled = true;
+
+// ============================================================================
+// MOVEMENT HELPERS
+// ============================================================================
+
+/**
+ * Move a velocity component toward its target with a frame-rate independent
+ * exponential blend. Never overshoots, so the target is the speed cap.
+ */
+static float approach_velocity(float current, float target, float rate, float dt) {
+    float t = 1.0f - expf(-rate * dt);
+    return current + (target - current) * t;
+}
+
 // ============================================================================
 // PLAYER LIFECYCLE
 // ============================================================================
@@ -69,6 +96,22 @@ Player* player_create(Vector3 start_position) {
     // Create inventory
     player->inventory = inventory_create();
 
+    // No home until the first block is placed
+    player->home = (Vector3){0.0f, 0.0f, 0.0f};
+    player->has_home = false;
+
+    // Experience
+    player->level = 1;
+    player->xp = 0;
+    player->xp_to_next = PLAYER_XP_BASE;
+
+    // No food effects yet
+    memset(player->effect_times, 0, sizeof(player->effect_times));
+
+    player->shake_intensity = 0.0f;
+    player->shake_duration = 0.0f;
+    player->shake_time = 0.0f;
+
     // Animation state (start at rest)
     player->walk_animation_time = 0.0f;
     player->arm_swing_angle = 0.0f;
@@ -103,7 +146,7 @@ void player_destroy(Player* player) {
 // ============================================================================
 
 /**
- * Check if a point is inside a solid block
+ * Check if a point is inside a solid block (or closed door)
  */
 static bool is_solid_at(World * world, float x, float y, float z) {
     if (!world) return false;
@@ -113,7 +156,7 @@ static bool is_solid_at(World * world, float x, float y, float z) {
     int block_z = (int)floorf(z);
 
     Block block = world_get_block(world, block_x, block_y, block_z);
-    return block_is_solid(block);
+    return block_blocks_movement(block);
 }
 
 /**
@@ -165,20 +208,12 @@ static bool check_collision(World * world, Vector3 position) {
 // ============================================================================
 
 /**
- * Update camera rotation from mouse input
+ * Update camera rotation from look input
  */
-static void update_camera_rotation(Player* player, float dt) {
-    (void)dt; // Unused
-
-    // Skip mouse input if window is not focused
-    if (!IsWindowFocused()) return;
-
-    // Get mouse delta
-    Vector2 mouse_delta = GetMouseDelta();
-
+static void update_camera_rotation(Player* player, Vector2 look_delta) {
     // Update yaw and pitch
-    player->yaw += mouse_delta.x * player->mouse_sensitivity;
-    player->pitch -= mouse_delta.y * player->mouse_sensitivity;
+    player->yaw += look_delta.x * player->mouse_sensitivity;
+    player->pitch -= look_delta.y * player->mouse_sensitivity;
 
     // Clamp pitch to prevent camera flip
     if (player->pitch > MAX_PITCH) player->pitch = MAX_PITCH;
@@ -206,9 +241,9 @@ static void get_movement_vectors(Player* player, Vector3* forward, Vector3* righ
 }
 
 /**
- * Handle movement input with collision detection
+ * Handle movement intent with collision detection
  */
-static void update_movement(Player* player, World * world, float dt) {
+static void update_movement(Player* player, World * world, const PlayerInput* input, float dt) {
     Vector3 forward, right;
     get_movement_vectors(player, &forward, &right);
 
@@ -218,16 +253,8 @@ static void update_movement(Player* player, World * world, float dt) {
         right = Vector3Negate(right);
     }
 
-    Vector3 move_direction = {0.0f, 0.0f, 0.0f};
-
-    // Skip keyboard input if window is not focused
-    bool window_focused = IsWindowFocused();
-
-    // WASD movement (only when window focused)
-    if (window_focused && IsKeyDown(KEY_W)) move_direction = Vector3Add(move_direction, forward);
-    if (window_focused && IsKeyDown(KEY_S)) move_direction = Vector3Subtract(move_direction, forward);
-    if (window_focused && IsKeyDown(KEY_A)) move_direction = Vector3Add(move_direction, right);
-    if (window_focused && IsKeyDown(KEY_D)) move_direction = Vector3Subtract(move_direction, right);
+    Vector3 move_direction = Vector3Add(Vector3Scale(forward, input->forward),
+                                        Vector3Scale(right, input->strafe));
 
     // Normalize diagonal movement
     if (Vector3Length(move_direction) > 0.0f) {
@@ -237,28 +264,34 @@ static void update_movement(Player* player, World * world, float dt) {
     // Calculate speed
     float speed = player->is_flying ? player->fly_speed : player->move_speed;
 
-    // Sprint modifier (only when window focused)
-    if (window_focused && IsKeyDown(KEY_LEFT_SHIFT)) {
+    // Sprint modifier
+    if (input->sprint) {
         speed *= player->sprint_multiplier;
     }
 
+    if (player_has_effect(player, EFFECT_SPEED)) {
+        speed *= 1.0f + PLAYER_SPEED_EFFECT_BONUS;
+    }
+
     // Apply movement
     if (player->is_flying) {
         // Flying mode: direct movement in all directions
         Vector3 velocity = Vector3Scale(move_direction, speed);
 
-        // Vertical movement (only when window focused)
-        if (window_focused && IsKeyDown(KEY_SPACE)) velocity.y = speed;
-        if (window_focused && IsKeyDown(KEY_LEFT_CONTROL)) velocity.y = -speed;
+        // Vertical movement
+        if (input->jump) velocity.y = speed;
+        if (input->descend) velocity.y = -speed;
 
         player->velocity = velocity;
     } else {
-        // Walking mode: apply gravity
-        player->velocity.x = move_direction.x * speed;
-        player->velocity.z = move_direction.z * speed;
-
-        // Jump (only when window focused)
-        if (window_focused && IsKeyDown(KEY_SPACE) && player->is_grounded) {
+        // Walking mode: ramp horizontal speed up/down, apply gravity
+        bool has_input = Vector3Length(move_direction) > 0.0f;
+        float rate = has_input ? WALK_ACCELERATION : WALK_FRICTION;
+        player->velocity.x = approach_velocity(player->velocity.x, move_direction.x * speed, rate, dt);
+        player->velocity.z = approach_velocity(player->velocity.z, move_direction.z * speed, rate, dt);
+
+        // Jump
+        if (input->jump && player->is_grounded) {
             player->velocity.y = JUMP_VELOCITY;
             player->is_grounded = false;
         }
@@ -284,6 +317,12 @@ static void update_movement(Player* player, World * world, float dt) {
         // Check if we're hitting ground (moving downward)
         if (player->velocity.y < 0.0f) {
             player->is_grounded = true;
+
+            float impact = -player->velocity.y - HARD_LANDING_SPEED;
+            if (impact > 0.0f) {
+                player_shake_camera(player, fminf(impact * HARD_LANDING_SHAKE, MAX_LANDING_SHAKE),
+                                    LANDING_SHAKE_TIME);
+            }
         }
 
         new_position.y = player->position.y;  // Cancel Y movement
@@ -371,21 +410,59 @@ void player_update(Player* player, World * world, float dt) {
         return;
     }
 
-    // Handle flying mode toggle
-    if (IsKeyPressed(KEY_F)) {
+    PlayerInput input = player_read_input();
+    player_apply_input(player, world, &input, dt);
+}
+
+/**
+ * Read this frame's intent from the keyboard and mouse
+ */
+PlayerInput player_read_input(void) {
+    PlayerInput input = {0};
+    if (!IsWindowFocused()) return input;
+
+    input.look_delta = GetMouseDelta();
+
+    // WASD movement
+    if (IsKeyDown(KEY_W)) input.forward += 1.0f;
+    if (IsKeyDown(KEY_S)) input.forward -= 1.0f;
+    if (IsKeyDown(KEY_A)) input.strafe += 1.0f;
+    if (IsKeyDown(KEY_D)) input.strafe -= 1.0f;
+
+    input.jump = IsKeyDown(KEY_SPACE);
+    input.descend = IsKeyDown(KEY_LEFT_CONTROL);
+    input.sprint = IsKeyDown(KEY_LEFT_SHIFT);
+
+    // F toggles flying, V cycles view modes
+    input.toggle_flying = IsKeyPressed(KEY_F);
+    input.toggle_view = IsKeyPressed(KEY_V);
+
+    // Controller input adds to the keyboard, so either can be used
+    gamepad_read_player_input(&input, GetFrameTime());
+
+    return input;
+}
+
+/**
+ * Advance the player by one step from the given intent
+ */
+void player_apply_input(Player* player, World* world, const PlayerInput* input, float dt) {
+    if (!player || !input) return;
+
+    if (input->toggle_flying) {
         player_toggle_flying(player);
     }
-
-    // Handle view mode toggle with V key
-    if (IsKeyPressed(KEY_V)) {
+    if (input->toggle_view) {
         player_toggle_view_mode(player);
     }
 
-    // Update camera rotation from mouse
-    update_camera_rotation(player, dt);
+    player_update_effects(player, dt);
 
-    // Update movement from keyboard (with collision detection)
-    update_movement(player, world, dt);
+    // Update camera rotation from look input
+    update_camera_rotation(player, input->look_delta);
+
+    // Update movement (with collision detection)
+    update_movement(player, world, input, dt);
 
     // Update camera
     update_camera(player);
@@ -766,3 +843,125 @@ float player_get_swing_angle(Player* player) {
 
     return swing_angle;
 }
+
+// ============================================================================
+// CAMERA SHAKE
+// ============================================================================
+
+void player_shake_camera(Player* player, float intensity, float duration) {
+    if (!player || !g_camera_shake_enabled || intensity <= 0.0f || duration <= 0.0f) return;
+    if (intensity < player_get_shake_strength(player)) return;
+
+    player->shake_intensity = intensity;
+    player->shake_duration = duration;
+    player->shake_time = duration;
+}
+
+void player_update_shake(Player* player, float dt) {
+    if (!player || player->shake_time <= 0.0f) return;
+
+    player->shake_time -= dt;
+    if (player->shake_time < 0.0f) player->shake_time = 0.0f;
+}
+
+float player_get_shake_strength(const Player* player) {
+    if (!player || player->shake_time <= 0.0f) return 0.0f;
+
+    // Quadratic fade: strong at first, settling gently to zero
+    float t = player->shake_time / player->shake_duration;
+    return player->shake_intensity * t * t;
+}
+
+Camera3D player_get_view_camera(Player* player) {
+    Camera3D camera = player_get_camera(player);
+    float strength = player_get_shake_strength(player);
+    if (strength <= 0.0f) return camera;
+
+    // Mixed sine frequencies look random but stay smooth from frame to frame
+    float elapsed = player->shake_duration - player->shake_time;
+    Vector3 offset = {
+        sinf(elapsed * 47.0f) * strength,
+        sinf(elapsed * 59.0f + 1.3f) * strength,
+        sinf(elapsed * 41.0f + 2.7f) * strength
+    };
+    camera.position = Vector3Add(camera.position, offset);
+    camera.target = Vector3Add(camera.target, offset);
+    return camera;
+}
+
+void player_set_camera_shake_enabled(bool enabled) {
+    g_camera_shake_enabled = enabled;
+}
+
+// ============================================================================
+// EXPERIENCE
+// ============================================================================
+
+/**
+ * XP needed to advance from the given level: grows geometrically
+ */
+static int xp_for_level(int level) {
+    return (int)roundf(PLAYER_XP_BASE * powf(PLAYER_XP_GROWTH, (float)(level - 1)));
+}
+
+bool player_add_xp(Player* player, int amount) {
+    if (!player || amount <= 0) return false;
+
+    bool leveled = false;
+    player->xp += amount;
+    while (player->xp >= player->xp_to_next) {
+        player->xp -= player->xp_to_next;
+        player->level++;
+        player->xp_to_next = xp_for_level(player->level);
+        leveled = true;
+        printf("[PLAYER] Reached level %d\n", player->level);
+    }
+    return leveled;
+}
+
+float player_get_speed_multiplier(const Player* player) {
+    if (!player) return 1.0f;
+
+    float bonus = (player->level - 1) * PLAYER_SPEED_BONUS_PER_LEVEL;
+    if (bonus > PLAYER_SPEED_BONUS_MAX) bonus = PLAYER_SPEED_BONUS_MAX;
+    return 1.0f + bonus;
+}
+
+// ============================================================================
+// EFFECTS
+// ============================================================================
+
+bool player_consume(Player* player, ItemType food) {
+    if (!player || !item_is_food(food)) return false;
+
+    const ItemProperties* props = item_get_properties(food);
+    float* time = &player->effect_times[props->food_effect];
+
+    // Refresh to the full duration, never past it
+    if (*time < props->food_duration) {
+        *time = props->food_duration;
+    }
+
+    printf("[PLAYER] Ate %s: %s for %.0fs\n", props->name,
+           item_get_effect_name(props->food_effect), props->food_duration);
+    return true;
+}
+
+void player_update_effects(Player* player, float dt) {
+    if (!player) return;
+
+    for (int i = 0; i < EFFECT_COUNT; i++) {
+        if (player->effect_times[i] <= 0.0f) continue;
+
+        player->effect_times[i] -= dt;
+        if (player->effect_times[i] <= 0.0f) {
+            player->effect_times[i] = 0.0f;
+            printf("[PLAYER] %s wore off\n", item_get_effect_name((EffectType)i));
+        }
+    }
+}
+
+bool player_has_effect(const Player* player, EffectType effect) {
+    if (!player || effect <= EFFECT_NONE || effect >= EFFECT_COUNT) return false;
+    return player->effect_times[effect] > 0.0f;
+}
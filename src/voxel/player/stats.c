@@ -0,0 +1,105 @@
+/**
+ * Game Statistics Implementation
+ */
+
+#include "voxel/player/stats.h"
+#include <raylib.h>
+#include <stdio.h>
+#include <string.h>
+
+static const char* g_stat_names[STAT_COUNT] = {
+    [STAT_BLOCKS_PLACED] = "Blocks placed",
+    [STAT_BLOCKS_MINED]  = "Blocks mined",
+    [STAT_LOGS_CHOPPED]  = "Logs chopped",
+    [STAT_ITEMS_CRAFTED] = "Items crafted",
+};
+
+static int g_counters[STAT_COUNT];
+static float g_playtime = 0.0f;
+static float g_distance = 0.0f;
+
+// ============================================================================
+// COUNTERS
+// ============================================================================
+
+void stats_init(void) {
+    memset(g_counters, 0, sizeof(g_counters));
+    g_playtime = 0.0f;
+    g_distance = 0.0f;
+}
+
+void stats_add(StatId id, int amount) {
+    if (id < 0 || id >= STAT_COUNT || amount <= 0) return;
+    g_counters[id] += amount;
+}
+
+int stats_get(StatId id) {
+    if (id < 0 || id >= STAT_COUNT) return 0;
+    return g_counters[id];
+}
+
+void stats_on_block_mined(BlockType type) {
+    stats_add(STAT_BLOCKS_MINED, 1);
+    if (type == BLOCK_WOOD) {
+        stats_add(STAT_LOGS_CHOPPED, 1);
+    }
+}
+
+void stats_add_playtime(float dt) {
+    if (dt > 0.0f) g_playtime += dt;
+}
+
+void stats_add_distance(float distance) {
+    if (distance <= 0.0f || distance > STATS_MAX_STEP) return;
+    g_distance += distance;
+}
+
+float stats_get_playtime(void) {
+    return g_playtime;
+}
+
+float stats_get_distance(void) {
+    return g_distance;
+}
+
+// ============================================================================
+// DRAWING
+// ============================================================================
+
+void stats_draw(int x, int y) {
+    // Narrow enough to fit beside the pause menu at the minimum window size
+    int font_size = 14;
+    int line_height = font_size + 6;
+    int padding = 10;
+    int width = 160;
+    int height = padding * 2 + line_height * (STAT_COUNT + 3);
+
+    DrawRectangle(x, y, width, height, (Color){20, 20, 30, 230});
+    DrawRectangleLines(x, y, width, height, (Color){100, 100, 120, 255});
+
+    int text_x = x + padding;
+    int value_x = x + width - padding;
+    int line_y = y + padding;
+
+    DrawText("STATISTICS", text_x, line_y, font_size + 2, WHITE);
+    line_y += line_height;
+
+    char value[32];
+    int total = (int)g_playtime;
+    snprintf(value, sizeof(value), "%d:%02d:%02d", total / 3600, (total / 60) % 60, total % 60);
+    DrawText("Playtime", text_x, line_y, font_size, LIGHTGRAY);
+    DrawText(value, value_x - MeasureText(value, font_size), line_y, font_size, WHITE);
+    line_y += line_height;
+
+    snprintf(value, sizeof(value), "%.0f m", g_distance);
+    DrawText("Distance", text_x, line_y, font_size, LIGHTGRAY);
+    DrawText(value, value_x - MeasureText(value, font_size), line_y, font_size, WHITE);
+    line_y += line_height;
+
+    for (int i = 0; i < STAT_COUNT; i++) {
+        snprintf(value, sizeof(value), "%d", g_counters[i]);
+        DrawText(g_stat_names[i], text_x, line_y, font_size, LIGHTGRAY);
+        DrawText(value, value_x - MeasureText(value, font_size), line_y, font_size, WHITE);
+        line_y += line_height;
+    }
+}
@@ -43,26 +43,41 @@ static int compare_sort_entries(const void* a, const void* b) {
 // BATCH MESH BUILDING
 // ============================================================================
 
+/**
+ * Pick the chunk mesh a batch mesh is built from
+ * LOD requests fall back to the full mesh when the chunk has no LOD mesh
+ * @return Mesh with CPU-side data, or NULL if the chunk has nothing to draw
+ */
+static Mesh* get_source_mesh(Chunk* chunk, bool transparent, bool lod) {
+    if (lod && chunk->lod_generated) {
+        Mesh* lod_mesh = transparent ? &chunk->transparent_mesh_lod : &chunk->mesh_lod;
+        if (lod_mesh->vertexCount > 0) return lod_mesh;
+    }
+
+    if (transparent) {
+        if (chunk->transparent_mesh_generated && chunk->transparent_mesh.vertexCount > 0) {
+            return &chunk->transparent_mesh;
+        }
+    } else {
+        if (chunk->mesh_generated && chunk->mesh.vertexCount > 0) {
+            return &chunk->mesh;
+        }
+    }
+    return NULL;
+}
+
 /**
  * Count total vertices needed for a batch's combined mesh
  */
-static int count_batch_vertices(ChunkBatch* batch, bool transparent) {
+static int count_batch_vertices(ChunkBatch* batch, bool transparent, bool lod) {
     int total = 0;
     for (int bz = 0; bz < BATCH_SIZE; bz++) {
         for (int bx = 0; bx < BATCH_SIZE; bx++) {
             Chunk* chunk = batch->chunks[bx][bz];
             if (!chunk) continue;
 
-            // Select mesh based on LOD distance (simplified: always use full detail for batches)
-            if (transparent) {
-                if (chunk->transparent_mesh_generated && chunk->transparent_mesh.vertexCount > 0) {
-                    total += chunk->transparent_mesh.vertexCount;
-                }
-            } else {
-                if (chunk->mesh_generated && chunk->mesh.vertexCount > 0) {
-                    total += chunk->mesh.vertexCount;
-                }
-            }
+            Mesh* src_mesh = get_source_mesh(chunk, transparent, lod);
+            if (src_mesh) total += src_mesh->vertexCount;
         }
     }
     return total;
@@ -70,16 +85,26 @@ static int count_batch_vertices(ChunkBatch* batch, bool transparent) {
 
 /**
  * Build combined mesh from all chunks in batch
+ * lod builds the opaque LOD mesh (transparent batches are always full detail)
  */
-static void build_batch_mesh(ChunkBatch* batch, bool transparent) {
-    int total_vertices = count_batch_vertices(batch, transparent);
+static void build_batch_mesh(ChunkBatch* batch, bool transparent, bool lod) {
+    Mesh* target_mesh;
+    bool* valid;
+    if (transparent) {
+        target_mesh = &batch->transparent_mesh;
+        valid = &batch->transparent_valid;
+    } else if (lod) {
+        target_mesh = &batch->opaque_lod_mesh;
+        valid = &batch->opaque_lod_valid;
+    } else {
+        target_mesh = &batch->opaque_mesh;
+        valid = &batch->opaque_valid;
+    }
+
+    int total_vertices = count_batch_vertices(batch, transparent, lod);
 
     if (total_vertices == 0) {
-        if (transparent) {
-            batch->transparent_valid = false;
-        } else {
-            batch->opaque_valid = false;
-        }
+        *valid = false;
         return;
     }
 
@@ -105,18 +130,8 @@ static void build_batch_mesh(ChunkBatch* batch, bool transparent) {
             Chunk* chunk = batch->chunks[bx][bz];
             if (!chunk) continue;
 
-            Mesh* src_mesh;
-            bool has_mesh;
-
-            if (transparent) {
-                src_mesh = &chunk->transparent_mesh;
-                has_mesh = chunk->transparent_mesh_generated && src_mesh->vertexCount > 0;
-            } else {
-                src_mesh = &chunk->mesh;
-                has_mesh = chunk->mesh_generated && src_mesh->vertexCount > 0;
-            }
-
-            if (!has_mesh) continue;
+            Mesh* src_mesh = get_source_mesh(chunk, transparent, lod);
+            if (!src_mesh) continue;
 
             int vc = src_mesh->vertexCount;
 
@@ -144,11 +159,8 @@ static void build_batch_mesh(ChunkBatch* batch, bool transparent) {
         }
     }
 
-    // Create combined mesh
-    Mesh* target_mesh = transparent ? &batch->transparent_mesh : &batch->opaque_mesh;
-
     // Unload old mesh if exists
-    if ((transparent ? batch->transparent_valid : batch->opaque_valid) && target_mesh->vboId != NULL) {
+    if (*valid && target_mesh->vboId != NULL) {
         UnloadMesh(*target_mesh);
     }
 
@@ -161,12 +173,15 @@ static void build_batch_mesh(ChunkBatch* batch, bool transparent) {
     target_mesh->colors = colors;
 
     UploadMesh(target_mesh, false);
+    *valid = true;
+}
 
-    if (transparent) {
-        batch->transparent_valid = true;
-    } else {
-        batch->opaque_valid = true;
-    }
+/**
+ * Check if a batch is far enough from the center chunk to draw its LOD mesh
+ * @param batch_offset Batch distance from the center batch (Chebyshev)
+ */
+static bool batch_uses_lod(int batch_offset, int lod_distance) {
+    return batch_offset * BATCH_SIZE > lod_distance;
 }
 
 // ============================================================================
@@ -208,6 +223,9 @@ void chunk_batcher_destroy(ChunkBatcher* batcher) {
             if (node->batch.transparent_valid && node->batch.transparent_mesh.vboId != NULL) {
                 UnloadMesh(node->batch.transparent_mesh);
             }
+            if (node->batch.opaque_lod_valid && node->batch.opaque_lod_mesh.vboId != NULL) {
+                UnloadMesh(node->batch.opaque_lod_mesh);
+            }
 
             free(node);
             node = next;
@@ -333,9 +351,10 @@ void chunk_batcher_update(ChunkBatcher* batcher, int max_rebuilds) {
         BatchNode* node = batcher->buckets[i];
         while (node && rebuilt < max_rebuilds) {
             if (node->batch.dirty) {
-                // Rebuild both meshes
-                build_batch_mesh(&node->batch, false);  // Opaque
-                build_batch_mesh(&node->batch, true);   // Transparent
+                // Rebuild all meshes
+                build_batch_mesh(&node->batch, false, false);  // Opaque
+                build_batch_mesh(&node->batch, false, true);   // Opaque LOD
+                build_batch_mesh(&node->batch, true, false);   // Transparent
 
                 node->batch.dirty = false;
                 batcher->dirty_count--;
@@ -352,6 +371,7 @@ void chunk_batcher_render_opaque(ChunkBatcher* batcher, World* world,
     if (!batcher || !world) return;
 
     int view_dist = world_get_view_distance(world);
+    int lod_dist = world_get_lod_distance(world);
     int center_x = world->center_chunk_x;
     int center_z = world->center_chunk_z;
 
@@ -377,13 +397,18 @@ void chunk_batcher_render_opaque(ChunkBatcher* batcher, World* world,
             while (node) {
                 if (node->batch.batch_x == batch_x && node->batch.batch_z == batch_z) {
                     found = true;
-                    if (node->batch.opaque_valid && node->batch.opaque_mesh.vboId != NULL) {
+                    int offset = abs(bx) > abs(bz) ? abs(bx) : abs(bz);
+                    bool use_lod = batch_uses_lod(offset, lod_dist) &&
+                                   node->batch.opaque_lod_valid && node->batch.opaque_lod_mesh.vboId != NULL;
+
+                    if (use_lod || (node->batch.opaque_valid && node->batch.opaque_mesh.vboId != NULL)) {
                         // Batch origin in world coordinates
                         float origin_x = (float)(batch_x * BATCH_SIZE * CHUNK_SIZE);
                         float origin_z = (float)(batch_z * BATCH_SIZE * CHUNK_SIZE);
 
                         Matrix transform = MatrixTranslate(origin_x, 0.0f, origin_z);
-                        DrawMesh(node->batch.opaque_mesh, material, transform);
+                        DrawMesh(use_lod ? node->batch.opaque_lod_mesh : node->batch.opaque_mesh,
+                                 material, transform);
                         rendered_batches++;
                     } else {
                         // Fallback: render individual chunks when batch not built yet
@@ -4,6 +4,7 @@
 
 #include "voxel/render/particle.h"
 #include "voxel/core/texture_atlas.h"
+#include "voxel/core/assets.h"
 #include <raymath.h>
 #include <rlgl.h>
 #include <stdlib.h>
@@ -32,7 +33,7 @@ void particle_system_init(void) {
     memset(&g_particles, 0, sizeof(ParticleSystem));
 
     // Load particle shader
-    g_particles.shader = LoadShader("shaders/particle.vs", "shaders/particle.fs");
+    g_particles.shader = assets_load_shader("shaders/particle.vs", "shaders/particle.fs");
 
     if (g_particles.shader.id == 0) {
         printf("[PARTICLE] Warning: Failed to load particle shaders, using default\n");
@@ -43,6 +44,21 @@ void particle_system_init(void) {
     printf("[PARTICLE] System initialized (max %d particles)\n", MAX_PARTICLES);
 }
 
+bool particle_system_reload_shader(void) {
+    if (!g_particles.initialized) return false;
+
+    Shader shader = assets_load_shader("shaders/particle.vs", "shaders/particle.fs");
+    if (!assets_shader_is_loaded(shader)) {
+        printf("[PARTICLE] Shader reload failed, keeping the current one\n");
+        return false;
+    }
+
+    UnloadShader(g_particles.shader);
+    g_particles.shader = shader;
+    printf("[PARTICLE] Shader reloaded (ID: %d)\n", shader.id);
+    return true;
+}
+
 void particle_system_destroy(void) {
     if (!g_particles.initialized) return;
 
@@ -182,6 +198,61 @@ void particle_spawn_block_break(Vector3 position, BlockType block_type, int coun
     }
 }
 
+/**
+ * Get spark color for an ore block
+ * Returns false if the block isn't an ore
+ */
+static bool get_ore_spark_color(BlockType block_type, Color* out_color) {
+    switch (block_type) {
+        case BLOCK_COAL_ORE:    *out_color = (Color){90, 90, 90, 255}; return true;     // Soot
+        case BLOCK_IRON_ORE:    *out_color = (Color){255, 170, 110, 255}; return true;  // Orange
+        case BLOCK_GOLD_ORE:    *out_color = (Color){255, 225, 60, 255}; return true;   // Yellow
+        case BLOCK_DIAMOND_ORE: *out_color = (Color){120, 240, 255, 255}; return true;  // Cyan
+        default: return false;
+    }
+}
+
+void particle_spawn_ore_sparks(Vector3 position, BlockType block_type, int count) {
+    if (!g_particles.initialized) return;
+
+    Color spark_color;
+    if (!get_ore_spark_color(block_type, &spark_color)) return;
+
+    // Near-white snow tile so the tint shows as a flat color
+    TextureCoords tex = texture_atlas_get_coords(BLOCK_SNOW, FACE_TOP);
+
+    for (int i = 0; i < count && g_particles.active_count < MAX_PARTICLES; i++) {
+        Particle* p = find_inactive_particle();
+        if (!p) break;
+
+        p->active = true;
+        p->type = PARTICLE_TYPE_SPARK;
+
+        // Start at block center
+        p->position.x = position.x + 0.5f;
+        p->position.y = position.y + 0.5f;
+        p->position.z = position.z + 0.5f;
+
+        // Fast burst in all directions
+        p->velocity.x = ((float)(rand() % 100) / 100.0f - 0.5f) * 8.0f;
+        p->velocity.y = ((float)(rand() % 100) / 100.0f) * 6.0f + 1.0f;
+        p->velocity.z = ((float)(rand() % 100) / 100.0f - 0.5f) * 8.0f;
+
+        // Small and short-lived
+        p->size = 0.06f + ((float)(rand() % 50) / 1000.0f);
+        p->life = 0.25f + ((float)(rand() % 100) / 400.0f);
+        p->max_life = p->life;
+        p->color = spark_color;
+
+        p->u_min = tex.u_min;
+        p->u_max = tex.u_max;
+        p->v_min = tex.v_min;
+        p->v_max = tex.v_max;
+
+        g_particles.active_count++;
+    }
+}
+
 void particle_spawn_water_splash(Vector3 position, int count, bool upward) {
     if (!g_particles.initialized) return;
 
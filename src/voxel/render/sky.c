@@ -4,6 +4,7 @@
  */
 
 #include "voxel/render/sky.h"
+#include "voxel/core/assets.h"
 #include <raylib.h>
 #include <raymath.h>
 #include <rlgl.h>
@@ -18,17 +19,21 @@ static int cam_forward_loc = -1;
 static int cam_right_loc = -1;
 static int cam_up_loc = -1;
 
+static void cache_uniform_locations(void) {
+    sun_loc = GetShaderLocation(sky_shader, "u_sun_direction");
+    time_loc = GetShaderLocation(sky_shader, "u_time_of_day");
+    cam_forward_loc = GetShaderLocation(sky_shader, "u_cam_forward");
+    cam_right_loc = GetShaderLocation(sky_shader, "u_cam_right");
+    cam_up_loc = GetShaderLocation(sky_shader, "u_cam_up");
+}
+
 void sky_init(void) {
     // Load sky shader
-    sky_shader = LoadShader("shaders/sky.vs", "shaders/sky.fs");
+    sky_shader = assets_load_shader("shaders/sky.vs", "shaders/sky.fs");
 
     if (sky_shader.id > 0) {
         printf("[SKY] Sky shader loaded (ID: %d)\n", sky_shader.id);
-        sun_loc = GetShaderLocation(sky_shader, "u_sun_direction");
-        time_loc = GetShaderLocation(sky_shader, "u_time_of_day");
-        cam_forward_loc = GetShaderLocation(sky_shader, "u_cam_forward");
-        cam_right_loc = GetShaderLocation(sky_shader, "u_cam_right");
-        cam_up_loc = GetShaderLocation(sky_shader, "u_cam_up");
+        cache_uniform_locations();
     } else {
         printf("[SKY] WARNING: Sky shader failed to load\n");
     }
@@ -95,6 +100,22 @@ void sky_render(Camera3D camera, float time_of_day) {
     EndShaderMode();
 }
 
+bool sky_reload_shader(void) {
+    Shader shader = assets_load_shader("shaders/sky.vs", "shaders/sky.fs");
+    if (!assets_shader_is_loaded(shader)) {
+        printf("[SKY] Shader reload failed, keeping the current one\n");
+        return false;
+    }
+
+    if (sky_shader.id > 0) {
+        UnloadShader(sky_shader);
+    }
+    sky_shader = shader;
+    cache_uniform_locations();
+    printf("[SKY] Sky shader reloaded (ID: %d)\n", sky_shader.id);
+    return true;
+}
+
 void sky_destroy(void) {
     if (initialized && sky_shader.id > 0) {
         UnloadShader(sky_shader);
@@ -0,0 +1,88 @@
+/**
+ * Floating Text Implementation
+ */
+
+#include "voxel/ui/floating_text.h"
+#include <raymath.h>
+#include <stdio.h>
+#include <string.h>
+
+typedef struct {
+    char text[FLOATING_TEXT_LENGTH];
+    Color color;
+    Vector3 position;
+    float lifetime;     // Remaining seconds, <= 0 means inactive
+} FloatingText;
+
+static FloatingText g_texts[MAX_FLOATING_TEXTS];
+
+void floating_text_clear(void) {
+    memset(g_texts, 0, sizeof(g_texts));
+}
+
+void floating_text_spawn(Vector3 position, const char* text, Color color) {
+    if (!text) return;
+
+    // Use a free slot, otherwise replace the popup closest to expiring
+    int slot = 0;
+    for (int i = 0; i < MAX_FLOATING_TEXTS; i++) {
+        if (g_texts[i].lifetime <= 0.0f) {
+            slot = i;
+            break;
+        }
+        if (g_texts[i].lifetime < g_texts[slot].lifetime) {
+            slot = i;
+        }
+    }
+
+    FloatingText* ft = &g_texts[slot];
+    snprintf(ft->text, sizeof(ft->text), "%s", text);
+    ft->color = color;
+    ft->position = position;
+    ft->lifetime = FLOATING_TEXT_LIFETIME;
+}
+
+void floating_text_spawn_item_gain(Vector3 position, ItemType item, int count) {
+    if (item == ITEM_NONE || count <= 0) return;
+
+    char text[FLOATING_TEXT_LENGTH];
+    snprintf(text, sizeof(text), "+%d %s", count, item_get_name(item));
+    floating_text_spawn(position, text, (Color){255, 230, 120, 255});
+}
+
+void floating_text_update(float dt) {
+    for (int i = 0; i < MAX_FLOATING_TEXTS; i++) {
+        FloatingText* ft = &g_texts[i];
+        if (ft->lifetime <= 0.0f) continue;
+
+        ft->position.y += FLOATING_TEXT_RISE_SPEED * dt;
+        ft->lifetime -= dt;
+    }
+}
+
+void floating_text_draw(Camera3D camera) {
+    Vector3 forward = Vector3Subtract(camera.target, camera.position);
+
+    for (int i = 0; i < MAX_FLOATING_TEXTS; i++) {
+        FloatingText* ft = &g_texts[i];
+        if (ft->lifetime <= 0.0f) continue;
+
+        // Skip popups behind the camera
+        Vector3 to_text = Vector3Subtract(ft->position, camera.position);
+        if (Vector3DotProduct(to_text, forward) <= 0.0f) continue;
+
+        Vector2 screen_pos = GetWorldToScreen(ft->position, camera);
+        int text_width = MeasureText(ft->text, FLOATING_TEXT_FONT_SIZE);
+        int text_x = (int)screen_pos.x - text_width / 2;
+        int text_y = (int)screen_pos.y - FLOATING_TEXT_FONT_SIZE / 2;
+
+        // Fade out over the popup's lifetime
+        float alpha = ft->lifetime / FLOATING_TEXT_LIFETIME;
+        Color shadow = (Color){0, 0, 0, (unsigned char)(180 * alpha)};
+        Color color = ft->color;
+        color.a = (unsigned char)(color.a * alpha);
+
+        DrawText(ft->text, text_x + 1, text_y + 1, FLOATING_TEXT_FONT_SIZE, shadow);
+        DrawText(ft->text, text_x, text_y, FLOATING_TEXT_FONT_SIZE, color);
+    }
+}
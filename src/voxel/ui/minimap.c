@@ -3,7 +3,10 @@
  */
 
 #include "voxel/ui/minimap.h"
+#include "voxel/ui/waypoint.h"
 #include "voxel/world/world.h"
+#include "voxel/world/discovery.h"
+#include "voxel/world/ore_scanner.h"
 #include "voxel/player/player.h"
 #include "voxel/network/network.h"
 #include "voxel/core/block.h"
@@ -25,6 +28,9 @@ struct Minimap {
     bool initialized;            // Has been initialized
 };
 
+// Unexplored columns (fog of war)
+#define MINIMAP_FOG_COLOR (Color){10, 10, 14, 255}
+
 // ============================================================================
 // BLOCK COLOR MAPPING
 // ============================================================================
@@ -63,6 +69,7 @@ static Color get_block_color(BlockType type, int height) {
         case BLOCK_STALACTITE:   base = (Color){100, 100, 105, 255}; break; // Gray stone
         case BLOCK_STALAGMITE:   base = (Color){95, 95, 100, 255}; break;   // Gray stone
         case BLOCK_CHEST:        base = (Color){139, 90, 43, 255}; break;   // Brown wood
+        case BLOCK_SAPLING:      base = (Color){70, 160, 60, 255}; break;   // Young green
         case BLOCK_AIR:
         default:                base = (Color){0, 0, 0, 255}; break;        // Black
     }
@@ -81,6 +88,22 @@ static Color get_block_color(BlockType type, int height) {
     };
 }
 
+/**
+ * Find the topmost non-air block in a column
+ */
+static BlockType get_surface(World* world, int world_x, int world_z, int* out_y) {
+    // Start from 200 to cover terrain surface at y=160
+    for (int y = 200; y >= 0; y--) {
+        Block block = world_get_block(world, world_x, y, world_z);
+        if (block.type != BLOCK_AIR) {
+            *out_y = y;
+            return (BlockType)block.type;
+        }
+    }
+    *out_y = 0;
+    return BLOCK_AIR;
+}
+
 // ============================================================================
 // MINIMAP LIFECYCLE
 // ============================================================================
@@ -155,27 +178,19 @@ void minimap_update(Minimap* minimap, World* world, Player* player) {
             int world_x = player_x + dx_block;
             int world_z = player_z + dz_block;
 
-            // Find surface block (scan from top down)
-            // Start from 200 to cover terrain surface at y=160
-            int surface_y = 0;
-            BlockType surface_type = BLOCK_AIR;
-
-            for (int y = 200; y >= 0; y--) {
-                Block block = world_get_block(world, world_x, y, world_z);
-                if (block.type != BLOCK_AIR) {
-                    surface_y = y;
-                    surface_type = (BlockType)block.type;
-                    break;
-                }
-            }
-
             // Map to pixel position
             int px = half_size + (int)(dx_block * scale);
             int py = half_size + (int)(dz_block * scale);
 
             // Only draw if within bounds
             if (px >= 0 && px < minimap->size && py >= 0 && py < minimap->size) {
-                Color color = get_block_color(surface_type, surface_y);
+                Color color = MINIMAP_FOG_COLOR;
+                if (discovery_is_discovered(world_x, world_z)) {
+                    // Find surface block (scan from top down)
+                    int surface_y;
+                    BlockType surface_type = get_surface(world, world_x, world_z, &surface_y);
+                    color = get_block_color(surface_type, surface_y);
+                }
 
                 // Draw a small rectangle if scale > 1 for smoother look
                 if (scale >= 1.0f) {
@@ -280,6 +295,34 @@ void minimap_draw(Minimap* minimap, Player* player, NetworkContext* network) {
         }
     }
 
+    // Draw waypoints as colored squares
+    for (int i = 0; i < MAX_WAYPOINTS; i++) {
+        const Waypoint* wp = waypoint_get(i);
+        if (!wp) continue;
+
+        int dx = (int)wp->position.x - player_x;
+        int dz = (int)wp->position.z - player_z;
+        if (abs(dx) < minimap->radius && abs(dz) < minimap->radius) {
+            int px = center_x + (int)(dx * scale);
+            int py = center_y + (int)(dz * scale);
+            DrawRectangle(px - 3, py - 3, 7, 7, wp->color);
+            DrawRectangleLines(px - 3, py - 3, 7, 7, BLACK);
+        }
+    }
+
+    // Draw scanned ores as small colored dots
+    for (int i = 0; i < ore_scanner_get_count(); i++) {
+        const OreScanResult* ore = ore_scanner_get(i);
+
+        int dx = ore->x - player_x;
+        int dz = ore->z - player_z;
+        if (abs(dx) < minimap->radius && abs(dz) < minimap->radius) {
+            int px = center_x + (int)(dx * scale);
+            int py = center_y + (int)(dz * scale);
+            DrawRectangle(px - 1, py - 1, 3, 3, ore_scanner_get_color(ore->type));
+        }
+    }
+
     // Draw local player arrow in center
     // Player yaw: 0 = looking at -Z, 90 = looking at +X
     float angle = player->yaw * DEG2RAD;
@@ -316,3 +359,72 @@ void minimap_draw(Minimap* minimap, Player* player, NetworkContext* network) {
     int font_size = 10;
     DrawText("N", x + minimap->size / 2 - 3, y + 2, font_size, WHITE);
 }
+
+int minimap_pick_waypoint(Minimap* minimap, Player* player, int mouse_x, int mouse_y) {
+    if (!minimap || !player) return -1;
+
+    int screen_width = GetScreenWidth();
+    int x = screen_width - minimap->size - MINIMAP_MARGIN;
+    int y = MINIMAP_MARGIN;
+    if (mouse_x < x || mouse_x >= x + minimap->size || mouse_y < y || mouse_y >= y + minimap->size) {
+        return -1;
+    }
+
+    // Same mapping as minimap_draw
+    float scale = (float)minimap->size / (minimap->radius * 2.0f);
+    int center_x = x + minimap->size / 2;
+    int center_y = y + minimap->size / 2;
+    int player_x = (int)player->position.x;
+    int player_z = (int)player->position.z;
+
+    int best = -1;
+    int best_dist_sq = MINIMAP_WAYPOINT_PICK_RADIUS * MINIMAP_WAYPOINT_PICK_RADIUS;
+    for (int i = 0; i < MAX_WAYPOINTS; i++) {
+        const Waypoint* wp = waypoint_get(i);
+        if (!wp) continue;
+
+        int px = center_x + (int)(((int)wp->position.x - player_x) * scale);
+        int py = center_y + (int)(((int)wp->position.z - player_z) * scale);
+        int dist_sq = (px - mouse_x) * (px - mouse_x) + (py - mouse_y) * (py - mouse_y);
+        if (dist_sq <= best_dist_sq) {
+            best_dist_sq = dist_sq;
+            best = i;
+        }
+    }
+    return best;
+}
+
+bool minimap_export_png(World* world, Player* player, const char* path) {
+    if (!world || !player || !path) return false;
+
+    // Cover the loaded area: view distance in chunks around the player
+    int radius = world_get_view_distance(world) * CHUNK_SIZE;
+    int size = radius * 2;
+    int origin_x = (int)player->position.x - radius;
+    int origin_z = (int)player->position.z - radius;
+
+    Image image = GenImageColor(size, size, (Color){20, 20, 30, 255});
+
+    for (int pz = 0; pz < size; pz++) {
+        for (int px = 0; px < size; px++) {
+            if (!discovery_is_discovered(origin_x + px, origin_z + pz)) {
+                ImageDrawPixel(&image, px, pz, MINIMAP_FOG_COLOR);
+                continue;
+            }
+
+            int surface_y;
+            BlockType surface_type = get_surface(world, origin_x + px, origin_z + pz, &surface_y);
+            if (surface_type == BLOCK_AIR) continue;  // Unloaded column
+            ImageDrawPixel(&image, px, pz, get_block_color(surface_type, surface_y));
+        }
+    }
+
+    // Mark the player position
+    ImageDrawCircle(&image, radius, radius, 3, RED);
+
+    bool ok = ExportImage(image, path);
+    UnloadImage(image);
+
+    printf("[MINIMAP] %s map %dx%d to %s\n", ok ? "Exported" : "Failed to export", size, size, path);
+    return ok;
+}
@@ -8,6 +8,7 @@
 #include "voxel/ui/pause_menu.h"
 #include "voxel/ui/settings_menu.h"
 #include "voxel/network/network.h"
+#include "voxel/player/stats.h"
 #include <raylib.h>
 #include <stdlib.h>
 #include <string.h>
@@ -768,6 +769,7 @@ void pause_menu_draw(PauseMenu* menu) {
     switch (menu->state) {
         case MENU_STATE_MAIN:
             draw_main_menu(menu, panel_x, panel_y, mouse_x, mouse_y);
+            stats_draw(panel_x + PANEL_WIDTH + 8, panel_y);
             break;
         case MENU_STATE_HOST_SETUP:
             draw_host_setup(menu, panel_x, panel_y, mouse_x, mouse_y);
@@ -0,0 +1,92 @@
+/**
+ * Ruler Tool Implementation
+ */
+
+#include "voxel/ui/ruler.h"
+#include <math.h>
+#include <stdio.h>
+
+static bool g_active = false;
+static bool g_has_measure = false;   // Start point placed
+static bool g_dragging = false;      // End point follows the target
+static Vector3 g_start = {0};
+static Vector3 g_end = {0};
+
+/**
+ * Center of a block position
+ */
+static Vector3 block_center(Vector3 block) {
+    return (Vector3){block.x + 0.5f, block.y + 0.5f, block.z + 0.5f};
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+void ruler_toggle(void) {
+    g_active = !g_active;
+    g_has_measure = false;
+    g_dragging = false;
+    printf("[RULER] %s\n", g_active ? "ON" : "OFF");
+}
+
+bool ruler_is_active(void) {
+    return g_active;
+}
+
+void ruler_update(bool has_target, Vector3 target, bool pressed, bool held) {
+    if (!g_active) return;
+
+    if (pressed && has_target) {
+        g_start = target;
+        g_end = target;
+        g_has_measure = true;
+        g_dragging = true;
+    }
+
+    if (!held) {
+        g_dragging = false;
+    } else if (g_dragging && has_target) {
+        g_end = target;
+    }
+}
+
+float ruler_block_distance(Vector3 from, Vector3 to) {
+    float dx = to.x - from.x;
+    float dy = to.y - from.y;
+    float dz = to.z - from.z;
+    return sqrtf(dx * dx + dy * dy + dz * dz);
+}
+
+void ruler_draw_3d(void) {
+    if (!g_active || !g_has_measure) return;
+
+    Vector3 start = block_center(g_start);
+    Vector3 end = block_center(g_end);
+
+    DrawCubeWires(start, 1.02f, 1.02f, 1.02f, RULER_COLOR);
+    DrawCubeWires(end, 1.02f, 1.02f, 1.02f, RULER_COLOR);
+    DrawLine3D(start, end, RULER_COLOR);
+}
+
+void ruler_draw_hud(void) {
+    if (!g_active) return;
+
+    char text[96];
+    if (g_has_measure) {
+        snprintf(text, sizeof(text), "%.1f blocks  (dx %d, dy %d, dz %d)",
+                 ruler_block_distance(g_start, g_end),
+                 (int)(g_end.x - g_start.x), (int)(g_end.y - g_start.y), (int)(g_end.z - g_start.z));
+    } else {
+        snprintf(text, sizeof(text), "Ruler: click and drag to measure");
+    }
+
+    int padding = 6;
+    int width = MeasureText(text, RULER_FONT_SIZE) + padding * 2;
+    int height = RULER_FONT_SIZE + padding * 2;
+    int x = (GetScreenWidth() - width) / 2;
+    int y = GetScreenHeight() / 2 + 24;
+
+    DrawRectangle(x, y, width, height, (Color){0, 0, 0, 150});
+    DrawText(text, x + padding, y + padding, RULER_FONT_SIZE, RULER_COLOR);
+}
@@ -7,6 +7,8 @@
 #include "voxel/ui/settings_menu.h"
 #include "voxel/core/settings_constants.h"
 #include "voxel/world/world.h"
+#include "voxel/inventory/inventory_ui.h"
+#include "voxel/player/player.h"
 #include <raylib.h>
 #include <stdlib.h>
 #include <stdio.h>
@@ -44,9 +46,17 @@
 static const char* graphics_items[] = {
     "View Distance",
     "LOD Distance",
-    "Batch Rebuilds/Frame"
+    "Batch Rebuilds/Frame",
+    "Frame Rate Cap",
+    "VSync",
+    "Color-Blind Palette",
+    "Camera Shake"
 };
-#define GRAPHICS_ITEM_COUNT 3
+#define GRAPHICS_ITEM_COUNT 7
+
+// Frame rate cap choices, cycled with left/right (0 = uncapped)
+static const int fps_options[] = {30, 60, 120, 144, 0};
+#define FPS_OPTION_COUNT 5
 
 static const char* world_items[] = {
     "Day Speed",
@@ -70,6 +80,21 @@ static const char* category_names[] = {
 // HELPER FUNCTIONS
 // ============================================================================
 
+/**
+ * Step to the next/previous frame rate cap choice
+ */
+static int step_fps_option(int current, int delta) {
+    int index = 1;  // Fall back to 60 for values not in the list
+    for (int i = 0; i < FPS_OPTION_COUNT; i++) {
+        if (fps_options[i] == current) {
+            index = i;
+            break;
+        }
+    }
+    index = (index + delta + FPS_OPTION_COUNT) % FPS_OPTION_COUNT;
+    return fps_options[index];
+}
+
 static void draw_slider(int x, int y, int width, float value, float min, float max, bool selected) {
     // Background
     DrawRectangle(x, y, width, SLIDER_HEIGHT, COLOR_SLIDER_BG);
@@ -260,6 +285,14 @@ int settings_menu_handle_input(SettingsMenu* menu) {
                     s->batch_rebuilds += delta * 4;
                     if (s->batch_rebuilds < SETTING_BATCH_REBUILD_MIN) s->batch_rebuilds = SETTING_BATCH_REBUILD_MIN;
                     if (s->batch_rebuilds > SETTING_BATCH_REBUILD_MAX) s->batch_rebuilds = SETTING_BATCH_REBUILD_MAX;
+                } else if (menu->selected_item == 3) {  // Frame Rate Cap
+                    s->target_fps = step_fps_option(s->target_fps, delta);
+                } else if (menu->selected_item == 4) {  // VSync
+                    s->vsync = !s->vsync;
+                } else if (menu->selected_item == 5) {  // Color-Blind Palette
+                    s->colorblind_palette = !s->colorblind_palette;
+                } else if (menu->selected_item == 6) {  // Camera Shake
+                    s->camera_shake = !s->camera_shake;
                 }
                 break;
 
@@ -359,6 +392,24 @@ void settings_menu_draw(SettingsMenu* menu) {
                     DrawText(val, ctrl_x + CONTROL_WIDTH + 10, ctrl_y + 2, 14, COLOR_TEXT_DIM);
                 } else if (i == 2) {  // Batch Rebuilds
                     draw_spinner_value(ctrl_x, ctrl_y, CONTROL_WIDTH, s->batch_rebuilds, selected);
+                } else if (i == 3) {  // Frame Rate Cap
+                    if (s->target_fps > 0) {
+                        draw_spinner_value(ctrl_x, ctrl_y, CONTROL_WIDTH, s->target_fps, selected);
+                    } else {
+                        const char* buf = "< Uncapped >";
+                        int text_width = MeasureText(buf, 16);
+                        DrawText(buf, ctrl_x + (CONTROL_WIDTH - text_width) / 2, ctrl_y + 2, 16,
+                                 selected ? WHITE : COLOR_TEXT_DIM);
+                        if (selected) {
+                            DrawRectangleLines(ctrl_x, ctrl_y, CONTROL_WIDTH, SLIDER_HEIGHT, WHITE);
+                        }
+                    }
+                } else if (i == 4) {  // VSync
+                    draw_toggle(ctrl_x, ctrl_y, s->vsync, selected);
+                } else if (i == 5) {  // Color-Blind Palette
+                    draw_toggle(ctrl_x, ctrl_y, s->colorblind_palette, selected);
+                } else if (i == 6) {  // Camera Shake
+                    draw_toggle(ctrl_x, ctrl_y, s->camera_shake, selected);
                 }
                 break;
 
@@ -398,14 +449,35 @@ void settings_menu_apply(SettingsMenu* menu, World* world) {
     // Apply settings to world
     if (world) {
         world_set_view_distance(world, menu->working_copy.view_distance);
+        world_set_lod_distance(world, menu->working_copy.lod_distance);
         world_set_batch_rebuilds(world, menu->working_copy.batch_rebuilds);
         world_set_max_uploads(world, menu->working_copy.max_uploads_per_frame);
     }
 
-    printf("[SETTINGS] Applied: view=%d, lod=%d, batch=%d, uploads=%d, day_speed=%.2f\n",
+    settings_apply_display(&menu->working_copy);
+
+    printf("[SETTINGS] Applied: view=%d, lod=%d, batch=%d, uploads=%d, day_speed=%.2f, fps=%d, vsync=%d\n",
            menu->working_copy.view_distance,
            menu->working_copy.lod_distance,
            menu->working_copy.batch_rebuilds,
            menu->working_copy.max_uploads_per_frame,
-           menu->working_copy.day_speed);
+           menu->working_copy.day_speed,
+           menu->working_copy.target_fps,
+           menu->working_copy.vsync);
+}
+
+void settings_apply_display(const GameSettings* settings) {
+    if (!settings) return;
+
+    // Simulation already scales by frame time, so the cap only affects smoothness
+    SetTargetFPS(settings->target_fps);
+
+    if (settings->vsync) {
+        SetWindowState(FLAG_VSYNC_HINT);
+    } else {
+        ClearWindowState(FLAG_VSYNC_HINT);
+    }
+
+    inventory_ui_set_colorblind_palette(settings->colorblind_palette);
+    player_set_camera_shake_enabled(settings->camera_shake);
 }
@@ -0,0 +1,142 @@
+/**
+ * Waypoint Markers Implementation
+ */
+
+#include "voxel/ui/waypoint.h"
+#include <raymath.h>
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+static Waypoint g_waypoints[MAX_WAYPOINTS];
+static int g_next_label = 1;     // Labels keep counting up so removed names aren't reused
+
+// Colors cycled through as waypoints are added
+static const Color g_waypoint_colors[] = {
+    {80, 200, 255, 255},   // Sky blue
+    {255, 120, 120, 255},  // Salmon
+    {140, 255, 140, 255},  // Mint
+    {220, 140, 255, 255},  // Lilac
+    {255, 170, 60, 255},   // Orange
+};
+#define WAYPOINT_COLOR_COUNT 5
+
+// ============================================================================
+// WAYPOINT LIST
+// ============================================================================
+
+void waypoint_clear(void) {
+    memset(g_waypoints, 0, sizeof(g_waypoints));
+    g_next_label = 1;
+}
+
+int waypoint_add(Vector3 position) {
+    for (int i = 0; i < MAX_WAYPOINTS; i++) {
+        Waypoint* wp = &g_waypoints[i];
+        if (wp->active) continue;
+
+        wp->position = position;
+        wp->color = g_waypoint_colors[(g_next_label - 1) % WAYPOINT_COLOR_COUNT];
+        snprintf(wp->label, sizeof(wp->label), "Waypoint %d", g_next_label++);
+        wp->active = true;
+        return i;
+    }
+    return -1;
+}
+
+void waypoint_remove(int index) {
+    if (index < 0 || index >= MAX_WAYPOINTS) return;
+    g_waypoints[index].active = false;
+}
+
+const Waypoint* waypoint_get(int index) {
+    if (index < 0 || index >= MAX_WAYPOINTS || !g_waypoints[index].active) return NULL;
+    return &g_waypoints[index];
+}
+
+void waypoint_draw_all(Camera3D camera, Vector3 from) {
+    for (int i = 0; i < MAX_WAYPOINTS; i++) {
+        const Waypoint* wp = &g_waypoints[i];
+        if (!wp->active) continue;
+        waypoint_draw_marker(camera, wp->position, from, wp->label, wp->color);
+    }
+}
+
+// ============================================================================
+// MARKERS
+// ============================================================================
+
+bool waypoint_get_screen_marker(Camera3D camera, Vector3 target, Vector2* out_screen, Vector2* out_dir) {
+    int screen_width = GetScreenWidth();
+    int screen_height = GetScreenHeight();
+    Vector2 center = {screen_width * 0.5f, screen_height * 0.5f};
+
+    Vector3 forward = Vector3Normalize(Vector3Subtract(camera.target, camera.position));
+    Vector3 to_target = Vector3Subtract(target, camera.position);
+    bool in_front = Vector3DotProduct(to_target, forward) > 0.0f;
+
+    Vector2 dir;
+    if (in_front) {
+        Vector2 projected = GetWorldToScreen(target, camera);
+        if (projected.x >= WAYPOINT_EDGE_MARGIN && projected.x <= screen_width - WAYPOINT_EDGE_MARGIN &&
+            projected.y >= WAYPOINT_EDGE_MARGIN && projected.y <= screen_height - WAYPOINT_EDGE_MARGIN) {
+            *out_screen = projected;
+            *out_dir = Vector2Normalize(Vector2Subtract(projected, center));
+            return true;
+        }
+        dir = Vector2Subtract(projected, center);
+    } else {
+        // Projection is mirrored behind the camera, so use the camera basis instead
+        Vector3 right = Vector3Normalize(Vector3CrossProduct(forward, camera.up));
+        Vector3 up = Vector3CrossProduct(right, forward);
+        dir = (Vector2){Vector3DotProduct(to_target, right), -Vector3DotProduct(to_target, up)};
+
+        // Straight behind: point down so the arrow is still visible
+        if (Vector2Length(dir) < 0.001f) dir = (Vector2){0.0f, 1.0f};
+    }
+
+    dir = Vector2Normalize(dir);
+
+    // Scale the direction until it hits the margin rectangle
+    float half_w = center.x - WAYPOINT_EDGE_MARGIN;
+    float half_h = center.y - WAYPOINT_EDGE_MARGIN;
+    float scale_x = fabsf(dir.x) > 0.0001f ? half_w / fabsf(dir.x) : INFINITY;
+    float scale_y = fabsf(dir.y) > 0.0001f ? half_h / fabsf(dir.y) : INFINITY;
+    float scale = fminf(scale_x, scale_y);
+
+    *out_screen = Vector2Add(center, Vector2Scale(dir, scale));
+    *out_dir = dir;
+    return false;
+}
+
+void waypoint_draw_marker(Camera3D camera, Vector3 target, Vector3 from, const char* label, Color color) {
+    Vector2 pos, dir;
+    bool on_screen = waypoint_get_screen_marker(camera, target, &pos, &dir);
+
+    char text[64];
+    snprintf(text, sizeof(text), "%s (%.0fm)", label ? label : "", Vector3Distance(from, target));
+    int text_width = MeasureText(text, WAYPOINT_FONT_SIZE);
+
+    if (on_screen) {
+        DrawCircleV(pos, 4.0f, color);
+    } else {
+        // Arrow tip points toward the target, base faces the screen center
+        Vector2 perp = {-dir.y, dir.x};
+        Vector2 tip = Vector2Add(pos, Vector2Scale(dir, WAYPOINT_ARROW_SIZE * 0.5f));
+        Vector2 base = Vector2Subtract(pos, Vector2Scale(dir, WAYPOINT_ARROW_SIZE * 0.5f));
+        Vector2 left = Vector2Add(base, Vector2Scale(perp, WAYPOINT_ARROW_SIZE * 0.5f));
+        Vector2 right = Vector2Subtract(base, Vector2Scale(perp, WAYPOINT_ARROW_SIZE * 0.5f));
+        DrawTriangle(tip, right, left, color);  // Counter-clockwise on screen
+    }
+
+    // Label sits on the screen-center side of the marker so it stays on screen
+    int text_x = (int)(pos.x - dir.x * (text_width * 0.5f + WAYPOINT_ARROW_SIZE)) - text_width / 2;
+    int text_y = (int)(pos.y - dir.y * (WAYPOINT_FONT_SIZE + WAYPOINT_ARROW_SIZE)) - WAYPOINT_FONT_SIZE / 2;
+    if (on_screen) {
+        text_x = (int)pos.x - text_width / 2;
+        text_y = (int)pos.y - WAYPOINT_FONT_SIZE - 8;
+    }
+
+    DrawRectangle(text_x - 4, text_y - 2, text_width + 8, WAYPOINT_FONT_SIZE + 4, (Color){0, 0, 0, 150});
+    DrawText(text, text_x, text_y, WAYPOINT_FONT_SIZE, color);
+}
@@ -117,3 +117,17 @@ const char* biome_get_name(BiomeType type) {
     const BiomeProperties* props = biome_get_properties(type);
     return props->name;
 }
+
+TreeType biome_pick_tree_type(const BiomeProperties* props, int surface_y, float roll) {
+    if (!props) return TREE_TYPE_OAK;
+
+    // Hilltops in grassy biomes grow conifers like the tundra
+    if (props->surface_block == BLOCK_GRASS && surface_y >= BIOME_HIGHLAND_Y) {
+        return (roll < BIOME_HIGHLAND_SPRUCE_CHANCE) ? TREE_TYPE_SPRUCE : props->primary_tree;
+    }
+
+    if (props->secondary_tree != TREE_TYPE_COUNT && roll < props->secondary_chance) {
+        return props->secondary_tree;
+    }
+    return props->primary_tree;
+}
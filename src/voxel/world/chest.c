@@ -238,28 +238,35 @@ bool chest_add_item(ChestData* chest, ItemStack item) {
     const ItemProperties* props = item_get_properties(item.type);
     if (!props) return false;
 
-    // First try to stack with existing items
-    if (props->max_stack_size > 1) {
-        for (int i = 0; i < CHEST_SLOTS; i++) {
-            if (chest->slots[i].type == item.type) {
-                int space = props->max_stack_size - chest->slots[i].count;
-                if (space >= item.count) {
-                    chest->slots[i].count += item.count;
-                    return true;
-                }
-            }
+    // Room in partly filled matching stacks, plus one empty slot for the rest
+    int space = 0;
+    int empty_slot = -1;
+    for (int i = 0; i < CHEST_SLOTS; i++) {
+        ItemStack* slot = &chest->slots[i];
+        if (slot->type == ITEM_NONE || slot->count == 0) {
+            if (empty_slot < 0) empty_slot = i;
+        } else if (slot->type == item.type && props->max_stack_size > 1) {
+            space += props->max_stack_size - slot->count;
         }
     }
+    if (space < item.count && empty_slot < 0) return false;  // Chest is full
 
-    // Find first empty slot
-    for (int i = 0; i < CHEST_SLOTS; i++) {
-        if (chest->slots[i].type == ITEM_NONE || chest->slots[i].count == 0) {
-            chest->slots[i] = item;
-            return true;
-        }
+    // Top up matching stacks first
+    for (int i = 0; i < CHEST_SLOTS && space > 0 && item.count > 0; i++) {
+        ItemStack* slot = &chest->slots[i];
+        if (slot->type != item.type || slot->count == 0 || slot->count >= props->max_stack_size) continue;
+
+        uint8_t to_add = props->max_stack_size - slot->count;
+        if (to_add > item.count) to_add = item.count;
+        slot->count += to_add;
+        item.count -= to_add;
     }
 
-    return false;  // Chest is full
+    // Whatever is left starts a new stack
+    if (item.count > 0) {
+        chest->slots[empty_slot] = item;
+    }
+    return true;
 }
 
 ItemStack chest_take_item(ChestData* chest, int slot) {
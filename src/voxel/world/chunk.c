@@ -278,13 +278,53 @@ static float calculate_face_brightness(Vector3 normal) {
     return 0.9f;  // Fallback
 }
 
+/**
+ * Check if a block's top/bottom texture looks right rotated or mirrored
+ * (natural ground; excludes log rings, beds, chests and other oriented tops)
+ */
+static bool block_has_variable_top(BlockType type) {
+    switch (type) {
+        case BLOCK_GRASS:
+        case BLOCK_DIRT:
+        case BLOCK_STONE:
+        case BLOCK_SAND:
+        case BLOCK_DEEP_STONE:
+        case BLOCK_GRAVEL:
+        case BLOCK_CLAY:
+        case BLOCK_SNOW:
+            return true;
+        default:
+            return false;
+    }
+}
+
+/**
+ * Pick a texture variant (0-7: four rotations, each optionally mirrored)
+ * from a hash of the block's world position, so the same block always
+ * gets the same variant and ground doesn't look like a repeating grid
+ */
+static int get_texture_variant(Chunk* chunk, int x, int y, int z, BlockType type) {
+    if (!block_has_variable_top(type)) return 0;
+
+    int world_x = chunk->x * CHUNK_SIZE + x;
+    int world_z = chunk->z * CHUNK_SIZE + z;
+    unsigned int h = (unsigned int)world_x * 73856093u ^
+                     (unsigned int)y * 19349663u ^
+                     (unsigned int)world_z * 83492791u;
+    h ^= h >> 13;
+    h *= 0x5bd1e995u;
+    h ^= h >> 15;
+    return (int)(h & 7u);
+}
+
 /**
  * Add a quad face to the mesh buffers with per-vertex ambient occlusion
  * ao1-ao4 are ambient occlusion values for each vertex (0.0-1.0)
+ * uv_variant rotates (bits 0-1) and mirrors (bit 2) the texture on the quad
  */
 static void add_quad(float* vertices, float* texcoords, float* normals, unsigned char* colors, int* vertex_count,
                      Vector3 v1, Vector3 v2, Vector3 v3, Vector3 v4, Vector3 normal,
-                     BlockType block_type, float width, float height, uint8_t block_light_level,
+                     BlockType block_type, int uv_variant, float width, float height, uint8_t block_light_level,
                      float ao1, float ao2, float ao3, float ao4) {
     (void)width;  // Unused but kept for API compatibility
     (void)height;
@@ -297,6 +337,21 @@ static void add_quad(float* vertices, float* texcoords, float* normals, unsigned
     // Get texture coordinates from atlas
     TextureCoords tex = texture_atlas_get_coords(block_type, face);
 
+    // Corner UVs for v1-v4, mirrored and rotated by the variant
+    float u_left = tex.u_min;
+    float u_right = tex.u_max;
+    if (uv_variant & 4) {
+        u_left = tex.u_max;
+        u_right = tex.u_min;
+    }
+    float corner_u[4] = {u_left, u_right, u_right, u_left};
+    float corner_v[4] = {tex.v_min, tex.v_min, tex.v_max, tex.v_max};
+    int r = uv_variant & 3;
+    float uv1[2] = {corner_u[r], corner_v[r]};
+    float uv2[2] = {corner_u[(r + 1) & 3], corner_v[(r + 1) & 3]};
+    float uv3[2] = {corner_u[(r + 2) & 3], corner_v[(r + 2) & 3]};
+    float uv4[2] = {corner_u[(r + 3) & 3], corner_v[(r + 3) & 3]};
+
     // Calculate face brightness based on normal direction
     float face_brightness = calculate_face_brightness(normal);
 
@@ -318,38 +373,38 @@ static void add_quad(float* vertices, float* texcoords, float* normals, unsigned
 
     // Triangle 1 (v1, v2, v3)
     vertices[idx * 3 + 0] = v1.x; vertices[idx * 3 + 1] = v1.y; vertices[idx * 3 + 2] = v1.z;
-    texcoords[idx * 2 + 0] = tex.u_min; texcoords[idx * 2 + 1] = tex.v_min;
+    texcoords[idx * 2 + 0] = uv1[0]; texcoords[idx * 2 + 1] = uv1[1];
     normals[idx * 3 + 0] = normal.x; normals[idx * 3 + 1] = normal.y; normals[idx * 3 + 2] = normal.z;
     colors[idx * 4 + 0] = light1; colors[idx * 4 + 1] = light1; colors[idx * 4 + 2] = light1; colors[idx * 4 + 3] = 255;
     idx++;
 
     vertices[idx * 3 + 0] = v2.x; vertices[idx * 3 + 1] = v2.y; vertices[idx * 3 + 2] = v2.z;
-    texcoords[idx * 2 + 0] = tex.u_max; texcoords[idx * 2 + 1] = tex.v_min;
+    texcoords[idx * 2 + 0] = uv2[0]; texcoords[idx * 2 + 1] = uv2[1];
     normals[idx * 3 + 0] = normal.x; normals[idx * 3 + 1] = normal.y; normals[idx * 3 + 2] = normal.z;
     colors[idx * 4 + 0] = light2; colors[idx * 4 + 1] = light2; colors[idx * 4 + 2] = light2; colors[idx * 4 + 3] = 255;
     idx++;
 
     vertices[idx * 3 + 0] = v3.x; vertices[idx * 3 + 1] = v3.y; vertices[idx * 3 + 2] = v3.z;
-    texcoords[idx * 2 + 0] = tex.u_max; texcoords[idx * 2 + 1] = tex.v_max;
+    texcoords[idx * 2 + 0] = uv3[0]; texcoords[idx * 2 + 1] = uv3[1];
     normals[idx * 3 + 0] = normal.x; normals[idx * 3 + 1] = normal.y; normals[idx * 3 + 2] = normal.z;
     colors[idx * 4 + 0] = light3; colors[idx * 4 + 1] = light3; colors[idx * 4 + 2] = light3; colors[idx * 4 + 3] = 255;
     idx++;
 
     // Triangle 2 (v1, v3, v4)
     vertices[idx * 3 + 0] = v1.x; vertices[idx * 3 + 1] = v1.y; vertices[idx * 3 + 2] = v1.z;
-    texcoords[idx * 2 + 0] = tex.u_min; texcoords[idx * 2 + 1] = tex.v_min;
+    texcoords[idx * 2 + 0] = uv1[0]; texcoords[idx * 2 + 1] = uv1[1];
     normals[idx * 3 + 0] = normal.x; normals[idx * 3 + 1] = normal.y; normals[idx * 3 + 2] = normal.z;
     colors[idx * 4 + 0] = light1; colors[idx * 4 + 1] = light1; colors[idx * 4 + 2] = light1; colors[idx * 4 + 3] = 255;
     idx++;
 
     vertices[idx * 3 + 0] = v3.x; vertices[idx * 3 + 1] = v3.y; vertices[idx * 3 + 2] = v3.z;
-    texcoords[idx * 2 + 0] = tex.u_max; texcoords[idx * 2 + 1] = tex.v_max;
+    texcoords[idx * 2 + 0] = uv3[0]; texcoords[idx * 2 + 1] = uv3[1];
     normals[idx * 3 + 0] = normal.x; normals[idx * 3 + 1] = normal.y; normals[idx * 3 + 2] = normal.z;
     colors[idx * 4 + 0] = light3; colors[idx * 4 + 1] = light3; colors[idx * 4 + 2] = light3; colors[idx * 4 + 3] = 255;
     idx++;
 
     vertices[idx * 3 + 0] = v4.x; vertices[idx * 3 + 1] = v4.y; vertices[idx * 3 + 2] = v4.z;
-    texcoords[idx * 2 + 0] = tex.u_min; texcoords[idx * 2 + 1] = tex.v_max;
+    texcoords[idx * 2 + 0] = uv4[0]; texcoords[idx * 2 + 1] = uv4[1];
     normals[idx * 3 + 0] = normal.x; normals[idx * 3 + 1] = normal.y; normals[idx * 3 + 2] = normal.z;
     colors[idx * 4 + 0] = light4; colors[idx * 4 + 1] = light4; colors[idx * 4 + 2] = light4; colors[idx * 4 + 3] = 255;
     idx++;
@@ -406,6 +461,97 @@ static uint8_t get_block_light(Chunk* chunk, int x, int y, int z) {
     return max_light;
 }
 
+// ============================================================================
+// WATER MESHING
+// ============================================================================
+
+// Vertex shade for water surfaces: open water is darker, so shorelines
+// (vertices touching land) stand out as a lighter rim
+#define WATER_OPEN_SHADE 0.75f
+#define WATER_SHORE_SHADE 1.0f
+
+/**
+ * Check if a water face toward a neighbor should be drawn
+ * Faces between water blocks and faces against opaque blocks are hidden.
+ * Sides at the chunk edge are skipped too: the neighbor chunk is unknown
+ * here and is nearly always more water or the shore.
+ */
+static bool water_face_visible(Chunk* chunk, int x, int y, int z) {
+    if (x < 0 || x >= CHUNK_SIZE || z < 0 || z >= CHUNK_SIZE) return false;
+    if (y < 0) return false;
+    if (y >= CHUNK_HEIGHT) return true;
+
+    Block neighbor = chunk_get_block(chunk, x, y, z);
+    if (block_is_fluid(neighbor)) return false;
+    return !block_is_solid(neighbor) || block_is_transparent(neighbor);
+}
+
+/**
+ * Shade for one corner of a water surface
+ * The corner is on the shore if any of the 3 blocks around it is solid land.
+ */
+static float water_corner_shade(Chunk* chunk, int x, int y, int z, int dx, int dz) {
+    bool shore = has_solid_block_at(chunk, x + dx, y, z) ||
+                 has_solid_block_at(chunk, x, y, z + dz) ||
+                 has_solid_block_at(chunk, x + dx, y, z + dz);
+    return shore ? WATER_SHORE_SHADE : WATER_OPEN_SHADE;
+}
+
+/**
+ * Add the visible faces of a water block
+ * The texture animation is done in the block shader, so the mesh only
+ * changes when the water itself does.
+ */
+static void add_water_faces(Chunk* chunk, int x, int y, int z, BlockType type, uint8_t light,
+                            float* vertices, float* texcoords, float* normals,
+                            unsigned char* colors, int* vertex_count) {
+    float wx = (float)x;
+    float wy = (float)y;
+    float wz = (float)z;
+    float open = WATER_OPEN_SHADE;
+
+    if (water_face_visible(chunk, x, y + 1, z)) {
+        float s1 = water_corner_shade(chunk, x, y, z, -1, -1);
+        float s2 = water_corner_shade(chunk, x, y, z, 1, -1);
+        float s3 = water_corner_shade(chunk, x, y, z, 1, 1);
+        float s4 = water_corner_shade(chunk, x, y, z, -1, 1);
+        add_quad(vertices, texcoords, normals, colors, vertex_count,
+                 (Vector3){wx, wy + 1, wz}, (Vector3){wx + 1, wy + 1, wz},
+                 (Vector3){wx + 1, wy + 1, wz + 1}, (Vector3){wx, wy + 1, wz + 1},
+                 (Vector3){0, 1, 0}, type, 0, 1, 1, light, s1, s2, s3, s4);
+    }
+    if (water_face_visible(chunk, x, y - 1, z)) {
+        add_quad(vertices, texcoords, normals, colors, vertex_count,
+                 (Vector3){wx, wy, wz + 1}, (Vector3){wx + 1, wy, wz + 1},
+                 (Vector3){wx + 1, wy, wz}, (Vector3){wx, wy, wz},
+                 (Vector3){0, -1, 0}, type, 0, 1, 1, light, open, open, open, open);
+    }
+    if (water_face_visible(chunk, x, y, z - 1)) {
+        add_quad(vertices, texcoords, normals, colors, vertex_count,
+                 (Vector3){wx, wy, wz}, (Vector3){wx + 1, wy, wz},
+                 (Vector3){wx + 1, wy + 1, wz}, (Vector3){wx, wy + 1, wz},
+                 (Vector3){0, 0, -1}, type, 0, 1, 1, light, open, open, open, open);
+    }
+    if (water_face_visible(chunk, x, y, z + 1)) {
+        add_quad(vertices, texcoords, normals, colors, vertex_count,
+                 (Vector3){wx + 1, wy, wz + 1}, (Vector3){wx, wy, wz + 1},
+                 (Vector3){wx, wy + 1, wz + 1}, (Vector3){wx + 1, wy + 1, wz + 1},
+                 (Vector3){0, 0, 1}, type, 0, 1, 1, light, open, open, open, open);
+    }
+    if (water_face_visible(chunk, x - 1, y, z)) {
+        add_quad(vertices, texcoords, normals, colors, vertex_count,
+                 (Vector3){wx, wy, wz + 1}, (Vector3){wx, wy, wz},
+                 (Vector3){wx, wy + 1, wz}, (Vector3){wx, wy + 1, wz + 1},
+                 (Vector3){-1, 0, 0}, type, 0, 1, 1, light, open, open, open, open);
+    }
+    if (water_face_visible(chunk, x + 1, y, z)) {
+        add_quad(vertices, texcoords, normals, colors, vertex_count,
+                 (Vector3){wx + 1, wy, wz}, (Vector3){wx + 1, wy, wz + 1},
+                 (Vector3){wx + 1, wy + 1, wz + 1}, (Vector3){wx + 1, wy + 1, wz},
+                 (Vector3){1, 0, 0}, type, 0, 1, 1, light, open, open, open, open);
+    }
+}
+
 /**
  * Simple meshing algorithm - each block face is independent (Luanti-style)
  * Does not merge adjacent faces, rendering each block separately
@@ -426,6 +572,15 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
             for (int x = 0; x < CHUNK_SIZE; x++) {
                 Block block = chunk_get_block(chunk, x, y, z);
 
+                // Water is drawn with the transparent pass
+                if (block_is_fluid(block)) {
+                    if (transparent_pass) {
+                        add_water_faces(chunk, x, y, z, block.type, get_block_light(chunk, x, y, z),
+                                        *vertices, *texcoords, *normals, *colors, vertex_count);
+                    }
+                    continue;
+                }
+
                 // Skip air blocks
                 if (!block_is_solid(block)) {
                     continue;
@@ -446,6 +601,9 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
                 // Get consistent light for this block (same for all faces)
                 uint8_t block_light = get_block_light(chunk, x, y, z);
 
+                // Per-position texture variation for top and bottom faces
+                int variant = get_texture_variant(chunk, x, y, z, block.type);
+
                 // Only render faces adjacent to air/transparent blocks (culling)
                 // For transparent blocks like leaves, we can see through them so render adjacent faces
 
@@ -463,7 +621,7 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
                     float ao3 = calculate_vertex_ao(chunk, x, y+1, z, 1,0,0, 0,0,1);    // v3: corner (+X, +Z)
                     float ao4 = calculate_vertex_ao(chunk, x, y+1, z, -1,0,0, 0,0,1);   // v4: corner (-X, +Z)
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao1, ao2, ao3, ao4);
+                            v1, v2, v3, v4, normal, block.type, variant, 1, 1, block_light, ao1, ao2, ao3, ao4);
                 }
 
                 // Face: Bottom (-Y) - render if neighbor is air or transparent
@@ -480,7 +638,7 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
                     float ao3 = calculate_vertex_ao(chunk, x, y-1, z, 1,0,0, 0,0,-1);   // v3: corner (+X, -Z)
                     float ao4 = calculate_vertex_ao(chunk, x, y-1, z, -1,0,0, 0,0,-1);  // v4: corner (-X, -Z)
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao1, ao2, ao3, ao4);
+                            v1, v2, v3, v4, normal, block.type, variant, 1, 1, block_light, ao1, ao2, ao3, ao4);
                 }
 
                 // Face: Front (-Z) - render if neighbor is air or transparent
@@ -497,7 +655,7 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
                     float ao3 = calculate_vertex_ao(chunk, x, y, z-1, 1,0,0, 0,1,0);    // v3: corner (+X, +Y)
                     float ao4 = calculate_vertex_ao(chunk, x, y, z-1, -1,0,0, 0,1,0);   // v4: corner (-X, +Y)
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao1, ao2, ao3, ao4);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao1, ao2, ao3, ao4);
                 }
 
                 // Face: Back (+Z) - render if neighbor is air or transparent
@@ -514,7 +672,7 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
                     float ao3 = calculate_vertex_ao(chunk, x, y, z+1, -1,0,0, 0,1,0);   // v3: corner (-X, +Y)
                     float ao4 = calculate_vertex_ao(chunk, x, y, z+1, 1,0,0, 0,1,0);    // v4: corner (+X, +Y)
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao1, ao2, ao3, ao4);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao1, ao2, ao3, ao4);
                 }
 
                 // Face: Left (-X) - render if neighbor is air or transparent
@@ -531,7 +689,7 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
                     float ao3 = calculate_vertex_ao(chunk, x-1, y, z, 0,0,-1, 0,1,0);   // v3: corner (-Z, +Y)
                     float ao4 = calculate_vertex_ao(chunk, x-1, y, z, 0,0,1, 0,1,0);    // v4: corner (+Z, +Y)
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao1, ao2, ao3, ao4);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao1, ao2, ao3, ao4);
                 }
 
                 // Face: Right (+X) - render if neighbor is air or transparent
@@ -548,7 +706,7 @@ static void chunk_generate_mesh_simple(Chunk* chunk, float** vertices, float** t
                     float ao3 = calculate_vertex_ao(chunk, x+1, y, z, 0,0,1, 0,1,0);    // v3: corner (+Z, +Y)
                     float ao4 = calculate_vertex_ao(chunk, x+1, y, z, 0,0,-1, 0,1,0);   // v4: corner (-Z, +Y)
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao1, ao2, ao3, ao4);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao1, ao2, ao3, ao4);
                 }
             }
         }
@@ -632,7 +790,7 @@ static void chunk_generate_mesh_lod(Chunk* chunk, float** vertices, float** texc
                     Vector3 v4 = {wx, wy + 1, wz + block_size};
                     Vector3 normal = {0, 1, 0};
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao, ao, ao, ao);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao, ao, ao, ao);
                 }
 
                 // Bottom face
@@ -657,7 +815,7 @@ static void chunk_generate_mesh_lod(Chunk* chunk, float** vertices, float** texc
                     Vector3 v4 = {wx, wy, wz};
                     Vector3 normal = {0, -1, 0};
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao, ao, ao, ao);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao, ao, ao, ao);
                 }
 
                 // Front face (-Z) - only if at z=0 edge of 2x2 area
@@ -668,7 +826,7 @@ static void chunk_generate_mesh_lod(Chunk* chunk, float** vertices, float** texc
                     Vector3 v4 = {wx, wy + 1, wz};
                     Vector3 normal = {0, 0, -1};
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao, ao, ao, ao);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao, ao, ao, ao);
                 }
 
                 // Back face (+Z) - only if at z+2 edge or neighbor is not solid
@@ -681,7 +839,7 @@ static void chunk_generate_mesh_lod(Chunk* chunk, float** vertices, float** texc
                     Vector3 v4 = {wx + block_size, wy + 1, wz + block_size};
                     Vector3 normal = {0, 0, 1};
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao, ao, ao, ao);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao, ao, ao, ao);
                 }
 
                 // Left face (-X) - only if at x=0 edge
@@ -692,7 +850,7 @@ static void chunk_generate_mesh_lod(Chunk* chunk, float** vertices, float** texc
                     Vector3 v4 = {wx, wy + 1, wz + block_size};
                     Vector3 normal = {-1, 0, 0};
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao, ao, ao, ao);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao, ao, ao, ao);
                 }
 
                 // Right face (+X) - only if at x+2 edge or neighbor is not solid
@@ -705,7 +863,7 @@ static void chunk_generate_mesh_lod(Chunk* chunk, float** vertices, float** texc
                     Vector3 v4 = {wx + block_size, wy + 1, wz};
                     Vector3 normal = {1, 0, 0};
                     add_quad(*vertices, *texcoords, *normals, *colors, vertex_count,
-                            v1, v2, v3, v4, normal, block.type, 1, 1, block_light, ao, ao, ao, ao);
+                            v1, v2, v3, v4, normal, block.type, 0, 1, 1, block_light, ao, ao, ao, ao);
                 }
             }
         }
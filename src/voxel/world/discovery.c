@@ -0,0 +1,131 @@
+/**
+ * Map Discovery Implementation
+ */
+
+#include "voxel/world/discovery.h"
+#include "voxel/world/world.h"
+#include "voxel/world/chunk.h"
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+#include <math.h>
+
+/**
+ * Discovered columns of one chunk (bit x of rows[z] = column revealed)
+ */
+typedef struct {
+    int chunk_x;
+    int chunk_z;
+    uint16_t rows[CHUNK_SIZE];
+    bool used;
+} DiscoveredChunk;
+
+// Open-addressing hash table keyed by chunk coordinates
+static DiscoveredChunk g_chunks[DISCOVERY_MAX_CHUNKS];
+static int g_chunk_count = 0;
+static bool g_full_warned = false;
+
+// Last revealed player column (reveal only when it changes)
+static int g_last_x = 0;
+static int g_last_z = 0;
+static bool g_has_last = false;
+
+// ============================================================================
+// HASH TABLE
+// ============================================================================
+
+static unsigned int hash_chunk(int chunk_x, int chunk_z) {
+    unsigned int h = (unsigned int)chunk_x * 374761393u + (unsigned int)chunk_z * 668265263u;
+    h = (h ^ (h >> 13)) * 1274126177u;
+    return (h ^ (h >> 16)) % DISCOVERY_MAX_CHUNKS;
+}
+
+/**
+ * Find a chunk's entry, optionally creating it
+ * @return Entry, or NULL if missing (or the table is full when creating)
+ */
+static DiscoveredChunk* find_chunk(int chunk_x, int chunk_z, bool create) {
+    unsigned int slot = hash_chunk(chunk_x, chunk_z);
+
+    for (int probe = 0; probe < DISCOVERY_MAX_CHUNKS; probe++) {
+        DiscoveredChunk* entry = &g_chunks[slot];
+        if (!entry->used) {
+            if (!create) return NULL;
+            if (g_chunk_count >= DISCOVERY_MAX_CHUNKS - 1) {
+                // Keep one slot free so lookups always terminate
+                if (!g_full_warned) {
+                    printf("[DISCOVERY] Table full, new areas stay fogged\n");
+                    g_full_warned = true;
+                }
+                return NULL;
+            }
+            memset(entry, 0, sizeof(*entry));
+            entry->chunk_x = chunk_x;
+            entry->chunk_z = chunk_z;
+            entry->used = true;
+            g_chunk_count++;
+            return entry;
+        }
+        if (entry->chunk_x == chunk_x && entry->chunk_z == chunk_z) {
+            return entry;
+        }
+        slot = (slot + 1) % DISCOVERY_MAX_CHUNKS;
+    }
+    return NULL;
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+void discovery_clear(void) {
+    memset(g_chunks, 0, sizeof(g_chunks));
+    g_chunk_count = 0;
+    g_full_warned = false;
+    g_has_last = false;
+}
+
+void discovery_reveal(int x, int z, int radius) {
+    int radius_sq = radius * radius;
+
+    for (int dz = -radius; dz <= radius; dz++) {
+        for (int dx = -radius; dx <= radius; dx++) {
+            if (dx * dx + dz * dz > radius_sq) continue;
+
+            int world_x = x + dx;
+            int world_z = z + dz;
+            int chunk_x, chunk_z;
+            world_to_chunk_coords(world_x, world_z, &chunk_x, &chunk_z);
+
+            DiscoveredChunk* entry = find_chunk(chunk_x, chunk_z, true);
+            if (!entry) continue;
+
+            int local_x = world_x - chunk_x * CHUNK_SIZE;
+            int local_z = world_z - chunk_z * CHUNK_SIZE;
+            entry->rows[local_z] |= (uint16_t)(1u << local_x);
+        }
+    }
+}
+
+void discovery_update(float player_x, float player_z, int radius) {
+    int x = (int)floorf(player_x);
+    int z = (int)floorf(player_z);
+    if (g_has_last && x == g_last_x && z == g_last_z) return;
+
+    discovery_reveal(x, z, radius);
+    g_last_x = x;
+    g_last_z = z;
+    g_has_last = true;
+}
+
+bool discovery_is_discovered(int x, int z) {
+    int chunk_x, chunk_z;
+    world_to_chunk_coords(x, z, &chunk_x, &chunk_z);
+
+    DiscoveredChunk* entry = find_chunk(chunk_x, chunk_z, false);
+    if (!entry) return false;
+
+    int local_x = x - chunk_x * CHUNK_SIZE;
+    int local_z = z - chunk_z * CHUNK_SIZE;
+    return (entry->rows[local_z] >> local_x) & 1u;
+}
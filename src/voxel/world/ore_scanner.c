@@ -0,0 +1,133 @@
+/**
+ * Ore Scanner Implementation
+ */
+
+#include "voxel/world/ore_scanner.h"
+#include "voxel/world/world.h"
+#include "voxel/world/chunk.h"
+#include <rlgl.h>
+#include <math.h>
+#include <stdio.h>
+
+static OreScanResult g_results[ORE_SCANNER_MAX_RESULTS];
+static int g_result_count = 0;
+static bool g_active = false;
+static float g_scan_timer = 0.0f;
+
+// ============================================================================
+// ORE TYPES
+// ============================================================================
+
+bool ore_scanner_is_ore(BlockType type) {
+    switch (type) {
+        case BLOCK_COAL_ORE:
+        case BLOCK_IRON_ORE:
+        case BLOCK_GOLD_ORE:
+        case BLOCK_DIAMOND_ORE:
+            return true;
+        default:
+            return false;
+    }
+}
+
+Color ore_scanner_get_color(BlockType type) {
+    switch (type) {
+        case BLOCK_COAL_ORE:    return (Color){90, 90, 90, 255};     // Charcoal gray
+        case BLOCK_IRON_ORE:    return (Color){220, 170, 120, 255};  // Iron tan
+        case BLOCK_GOLD_ORE:    return (Color){255, 215, 0, 255};    // Gold
+        case BLOCK_DIAMOND_ORE: return (Color){0, 255, 255, 255};    // Cyan
+        default:                return WHITE;
+    }
+}
+
+// ============================================================================
+// SCANNING
+// ============================================================================
+
+void ore_scanner_clear(void) {
+    g_result_count = 0;
+    g_active = false;
+    g_scan_timer = 0.0f;
+}
+
+int ore_scanner_scan(World* world, int center_x, int center_y, int center_z) {
+    g_result_count = 0;
+    if (!world) return 0;
+
+    int min_y = center_y - ORE_SCANNER_RADIUS;
+    int max_y = center_y + ORE_SCANNER_RADIUS;
+    if (min_y < 0) min_y = 0;
+    if (max_y > CHUNK_HEIGHT - 1) max_y = CHUNK_HEIGHT - 1;
+
+    for (int y = min_y; y <= max_y; y++) {
+        for (int z = center_z - ORE_SCANNER_RADIUS; z <= center_z + ORE_SCANNER_RADIUS; z++) {
+            for (int x = center_x - ORE_SCANNER_RADIUS; x <= center_x + ORE_SCANNER_RADIUS; x++) {
+                Block block = world_get_block(world, x, y, z);
+                if (!ore_scanner_is_ore((BlockType)block.type)) continue;
+
+                if (g_result_count >= ORE_SCANNER_MAX_RESULTS) return g_result_count;
+                g_results[g_result_count++] = (OreScanResult){x, y, z, (BlockType)block.type};
+            }
+        }
+    }
+
+    return g_result_count;
+}
+
+void ore_scanner_update(World* world, Vector3 position, bool active, float dt) {
+    if (!active) {
+        if (g_active) ore_scanner_clear();
+        return;
+    }
+
+    // Scan right away when the scanner is first picked up
+    if (!g_active) {
+        g_active = true;
+        g_scan_timer = 0.0f;
+    }
+
+    g_scan_timer -= dt;
+    if (g_scan_timer > 0.0f) return;
+    g_scan_timer = ORE_SCANNER_INTERVAL;
+
+    ore_scanner_scan(world, (int)floorf(position.x), (int)floorf(position.y), (int)floorf(position.z));
+}
+
+bool ore_scanner_is_active(void) {
+    return g_active;
+}
+
+int ore_scanner_get_count(void) {
+    return g_result_count;
+}
+
+const OreScanResult* ore_scanner_get(int index) {
+    if (index < 0 || index >= g_result_count) return NULL;
+    return &g_results[index];
+}
+
+// ============================================================================
+// RENDERING
+// ============================================================================
+
+void ore_scanner_draw_3d(World* world) {
+    if (!g_active || !world) return;
+
+    // Markers show through terrain
+    rlDrawRenderBatchActive();
+    rlDisableDepthTest();
+
+    for (int i = 0; i < g_result_count; i++) {
+        const OreScanResult* ore = &g_results[i];
+
+        // Skip ores mined since the last scan
+        Block block = world_get_block(world, ore->x, ore->y, ore->z);
+        if (block.type != ore->type) continue;
+
+        Vector3 center = {ore->x + 0.5f, ore->y + 0.5f, ore->z + 0.5f};
+        DrawCubeWires(center, 1.0f, 1.0f, 1.0f, ore_scanner_get_color(ore->type));
+    }
+
+    rlDrawRenderBatchActive();
+    rlEnableDepthTest();
+}
@@ -7,7 +7,11 @@
 #include "voxel/world/spawn.h"
 #include "voxel/entity/sheep.h"
 #include "voxel/entity/pig.h"
+#include "voxel/entity/zombie.h"
+#include "voxel/entity/pathfinding.h"
 #include "voxel/world/chunk.h"
+#include "voxel/world/world.h"
+#include "voxel/player/player.h"
 #include <stdlib.h>
 #include <stdio.h>
 #include <math.h>
@@ -68,18 +72,13 @@ static unsigned int hash_chunk_coords(int x, int z) {
     return h ^ (h >> 16);
 }
 
-// Random float [0, 1) using current rand state
-static float random_float(void) {
-    return (float)rand() / (float)RAND_MAX;
-}
-
 // Gaussian-ish distribution using Box-Muller (simplified)
 // Returns value roughly in [-2, 2] with most values near 0
-static float random_gaussian(void) {
+static float random_gaussian(GameRng* rng) {
     // Use sum of uniform randoms for approximate gaussian
     float sum = 0.0f;
     for (int i = 0; i < 3; i++) {
-        sum += random_float();
+        sum += rng_float(rng);
     }
     return (sum / 3.0f - 0.5f) * 4.0f;  // Center around 0, scale
 }
@@ -89,18 +88,23 @@ static float random_gaussian(void) {
 // ============================================================================
 
 void spawn_herd(EntityManager* manager, EntityType type, Vector3 center,
-                int count, float radius, TerrainParams params) {
-    if (!manager) return;
+                int count, float radius, TerrainParams params, GameRng* rng) {
+    if (!manager || !rng) return;
 
     for (int i = 0; i < count; i++) {
         // Spread animals around herd center using gaussian distribution
         // This clusters animals near the center for natural herding
-        float angle = random_float() * 2.0f * 3.14159f;
-        float dist = fabsf(random_gaussian()) * radius * 0.4f;
+        float angle = rng_float(rng) * 2.0f * 3.14159f;
+        float dist = fabsf(random_gaussian(rng)) * radius * 0.4f;
 
         float x = center.x + cosf(angle) * dist;
         float z = center.z + sinf(angle) * dist;
-        float y = (float)terrain_get_height_at((int)x, (int)z, params) + 1.0f;
+        int surface_y = terrain_get_height_at((int)x, (int)z, params);
+
+        // Don't drop animals into rivers or lakes
+        if (params.generate_rivers && surface_y < params.water_level) continue;
+
+        float y = (float)surface_y + 1.0f;
 
         Vector3 pos = { x + 0.5f, y, z + 0.5f };
 
@@ -116,9 +120,9 @@ void spawn_herd(EntityManager* manager, EntityType type, Vector3 center,
                 {50, 150, 50, 255},    // Green
                 {50, 50, 200, 255},    // Blue
             };
-            int color_idx = rand() % 7;  // Weight towards white (will be adjusted)
+            int color_idx = rng_int(rng, 7);  // Weight towards white (will be adjusted)
             // Bias towards white (50% chance of white)
-            if (rand() % 2 == 0) color_idx = 0;
+            if (rng_int(rng, 2) == 0) color_idx = 0;
 
             entity = sheep_spawn_colored(manager, pos, wool_colors[color_idx]);
         } else if (type == ENTITY_TYPE_PIG) {
@@ -155,30 +159,107 @@ void spawn_animals_for_chunk(struct World* world, int chunk_x, int chunk_z, Terr
     // No animals in this biome?
     if (rules->herd_rule_count == 0) return;
 
-    // Use deterministic seed for this chunk (reproducible world)
-    unsigned int seed = hash_chunk_coords(chunk_x, chunk_z);
-    srand(seed);
+    // Own generator per chunk, so herds depend only on the world seed and
+    // chunk position, not on the order chunks finish loading
+    GameRng chunk_rng;
+    rng_seed(&chunk_rng, ((uint64_t)world->seed << 32) | hash_chunk_coords(chunk_x, chunk_z));
 
     // Try spawning each herd type
     for (int i = 0; i < rules->herd_rule_count; i++) {
         const HerdSpawnRule* herd = &rules->herd_rules[i];
 
         // Roll for spawn chance
-        if (random_float() < herd->spawn_chance) {
+        if (rng_float(&chunk_rng) < herd->spawn_chance) {
             // Pick random position within chunk
-            float herd_x = (float)world_x + (random_float() - 0.5f) * CHUNK_SIZE;
-            float herd_z = (float)world_z + (random_float() - 0.5f) * CHUNK_SIZE;
+            float herd_x = (float)world_x + (rng_float(&chunk_rng) - 0.5f) * CHUNK_SIZE;
+            float herd_z = (float)world_z + (rng_float(&chunk_rng) - 0.5f) * CHUNK_SIZE;
             float herd_y = (float)terrain_get_height_at((int)herd_x, (int)herd_z, params) + 1.0f;
 
             Vector3 center = { herd_x, herd_y, herd_z };
 
             // Determine herd size
             int size_range = herd->max_herd_size - herd->min_herd_size + 1;
-            int count = herd->min_herd_size + (rand() % size_range);
+            int count = herd->min_herd_size + rng_int(&chunk_rng, size_range);
+
+            // Respect species population cap
+            int room = spawn_get_population_cap(herd->animal_type) -
+                       entity_manager_count_type(manager, herd->animal_type);
+            if (room <= 0) continue;
+            if (count > room) count = room;
 
             // Spawn the herd
-            spawn_herd(manager, herd->animal_type, center, count, herd->herd_radius, params);
+            spawn_herd(manager, herd->animal_type, center, count, herd->herd_radius, params, &chunk_rng);
+        }
+    }
+}
+
+// ============================================================================
+// NIGHT SPAWNING
+// ============================================================================
+
+static float night_spawn_timer = 0.0f;
+
+int spawn_get_night_cap(float time_of_day) {
+    if (!world_is_night(time_of_day)) return 0;
+
+    // Hours since dusk (20:00), 0-9; depth peaks at 1.0 at 00:30
+    float hours = time_of_day >= 20.0f ? time_of_day - 20.0f : time_of_day + 4.0f;
+    float depth = 1.0f - fabsf(hours - 4.5f) / 4.5f;
+
+    return SPAWN_CAP_ZOMBIE_MIN +
+           (int)((SPAWN_CAP_ZOMBIE_MAX - SPAWN_CAP_ZOMBIE_MIN) * depth + 0.5f);
+}
+
+/**
+ * Remove and free zombies that despawned themselves
+ * Other entity types are owned elsewhere and left alone
+ */
+static void remove_despawned_zombies(EntityManager* manager) {
+    Entity* entity = manager->entities;
+    while (entity) {
+        Entity* next = entity->next;
+        if (!entity->active && entity->type == ENTITY_TYPE_ZOMBIE) {
+            entity_manager_remove(manager, entity);
+            entity_destroy(entity);
         }
+        entity = next;
+    }
+}
+
+void spawn_night_mobs(struct World* world, float dt) {
+    if (!world || !world->player) return;
+
+    EntityManager* manager = world_get_entity_manager(world);
+    if (!manager) return;
+
+    remove_despawned_zombies(manager);
+
+    night_spawn_timer -= dt;
+    if (night_spawn_timer > 0.0f) return;
+    night_spawn_timer = SPAWN_NIGHT_INTERVAL;
+
+    int cap = spawn_get_night_cap(world->time_of_day);
+    if (entity_manager_count_type(manager, ENTITY_TYPE_ZOMBIE) >= cap) return;
+
+    // Pick a ring position around the player, away from them
+    Vector3 player_pos = world->player->position;
+    float angle = rng_float(&world->rng) * 2.0f * 3.14159f;
+    float dist = rng_range(&world->rng, SPAWN_NIGHT_MIN_DISTANCE, SPAWN_NIGHT_MAX_DISTANCE);
+    int x = (int)floorf(player_pos.x + cosf(angle) * dist);
+    int z = (int)floorf(player_pos.z + sinf(angle) * dist);
+    int y = terrain_get_height_at(x, z, world->terrain_params) + 1;
+
+    // Needs loaded, dry ground with room to stand (skips water and unloaded chunks)
+    if (!pathfinding_is_walkable(world, x, y, z)) return;
+
+    zombie_spawn(manager, (Vector3){x + 0.5f, (float)y, z + 0.5f});
+}
+
+int spawn_get_population_cap(EntityType type) {
+    switch (type) {
+        case ENTITY_TYPE_SHEEP: return SPAWN_CAP_SHEEP;
+        case ENTITY_TYPE_PIG: return SPAWN_CAP_PIG;
+        default: return 0;
     }
 }
 
@@ -47,6 +47,10 @@ TerrainParams terrain_default_params(void) {
     params.bedrock_start = 8;        // Bedrock mixing at y=8
     params.bedrock_solid = 4;        // Solid bedrock at y=4
 
+    // Abundance multipliers
+    params.tree_density = 1.0f;
+    params.ore_abundance = 1.0f;
+
     // Ore generation - Coal (upper stone layer)
     params.coal_frequency = 0.10f;
     params.coal_min_y = 100;
@@ -108,6 +112,14 @@ TerrainParams terrain_default_params(void) {
     params.water_pool_max_y = 80;       // Water only below y=80
     params.water_pool_frequency = 0.15f; // 15% chance at valid low points
 
+    // Rivers and lakes
+    params.generate_rivers = true;
+    params.water_level = 150;           // Lakes fill anything below y=150
+    params.river_frequency = 0.002f;    // Long winding rivers
+    params.river_width = 0.035f;        // Channel width
+    params.river_bank_width = 0.12f;    // Valley slopes down to the channel
+    params.river_depth = 4;             // Bed 4 blocks below water level
+
     return params;
 }
 
@@ -115,6 +127,8 @@ TerrainParams terrain_default_params(void) {
 // TERRAIN GENERATION
 // ============================================================================
 
+#define RIVER_NOISE_OFFSET 20000.0f  // Offset from terrain and biome noise
+
 /**
  * Get blended height scale at world coordinates
  * Samples nearby biomes and blends their height_scale values
@@ -150,6 +164,45 @@ static float terrain_get_blended_height_scale(int world_x, int world_z) {
     return total_scale / total_weight;
 }
 
+/**
+ * Carve river valleys into a terrain height
+ * Rivers follow the zero contour of a low-frequency noise field, so they
+ * form continuous channels across chunk borders. Terrain slopes down to
+ * water level along the banks and dips below it in the channel.
+ */
+static float terrain_apply_river(int world_x, int world_z, float height, TerrainParams params) {
+    if (!params.generate_rivers) return height;
+
+    float river_noise = fabsf(noise_fbm_2d(
+        (float)world_x + RIVER_NOISE_OFFSET,
+        (float)world_z + RIVER_NOISE_OFFSET,
+        2,
+        params.river_frequency,
+        1.0f,
+        2.0f,
+        0.5f
+    ));
+
+    float water = (float)params.water_level;
+
+    // Channel: bed deepest at the center, rising to water level at the edge
+    if (river_noise < params.river_width) {
+        float t = river_noise / params.river_width;
+        float bed = water - (float)params.river_depth * (1.0f - t * t);
+        return (height < bed) ? height : bed;
+    }
+
+    // Banks: blend down towards water level (never raises low ground)
+    float bank_end = params.river_width + params.river_bank_width;
+    if (river_noise < bank_end && height > water) {
+        float t = (river_noise - params.river_width) / params.river_bank_width;
+        t = t * t * (3.0f - 2.0f * t);  // Smoothstep
+        return water + (height - water) * t;
+    }
+
+    return height;
+}
+
 /**
  * Get terrain height at world coordinates
  * Applies biome-specific height scaling for varied terrain
@@ -170,6 +223,7 @@ int terrain_get_height_at(int world_x, int world_z, TerrainParams params) {
 
     // Convert noise (-1 to 1) to height with biome scaling
     float height = params.height_offset + (noise_value * params.height_scale * biome_scale);
+    height = terrain_apply_river(world_x, world_z, height, params);
     return (int)height;
 }
 
@@ -213,13 +267,19 @@ static bool is_cave(int world_x, int world_y, int world_z, int terrain_height, T
 static BlockType get_terrain_block(int world_x, int world_y, int world_z, int terrain_height, TerrainParams params, BiomeType biome) {
     const BiomeProperties* bp = biome_get_properties(biome);
 
-    // 1. Air above terrain
+    // 1. Air above terrain (water up to water level in rivers and lakes)
     if (world_y > terrain_height) {
+        if (params.generate_rivers && world_y <= params.water_level) {
+            return BLOCK_WATER;
+        }
         return BLOCK_AIR;
     }
 
-    // 2. Surface block (biome-specific: grass, sand, snow)
+    // 2. Surface block (biome-specific: grass, sand, snow; sand under water)
     if (world_y == terrain_height) {
+        if (params.generate_rivers && terrain_height < params.water_level) {
+            return BLOCK_SAND;
+        }
         return bp->surface_block;
     }
 
@@ -273,7 +333,7 @@ static BlockType get_terrain_block(int world_x, int world_y, int world_z, int te
                 (float)world_y * 0.2f,
                 (float)world_z * 0.2f
             );
-            if (ore_noise > (1.0f - params.diamond_frequency)) {
+            if (ore_noise > (1.0f - params.diamond_frequency * params.ore_abundance)) {
                 return BLOCK_DIAMOND_ORE;
             }
         }
@@ -285,7 +345,7 @@ static BlockType get_terrain_block(int world_x, int world_y, int world_z, int te
                 (float)world_y * 0.2f,
                 (float)world_z * 0.2f
             );
-            if (ore_noise > (1.0f - params.gold_frequency)) {
+            if (ore_noise > (1.0f - params.gold_frequency * params.ore_abundance)) {
                 return BLOCK_GOLD_ORE;
             }
         }
@@ -325,7 +385,7 @@ static BlockType get_terrain_block(int world_x, int world_y, int world_z, int te
             (float)world_y * 0.2f,
             (float)world_z * 0.2f
         );
-        if (ore_noise > (1.0f - params.iron_frequency)) {
+        if (ore_noise > (1.0f - params.iron_frequency * params.ore_abundance)) {
             return BLOCK_IRON_ORE;
         }
     }
@@ -337,7 +397,7 @@ static BlockType get_terrain_block(int world_x, int world_y, int world_z, int te
             (float)world_y * 0.2f,
             (float)world_z * 0.2f
         );
-        if (ore_noise > (1.0f - params.coal_frequency)) {
+        if (ore_noise > (1.0f - params.coal_frequency * params.ore_abundance)) {
             return BLOCK_COAL_ORE;
         }
     }
@@ -1092,7 +1152,7 @@ void terrain_generate_chunk(Chunk* chunk, TerrainParams params) {
     generate_cave_water(chunk, params, avg_terrain_height);
 
     // Generate trees on the terrain
-    tree_generate_for_chunk(chunk);
+    tree_generate_for_chunk(chunk, params.tree_density);
 
     // Calculate skylight propagation (must be after all blocks are placed)
     light_calculate_chunk(chunk);
@@ -0,0 +1,131 @@
+/**
+ * Weather System Implementation
+ */
+
+#include "voxel/world/weather.h"
+#include <raylib.h>
+#include <stdio.h>
+
+#define WEATHER_RAIN_DROPS 160          // Rain streaks on screen at full rain
+
+// ============================================================================
+// FORECAST
+// ============================================================================
+
+/**
+ * Choose what follows the current weather and how long it lasts
+ * Rain and fog always clear up before the next spell.
+ */
+static void pick_next_weather(Weather* weather) {
+    if (weather->next == WEATHER_CLEAR) {
+        weather->next = (rng_float(&weather->rng) < WEATHER_RAIN_CHANCE) ? WEATHER_RAIN : WEATHER_FOG;
+        weather->timer = rng_range(&weather->rng, WEATHER_SPELL_TIME_MIN, WEATHER_SPELL_TIME_MAX);
+    } else {
+        weather->next = WEATHER_CLEAR;
+        weather->timer = rng_range(&weather->rng, WEATHER_CLEAR_TIME_MIN, WEATHER_CLEAR_TIME_MAX);
+    }
+    printf("[WEATHER] %s for %.0f s\n", weather_get_name(weather->next), weather->timer);
+}
+
+// ============================================================================
+// API
+// ============================================================================
+
+void weather_init(Weather* weather, uint32_t seed) {
+    if (!weather) return;
+
+    weather->type = WEATHER_CLEAR;
+    weather->next = WEATHER_CLEAR;
+    weather->intensity = 0.0f;
+    rng_seed(&weather->rng, seed);
+    weather->timer = rng_range(&weather->rng, WEATHER_CLEAR_TIME_MIN, WEATHER_CLEAR_TIME_MAX);
+}
+
+void weather_update(Weather* weather, float dt) {
+    if (!weather) return;
+
+    weather->timer -= dt;
+    if (weather->timer <= 0.0f) {
+        pick_next_weather(weather);
+    }
+
+    // Fade the old weather out before the new one fades in
+    float fade = dt / WEATHER_FADE_TIME;
+    if (weather->type != weather->next) {
+        weather->intensity -= fade;
+        if (weather->intensity <= 0.0f) {
+            weather->intensity = 0.0f;
+            weather->type = weather->next;
+        }
+    } else if (weather->type != WEATHER_CLEAR) {
+        weather->intensity += fade;
+        if (weather->intensity > 1.0f) weather->intensity = 1.0f;
+    }
+}
+
+float weather_get_rain(const Weather* weather) {
+    if (!weather || weather->type != WEATHER_RAIN) return 0.0f;
+    return weather->intensity;
+}
+
+float weather_get_fog(const Weather* weather) {
+    if (!weather || weather->type != WEATHER_FOG) return 0.0f;
+    return weather->intensity;
+}
+
+float weather_get_growth_rate(const Weather* weather) {
+    return 1.0f + WEATHER_RAIN_GROWTH_BONUS * weather_get_rain(weather);
+}
+
+float weather_scale_view_distance(const Weather* weather, float distance) {
+    float fog = weather_get_fog(weather);
+    return distance * (1.0f - (1.0f - WEATHER_FOG_VIEW_SCALE) * fog);
+}
+
+int weather_scale_reveal_radius(const Weather* weather, int radius) {
+    float fog = weather_get_fog(weather);
+    int scaled = (int)(radius * (1.0f - (1.0f - WEATHER_FOG_REVEAL_SCALE) * fog));
+    return scaled < 1 ? 1 : scaled;
+}
+
+const char* weather_get_name(WeatherType type) {
+    switch (type) {
+        case WEATHER_CLEAR: return "Clear";
+        case WEATHER_RAIN:  return "Rain";
+        case WEATHER_FOG:   return "Fog";
+        default:            return "Unknown";
+    }
+}
+
+// ============================================================================
+// RENDERING
+// ============================================================================
+
+void weather_draw_overlay(const Weather* weather, int screen_width, int screen_height, float time) {
+    float rain = weather_get_rain(weather);
+    float fog = weather_get_fog(weather);
+
+    if (fog > 0.0f) {
+        DrawRectangle(0, 0, screen_width, screen_height,
+                      (Color){200, 205, 210, (unsigned char)(70 * fog)});
+    }
+
+    if (rain > 0.0f) {
+        // Slightly darker sky light while it rains
+        DrawRectangle(0, 0, screen_width, screen_height,
+                      (Color){20, 30, 50, (unsigned char)(50 * rain)});
+
+        // Each drop falls down a fixed column at its own speed; positions come
+        // from the drop index, so no per-frame state is needed
+        int drops = (int)(WEATHER_RAIN_DROPS * rain);
+        Color streak = {170, 190, 230, (unsigned char)(140 * rain)};
+        for (int i = 0; i < drops; i++) {
+            unsigned int h = (unsigned int)i * 2654435761u;
+            int x = (int)(h % (unsigned int)(screen_width > 0 ? screen_width : 1));
+            float speed = 600.0f + (float)((h >> 8) % 400u);
+            float offset = (float)((h >> 4) % 1000u);
+            int y = (int)(time * speed + offset) % (screen_height + 40) - 20;
+            DrawLine(x, y, x - 3, y + 16, streak);
+        }
+    }
+}
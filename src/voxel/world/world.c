@@ -12,6 +12,7 @@
 #include "voxel/render/light.h"
 #include "voxel/render/chunk_batcher.h"
 #include "voxel/entity/entity.h"
+#include "voxel/entity/entity_utils.h"
 #include <stdio.h>
 #include <stdlib.h>
 #include <string.h>
@@ -31,14 +32,15 @@ static struct {
     int fog_color;
     int underwater;
     int time;
+    unsigned int shader_id;     // Shader the locations belong to (reloads change it)
     bool initialized;
 } g_shader_locs = {0};
 
 /**
- * Initialize shader uniform location cache (call once after shader is loaded)
+ * Initialize shader uniform location cache (again if the shader was reloaded)
  */
 static void init_shader_loc_cache(Shader shader) {
-    if (g_shader_locs.initialized) return;
+    if (g_shader_locs.initialized && g_shader_locs.shader_id == shader.id) return;
 
     g_shader_locs.ambient = GetShaderLocation(shader, "u_ambient_light");
     g_shader_locs.camera_pos = GetShaderLocation(shader, "u_camera_pos");
@@ -47,6 +49,7 @@ static void init_shader_loc_cache(Shader shader) {
     g_shader_locs.fog_color = GetShaderLocation(shader, "u_fog_color");
     g_shader_locs.underwater = GetShaderLocation(shader, "u_underwater");
     g_shader_locs.time = GetShaderLocation(shader, "u_time");
+    g_shader_locs.shader_id = shader.id;
     g_shader_locs.initialized = true;
 }
 
@@ -201,10 +204,16 @@ World* world_create(TerrainParams terrain_params) {
     world->center_chunk_x = 0;
     world->center_chunk_z = 0;
     world->view_distance = WORLD_VIEW_DISTANCE;
+    world->lod_distance = LOD_DISTANCE_THRESHOLD;
     world->terrain_params = terrain_params;
     world->player = NULL;  // Set by game after player creation
     world->entity_manager = NULL;  // Set by game after entity manager creation
     world->time_of_day = 12.0f;  // Default to noon
+    world->day = 1;
+    world->seed = 0;
+    rng_seed(&world->rng, 0);
+    weather_init(&world->weather, 0);
+    entity_set_rng(&world->rng);  // Mob AI draws from the world's RNG
     world->water_queue = water_queue_create();
     world->game_tick = 0;
     world->chest_registry = chest_registry_create();
@@ -220,9 +229,19 @@ World* world_create(TerrainParams terrain_params) {
     return world;
 }
 
+void world_set_seed(World* world, uint32_t seed) {
+    if (!world) return;
+    world->seed = seed;
+    rng_seed(&world->rng, seed);
+    // Own stream, so the forecast doesn't shift mob and loot rolls
+    weather_init(&world->weather, seed ^ 0x9E3779B9u);
+}
+
 void world_destroy(World* world) {
     if (!world) return;
 
+    entity_set_rng(NULL);
+
     // Stop worker threads first
     if (world->worker) {
         chunk_worker_destroy(world->worker);
@@ -280,6 +299,62 @@ Block world_get_block(World* world, int x, int y, int z) {
     return chunk_get_block(chunk, local_x, local_y, local_z);
 }
 
+bool world_find_nearest_block(World* world, Vector3 center, float radius, BlockType type,
+                              int* out_x, int* out_y, int* out_z) {
+    if (!world) return false;
+
+    int min_x = (int)floorf(center.x - radius);
+    int max_x = (int)floorf(center.x + radius);
+    int min_y = (int)floorf(center.y - radius);
+    int max_y = (int)floorf(center.y + radius);
+    int min_z = (int)floorf(center.z - radius);
+    int max_z = (int)floorf(center.z + radius);
+    if (min_y < 0) min_y = 0;
+    if (max_y >= CHUNK_HEIGHT) max_y = CHUNK_HEIGHT - 1;
+
+    float radius_sq = radius * radius;
+    float best_dist_sq = 0.0f;
+    bool found = false;
+
+    for (int y = min_y; y <= max_y; y++) {
+        for (int z = min_z; z <= max_z; z++) {
+            for (int x = min_x; x <= max_x; x++) {
+                if (world_get_block(world, x, y, z).type != type) continue;
+
+                float dx = x + 0.5f - center.x;
+                float dy = y + 0.5f - center.y;
+                float dz = z + 0.5f - center.z;
+                float dist_sq = dx * dx + dy * dy + dz * dz;
+                if (dist_sq > radius_sq) continue;
+                if (!found || dist_sq < best_dist_sq) {
+                    best_dist_sq = dist_sq;
+                    *out_x = x;
+                    *out_y = y;
+                    *out_z = z;
+                    found = true;
+                }
+            }
+        }
+    }
+    return found;
+}
+
+BlockInspection world_inspect_block(World* world, int x, int y, int z) {
+    BlockInspection info = {0};
+    int local_y;
+    world_to_local_coords(x, y, z, &info.chunk_x, &info.chunk_z, &info.local_x, &local_y, &info.local_z);
+
+    Chunk* chunk = world_get_chunk(world, info.chunk_x, info.chunk_z);
+    info.loaded = chunk && chunk->state != CHUNK_STATE_EMPTY && chunk->state != CHUNK_STATE_GENERATING;
+    info.block = info.loaded ? chunk_get_block(chunk, info.local_x, local_y, info.local_z)
+                             : (Block){BLOCK_AIR, 0, 0};
+
+    info.biome = biome_get_at(x, z);
+    info.surface_height = terrain_get_height_at(x, z, world->terrain_params);
+    info.has_chest_data = chest_get(world->chest_registry, x, y, z) != NULL;
+    return info;
+}
+
 void world_set_block(World* world, int x, int y, int z, Block block) {
     int chunk_x, chunk_z;
     int local_x, local_y, local_z;
@@ -460,6 +535,36 @@ Vector3 world_get_ambient_color(float time_of_day) {
     return get_ambient_color(time_of_day);
 }
 
+bool world_is_night(float time_of_day) {
+    // Same range as the cool night tint in get_ambient_color
+    return time_of_day < 5.0f || time_of_day >= 20.0f;
+}
+
+Season world_get_season(int day) {
+    if (day < 1) day = 1;
+    return (Season)(((day - 1) / WORLD_DAYS_PER_SEASON) % SEASON_COUNT);
+}
+
+const char* world_get_season_name(Season season) {
+    switch (season) {
+        case SEASON_SPRING: return "Spring";
+        case SEASON_SUMMER: return "Summer";
+        case SEASON_AUTUMN: return "Autumn";
+        case SEASON_WINTER: return "Winter";
+        default: return "Unknown";
+    }
+}
+
+float world_get_growth_rate(Season season) {
+    switch (season) {
+        case SEASON_SPRING: return 1.5f;
+        case SEASON_SUMMER: return 1.0f;
+        case SEASON_AUTUMN: return 0.75f;
+        case SEASON_WINTER: return 0.25f;  // Saplings barely grow
+        default: return 1.0f;
+    }
+}
+
 // ============================================================================
 // ENTITY MANAGER ACCESS
 // ============================================================================
@@ -492,6 +597,24 @@ void world_set_view_distance(World* world, int distance) {
     }
 }
 
+int world_get_lod_distance(World* world) {
+    if (!world) return LOD_DISTANCE_THRESHOLD;
+    return world->lod_distance;
+}
+
+void world_set_lod_distance(World* world, int distance) {
+    if (!world) return;
+
+    // Same range as the view distance
+    if (distance < 2) distance = 2;
+    if (distance > 32) distance = 32;
+
+    if (distance != world->lod_distance) {
+        world->lod_distance = distance;
+        printf("[WORLD] LOD distance set to %d chunks\n", distance);
+    }
+}
+
 void world_set_batch_rebuilds(World* world, int max_rebuilds) {
     if (!world) return;
     // Clamp to reasonable range (4-64)
@@ -553,9 +676,9 @@ static void apply_world_shader_uniforms(Material material, World* world,
     Vector3 ambient_light = get_ambient_color(time_of_day);
     SetShaderValue(material.shader, g_shader_locs.ambient, &ambient_light, SHADER_UNIFORM_VEC3);
 
-    // Fog settings - start further out for better visibility
-    float fog_start = world->view_distance * CHUNK_SIZE * 0.8f;
-    float fog_end = world->view_distance * CHUNK_SIZE * 1.2f;
+    // Fog settings - start further out for better visibility (closer in foggy weather)
+    float fog_start = weather_scale_view_distance(&world->weather, world->view_distance * CHUNK_SIZE * 0.8f);
+    float fog_end = weather_scale_view_distance(&world->weather, world->view_distance * CHUNK_SIZE * 1.2f);
     Vector3 fog_color = get_fog_color(time_of_day);
     int underwater_flag = underwater ? 1 : 0;
 